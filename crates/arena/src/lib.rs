@@ -0,0 +1,184 @@
+//! A typed arena allocator.
+//!
+//! Values are bump-allocated into chunks which never move once created, so
+//! `alloc` can hand out references which live as long as the arena. All
+//! values are dropped together when the arena is.
+//!
+//! # Example
+//! ```
+//! use arena::Arena;
+//!
+//! struct Node<'a> {
+//!     value: u32,
+//!     children: Vec<&'a Node<'a>>,
+//! }
+//!
+//! let arena = Arena::new();
+//! let leaf = arena.alloc(Node { value: 1, children: Vec::new() });
+//! let other = arena.alloc(Node { value: 2, children: Vec::new() });
+//! let root = arena.alloc(Node { value: 0, children: vec![leaf, other] });
+//!
+//! let total: u32 = root.children.iter().map(|child| child.value).sum();
+//! assert_eq!(total, 3);
+//! assert_eq!(arena.len(), 3);
+//! ```
+
+use std::cell::RefCell;
+
+/// The capacity of the first chunk of an arena created with `new`.
+const INITIAL_CAPACITY: usize = 16;
+
+/// An arena of values of type `T`.
+///
+/// See the crate documentation for an example.
+pub struct Arena<T> {
+    chunks: RefCell<Chunks<T>>,
+}
+
+struct Chunks<T> {
+    /// The chunk being allocated into. It is never grown past its
+    /// capacity, so its elements never move.
+    current: Vec<T>,
+    /// Full chunks, in allocation order.
+    full: Vec<Vec<T>>,
+}
+
+impl<T> Arena<T> {
+    /// Construct an empty arena.
+    pub fn new() -> Self {
+        Self::with_capacity(INITIAL_CAPACITY)
+    }
+
+    /// Construct an empty arena whose first chunk holds `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            chunks: RefCell::new(Chunks {
+                current: Vec::with_capacity(capacity.max(1)),
+                full: Vec::new(),
+            }),
+        }
+    }
+
+    /// Moves `value` into the arena and returns a mutable reference to it,
+    /// valid for as long as the arena is borrowed.
+    // Each value is handed out once, so the returned reference is unique
+    // despite coming from a shared borrow of the arena.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> &mut T {
+        let mut chunks = self.chunks.borrow_mut();
+        if chunks.current.len() == chunks.current.capacity() {
+            chunks.grow();
+        }
+        let idx = chunks.current.len();
+        chunks.current.push(value);
+        // SAFETY: the push above was within capacity, so the chunk did not
+        // reallocate and no existing element moved. Chunks are only ever
+        // moved into `full` as whole `Vec`s, which keeps their heap buffers
+        // in place, and elements are not removed until the arena is dropped
+        // or consumed, both of which need it to be unborrowed. Each
+        // element is handed out exactly once, so the reference is unique.
+        unsafe { &mut *chunks.current.as_mut_ptr().add(idx) }
+    }
+
+    /// Moves every value of `values` into the arena and returns a mutable
+    /// slice of them, in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arena::Arena;
+    /// let arena = Arena::with_capacity(2);
+    /// let first = arena.alloc(0);
+    /// let rest = arena.alloc_extend(1..=5);
+    /// rest[0] = 10;
+    /// assert_eq!(*first, 0);
+    /// assert_eq!(rest, [10, 2, 3, 4, 5]);
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_extend<I>(&self, values: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+    {
+        // Collect first so the slice can be placed in a single chunk, and
+        // so a panicking iterator cannot observe the arena mid-allocation.
+        let values: Vec<T> = values.into_iter().collect();
+        let mut chunks = self.chunks.borrow_mut();
+        if chunks.current.capacity() - chunks.current.len() < values.len() {
+            chunks.grow_for(values.len());
+        }
+        let start = chunks.current.len();
+        chunks.current.extend(values);
+        let len = chunks.current.len() - start;
+        // SAFETY: as in `alloc`; the extend stayed within capacity.
+        unsafe { std::slice::from_raw_parts_mut(chunks.current.as_mut_ptr().add(start), len) }
+    }
+
+    /// Returns the number of values in the arena.
+    pub fn len(&self) -> usize {
+        let chunks = self.chunks.borrow();
+        chunks.current.len() + chunks.full.iter().map(Vec::len).sum::<usize>()
+    }
+
+    /// Returns whether the arena holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over mutable references to every value in the
+    /// arena, in allocation order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arena::Arena;
+    /// let mut arena = Arena::with_capacity(1);
+    /// for value in 0..4 {
+    ///     arena.alloc(value);
+    /// }
+    /// arena.iter_mut().for_each(|value| *value *= 10);
+    /// assert_eq!(arena.into_vec(), [0, 10, 20, 30]);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let chunks = self.chunks.get_mut();
+        chunks
+            .full
+            .iter_mut()
+            .flatten()
+            .chain(chunks.current.iter_mut())
+    }
+
+    /// Consumes the arena, returning its values in allocation order.
+    pub fn into_vec(self) -> Vec<T> {
+        let chunks = self.chunks.into_inner();
+        let mut values = Vec::with_capacity(
+            chunks.current.len() + chunks.full.iter().map(Vec::len).sum::<usize>(),
+        );
+        for chunk in chunks.full {
+            values.extend(chunk);
+        }
+        values.extend(chunks.current);
+        values
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Chunks<T> {
+    /// Starts a new chunk of twice the current capacity.
+    fn grow(&mut self) {
+        self.grow_for(1);
+    }
+
+    /// Starts a new chunk with room for at least `additional` values.
+    fn grow_for(&mut self, additional: usize) {
+        let capacity = self.current.capacity().saturating_mul(2).max(additional);
+        let full = std::mem::replace(&mut self.current, Vec::with_capacity(capacity));
+        if !full.is_empty() {
+            self.full.push(full);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! A Bloom filter: a compact set which may report false positives but never
+//! false negatives.
+//!
+//! Suits membership pre-checks in front of an exact but expensive lookup,
+//! such as a `HashSet` of every row of a huge matrix: only items the filter
+//! reports as possibly present need the exact check.
+//!
+//! # Example
+//! ```
+//! use bloom_filter::BloomFilter;
+//!
+//! let mut seen = BloomFilter::with_false_positive_rate(1000, 0.01);
+//! for row in [[1, 2], [3, 4], [1, 2]] {
+//!     if seen.contains(&row) {
+//!         // Possibly a duplicate; confirm with an exact check.
+//!     }
+//!     seen.insert(&row);
+//! }
+//! assert!(seen.contains(&[3, 4]));
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// A Bloom filter over items of type `T`.
+///
+/// Items are hashed with `DefaultHasher::new()`, which uses fixed keys, so
+/// filters built by different runs of the same program agree and can be
+/// merged. The hash algorithm may change between Rust releases, so filters
+/// should not be persisted across toolchain upgrades.
+#[derive(Debug)]
+pub struct BloomFilter<T: ?Sized> {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+    item: PhantomData<fn(&T)>,
+}
+
+/// Error returned by `BloomFilter::merge` for filters with different
+/// parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncompatibleError {
+    /// The number of bits and hash functions of the filter merged into.
+    pub expected: (u64, u32),
+    /// The number of bits and hash functions of the other filter.
+    pub found: (u64, u32),
+}
+
+impl std::error::Error for IncompatibleError {}
+
+impl std::fmt::Display for IncompatibleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "Cannot merge a filter of {} bits and {} hashes into one of {} bits and {} hashes",
+            self.found.0, self.found.1, self.expected.0, self.expected.1
+        ))
+    }
+}
+
+impl<T: ?Sized + Hash> BloomFilter<T> {
+    /// Construct an empty filter of `num_bits` bits using `num_hashes` hash
+    /// functions per item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either argument is zero.
+    pub fn new(num_bits: u64, num_hashes: u32) -> Self {
+        assert!(num_bits > 0, "Bloom filter needs at least one bit");
+        assert!(num_hashes > 0, "Bloom filter needs at least one hash");
+        Self {
+            bits: vec![0; num_bits.div_ceil(64) as usize],
+            num_bits,
+            num_hashes,
+            item: PhantomData,
+        }
+    }
+
+    /// Construct an empty filter sized so that after `expected_items`
+    /// insertions the false-positive rate is about `false_positive_rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `0 < false_positive_rate < 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bloom_filter::BloomFilter;
+    /// let filter = BloomFilter::<str>::with_false_positive_rate(10_000, 0.01);
+    /// // About 9.6 bits and 7 hashes per item.
+    /// assert_eq!(filter.num_bits(), 95_851);
+    /// assert_eq!(filter.num_hashes(), 7);
+    /// ```
+    pub fn with_false_positive_rate(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate {} is not between 0 and 1",
+            false_positive_rate
+        );
+        let items = expected_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-items * false_positive_rate.ln() / (ln2 * ln2)).ceil();
+        let num_hashes = (num_bits / items * ln2).round().max(1.0);
+        Self::new(num_bits as u64, num_hashes as u32)
+    }
+
+    /// Adds `item` to the filter, returning `false` if it was possibly
+    /// present already and `true` if it was certainly not.
+    pub fn insert(&mut self, item: &T) -> bool {
+        let mut added = false;
+        for bit in self.bit_indices(item) {
+            let (word, mask) = ((bit / 64) as usize, 1 << (bit % 64));
+            added |= self.bits[word] & mask == 0;
+            self.bits[word] |= mask;
+        }
+        added
+    }
+
+    /// Returns whether `item` is possibly in the filter. A `false` result is
+    /// certain; a `true` one may be a false positive.
+    pub fn contains(&self, item: &T) -> bool {
+        self.bit_indices(item)
+            .all(|bit| self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Adds every item of `other` to the filter, so it reports an item as
+    /// possibly present if either filter did.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IncompatibleError` unless both filters have the same number
+    /// of bits and hash functions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bloom_filter::BloomFilter;
+    /// let mut a = BloomFilter::new(1024, 4);
+    /// let mut b = BloomFilter::new(1024, 4);
+    /// a.insert("left");
+    /// b.insert("right");
+    /// a.merge(&b).unwrap();
+    /// assert!(a.contains("left") && a.contains("right"));
+    /// assert!(a.merge(&BloomFilter::new(512, 4)).is_err());
+    /// ```
+    pub fn merge(&mut self, other: &Self) -> Result<(), IncompatibleError> {
+        if (self.num_bits, self.num_hashes) != (other.num_bits, other.num_hashes) {
+            return Err(IncompatibleError {
+                expected: (self.num_bits, self.num_hashes),
+                found: (other.num_bits, other.num_hashes),
+            });
+        }
+        for (word, other_word) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= other_word;
+        }
+        Ok(())
+    }
+
+    /// Removes every item from the filter.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Returns the number of bits in the filter.
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Returns the number of hash functions applied to each item.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Estimates the current false-positive rate from the fraction of bits
+    /// set.
+    pub fn estimated_false_positive_rate(&self) -> f64 {
+        let set: u64 = self
+            .bits
+            .iter()
+            .map(|word| u64::from(word.count_ones()))
+            .sum();
+        (set as f64 / self.num_bits as f64).powi(self.num_hashes as i32)
+    }
+
+    /// Returns the bits for `item`, derived from two hashes by double
+    /// hashing (Kirsch and Mitzenmacher).
+    fn bit_indices(&self, item: &T) -> impl Iterator<Item = u64> + use<T> {
+        let first = hash_with(0, item);
+        // An odd step visits distinct bits whenever `num_bits` is a power
+        // of two, and is otherwise as good as any.
+        let step = hash_with(1, item) | 1;
+        let num_bits = self.num_bits;
+        (0..u64::from(self.num_hashes))
+            .map(move |i| first.wrapping_add(i.wrapping_mul(step)) % num_bits)
+    }
+}
+
+impl<T: ?Sized> Clone for BloomFilter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits.clone(),
+            num_bits: self.num_bits,
+            num_hashes: self.num_hashes,
+            item: PhantomData,
+        }
+    }
+}
+
+fn hash_with<T: ?Sized + Hash>(seed: u8, item: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    item.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,255 @@
+//! Compressed sparse row (CSR) matrices and their products with dense
+//! `two_dim_array` operands.
+//!
+//! A `CsrMatrix` stores only its non-zero entries, row by row. `spmv`
+//! multiplies it by a dense vector and `spmm` by a dense matrix, writing
+//! into caller-provided outputs so solver loops do not allocate.
+//!
+//! # Example
+//! ```
+//! use csr::{CsrMatrix, spmm, spmv};
+//! use two_dim_array::TwoDimensionalArray;
+//!
+//! // [[2, 0, 1],
+//! //  [0, 3, 0]]
+//! let a = CsrMatrix::from_triplets(2, 3, [(0, 0, 2.0), (0, 2, 1.0), (1, 1, 3.0)]).unwrap();
+//! assert_eq!(a.nnz(), 3);
+//!
+//! let mut y = [0.0; 2];
+//! spmv(&a, &[1.0, 2.0, 3.0], &mut y).unwrap();
+//! assert_eq!(y, [5.0, 6.0]);
+//!
+//! let mut b = [
+//!     1.0, 0.0,
+//!     0.0, 1.0,
+//!     1.0, 1.0,
+//! ];
+//! let mut c = [0.0; 4];
+//! let b = TwoDimensionalArray::new(&mut b, 3, 2).unwrap();
+//! let mut out = TwoDimensionalArray::new(&mut c, 2, 2).unwrap();
+//! spmm(&a, &b.as_view(), &mut out).unwrap();
+//! assert_eq!(c, [3.0, 1.0, 0.0, 3.0]);
+//! ```
+
+use std::ops::{Add, Mul};
+
+use two_dim_array::num::Zero;
+use two_dim_array::{IndexError, ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// A sparse matrix in compressed sparse row format.
+///
+/// The entries of row `r` are `col_indices[row_offsets[r]..row_offsets[r + 1]]`
+/// and the matching `values`, with strictly increasing column indices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    num_rows: usize,
+    num_cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Construct a `num_rows` by `num_cols` matrix from `(row, col, value)`
+    /// triplets in any order. Values given for the same position are
+    /// summed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` for the first triplet outside the matrix.
+    pub fn from_triplets<I>(
+        num_rows: usize,
+        num_cols: usize,
+        triplets: I,
+    ) -> Result<Self, IndexError>
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+        T: Add<Output = T>,
+    {
+        let mut triplets: Vec<_> = triplets.into_iter().collect();
+        if let Some(&(row, col, _)) = triplets
+            .iter()
+            .find(|&&(row, col, _)| row >= num_rows || col >= num_cols)
+        {
+            return Err(IndexError {
+                row,
+                col,
+                shape: (num_rows, num_cols),
+            });
+        }
+        triplets.sort_by_key(|&(row, col, _)| (row, col));
+
+        let mut row_offsets = vec![0; num_rows + 1];
+        let mut col_indices: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+        for (row, col, value) in triplets {
+            if last == Some((row, col)) {
+                let sum = values
+                    .pop()
+                    .expect("a value was pushed for the last position");
+                values.push(sum + value);
+                continue;
+            }
+            last = Some((row, col));
+            row_offsets[row + 1] += 1;
+            col_indices.push(col);
+            values.push(value);
+        }
+        for row in 0..num_rows {
+            row_offsets[row + 1] += row_offsets[row];
+        }
+        Ok(Self {
+            num_rows,
+            num_cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    /// Returns the shape of the matrix as `(num_rows, num_cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns an iterator over the stored entries of `row`, as the column
+    /// and value, by increasing column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> impl ExactSizeIterator<Item = (usize, &T)> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        self.col_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(&self.values[range])
+    }
+
+    /// Returns the value at `(row, col)`, or `None` if no entry is stored
+    /// there or it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.num_rows {
+            return None;
+        }
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        let idx = self.col_indices[range.clone()].binary_search(&col).ok()?;
+        Some(&self.values[range.start + idx])
+    }
+
+    /// Returns the row offsets, column indices and values arrays.
+    pub fn as_parts(&self) -> (&[usize], &[usize], &[T]) {
+        (&self.row_offsets, &self.col_indices, &self.values)
+    }
+}
+
+impl<T: Copy + Zero + PartialEq> CsrMatrix<T> {
+    /// Construct a matrix storing the non-zero elements of `dense`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use csr::CsrMatrix;
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// let dense = TwoDimensionalArrayRef::new(&[0, 4, 0, 5, 0, 6], 2, 3).unwrap();
+    /// let sparse = CsrMatrix::from_dense(&dense);
+    /// assert_eq!(sparse.nnz(), 3);
+    /// assert_eq!(sparse.get(1, 0), Some(&5));
+    /// assert_eq!(sparse.get(0, 0), None);
+    /// ```
+    pub fn from_dense(dense: &TwoDimensionalArrayRef<'_, T>) -> Self {
+        let (num_rows, num_cols) = dense.shape();
+        let mut row_offsets = Vec::with_capacity(num_rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_offsets.push(0);
+        for row in 0..num_rows {
+            for (col, &value) in dense.get_panic(row, ..).iter().enumerate() {
+                if value != T::zero() {
+                    col_indices.push(col);
+                    values.push(value);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+        Self {
+            num_rows,
+            num_cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+/// Computes `y = a * x` for a sparse matrix `a` and dense vector `x`.
+///
+/// # Errors
+///
+/// Returns `ShapeError::LengthMismatch` when `x` does not have one element
+/// per column of `a` or `y` one per row.
+pub fn spmv<T>(a: &CsrMatrix<T>, x: &[T], y: &mut [T]) -> Result<(), ShapeError>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    check_len(a.num_cols, x.len())?;
+    check_len(a.num_rows, y.len())?;
+    for (row, out) in y.iter_mut().enumerate() {
+        *out = a
+            .row(row)
+            .fold(T::zero(), |acc, (col, &value)| acc + value * x[col]);
+    }
+    Ok(())
+}
+
+/// Computes `out = a * b` for a sparse matrix `a` and dense matrix `b`.
+///
+/// Each row of the output accumulates scaled rows of `b`, so both are read
+/// and written contiguously.
+///
+/// # Errors
+///
+/// Returns `ShapeError::ShapeMismatch` when `b` does not have one row per
+/// column of `a`, or `out` is not `a.num_rows` by `b.num_cols`.
+pub fn spmm<T>(
+    a: &CsrMatrix<T>,
+    b: &TwoDimensionalArrayRef<'_, T>,
+    out: &mut TwoDimensionalArray<'_, T>,
+) -> Result<(), ShapeError>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let num_cols = b.num_cols();
+    check_shape((a.num_cols, num_cols), b.shape())?;
+    check_shape((a.num_rows, num_cols), out.shape())?;
+    for row in 0..a.num_rows {
+        let out_row = out.get_mut_panic(row, ..);
+        out_row.fill(T::zero());
+        for (col, &value) in a.row(row) {
+            for (acc, &elem) in out_row.iter_mut().zip(b.get_panic(col, ..)) {
+                *acc = *acc + value * elem;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_len(expected: usize, found: usize) -> Result<(), ShapeError> {
+    if expected != found {
+        return Err(ShapeError::LengthMismatch { expected, found });
+    }
+    Ok(())
+}
+
+fn check_shape(expected: (usize, usize), found: (usize, usize)) -> Result<(), ShapeError> {
+    if expected != found {
+        return Err(ShapeError::ShapeMismatch { expected, found });
+    }
+    Ok(())
+}
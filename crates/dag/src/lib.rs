@@ -0,0 +1,323 @@
+//! Directed acyclic graph utilities: building a graph of nodes and edges,
+//! detecting cycles, topological ordering and longest paths.
+//!
+//! # Example
+//!
+//! Ordering dependent update passes, where an edge `a -> b` means `a` must
+//! run before `b`:
+//! ```
+//! use dag::Dag;
+//!
+//! let mut passes = Dag::new();
+//! let erode = passes.add_node("erode");
+//! let rivers = passes.add_node("rivers");
+//! let heights = passes.add_node("heights");
+//! let biomes = passes.add_node("biomes");
+//! passes.add_edge(heights, erode, ());
+//! passes.add_edge(erode, rivers, ());
+//! passes.add_edge(heights, biomes, ());
+//! passes.add_edge(rivers, biomes, ());
+//!
+//! let order: Vec<_> = passes
+//!     .topological_order()
+//!     .unwrap()
+//!     .into_iter()
+//!     .map(|id| passes[id])
+//!     .collect();
+//! assert_eq!(order, ["heights", "erode", "rivers", "biomes"]);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::{Add, Index, IndexMut};
+
+/// Identifies a node of a `Dag`.
+///
+/// Ids count up from zero in the order nodes were added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the index of the node.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Error returned when an operation needing an acyclic graph finds a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// The nodes of one cycle in edge order; the last has an edge back to
+    /// the first.
+    pub cycle: Vec<NodeId>,
+}
+
+impl std::error::Error for CycleError {}
+
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Graph contains a cycle through nodes")?;
+        for node in &self.cycle {
+            f.write_fmt(format_args!(" {}", node.0))?;
+        }
+        Ok(())
+    }
+}
+
+/// A directed graph with node weights `N` and edge weights `E`, intended to
+/// be acyclic.
+///
+/// Edges may be added freely; operations which need the graph to be
+/// acyclic return `CycleError` when it is not.
+#[derive(Debug, Clone)]
+pub struct Dag<N, E = ()> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<(NodeId, E)>>,
+}
+
+impl<N, E> Dag<N, E> {
+    /// Construct an empty graph.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Adds a node holding `weight` and returns its id.
+    pub fn add_node(&mut self, weight: N) -> NodeId {
+        self.nodes.push(weight);
+        self.edges.push(Vec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    /// Adds an edge from `from` to `to` holding `weight`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is not in the graph.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: E) {
+        assert!(to.0 < self.nodes.len(), "node {} is not in the graph", to.0);
+        self.edges[from.0].push((to, weight));
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the weight of `node`, or `None` if it is not in the graph.
+    pub fn node(&self, node: NodeId) -> Option<&N> {
+        self.nodes.get(node.0)
+    }
+
+    /// Returns the weight of `node` mutably, or `None` if it is not in the
+    /// graph.
+    pub fn node_mut(&mut self, node: NodeId) -> Option<&mut N> {
+        self.nodes.get_mut(node.0)
+    }
+
+    /// Returns an iterator over the ids of every node.
+    pub fn node_ids(&self) -> impl ExactSizeIterator<Item = NodeId> + use<N, E> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    /// Returns an iterator over the edges leaving `node`, as the target and
+    /// the edge weight, in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not in the graph.
+    pub fn successors(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> {
+        self.edges[node.0].iter().map(|(to, weight)| (*to, weight))
+    }
+
+    /// Returns a cycle of the graph, if it has one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dag::Dag;
+    /// let mut graph = Dag::new();
+    /// let [a, b, c] = [(); 3].map(|()| graph.add_node(()));
+    /// graph.add_edge(a, b, ());
+    /// graph.add_edge(b, c, ());
+    /// assert_eq!(graph.find_cycle(), None);
+    ///
+    /// graph.add_edge(c, b, ());
+    /// assert_eq!(graph.find_cycle(), Some(vec![b, c]));
+    /// ```
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            OnPath,
+            Done,
+        }
+        let mut state = vec![State::Unvisited; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if state[root] != State::Unvisited {
+                continue;
+            }
+            // Depth-first search keeping the current path and, for each
+            // node on it, how many of its edges have been followed.
+            let mut path = vec![(root, 0)];
+            state[root] = State::OnPath;
+            while let Some((node, next_edge)) = path.last_mut() {
+                let node = *node;
+                let Some(&(NodeId(to), _)) = self.edges[node].get(*next_edge) else {
+                    state[node] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                *next_edge += 1;
+                match state[to] {
+                    State::Unvisited => {
+                        state[to] = State::OnPath;
+                        path.push((to, 0));
+                    }
+                    State::OnPath => {
+                        let start = path.iter().position(|&(n, _)| n == to).unwrap_or(0);
+                        return Some(path[start..].iter().map(|&(n, _)| NodeId(n)).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the nodes in an order where every edge points forwards, using
+    /// Kahn's algorithm. Among the nodes ready at each step the one added
+    /// first comes first, so the order is deterministic.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CycleError` when the graph has a cycle.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, CycleError> {
+        let mut in_degrees = vec![0usize; self.nodes.len()];
+        for (to, _) in self.edges.iter().flatten() {
+            in_degrees[to.0] += 1;
+        }
+        let mut ready: BinaryHeap<Reverse<usize>> = in_degrees
+            .iter()
+            .enumerate()
+            .filter(|&(_, &degree)| degree == 0)
+            .map(|(node, _)| Reverse(node))
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(NodeId(node));
+            for (to, _) in &self.edges[node] {
+                in_degrees[to.0] -= 1;
+                if in_degrees[to.0] == 0 {
+                    ready.push(Reverse(to.0));
+                }
+            }
+        }
+        if order.len() < self.nodes.len() {
+            let cycle = self.find_cycle().unwrap_or_default();
+            return Err(CycleError { cycle });
+        }
+        Ok(order)
+    }
+
+    /// Returns the length and nodes of a longest path through the graph,
+    /// where `weight` gives the length of each edge. On ties the path found
+    /// first in topological order is returned. An empty graph has an empty
+    /// path of length `W::default()`.
+    ///
+    /// A path may start and end at any node, so with signed weights an edge
+    /// is only taken when the path through it is longer than starting afresh.
+    ///
+    /// With unit weights this is the critical path of a schedule: the most
+    /// passes which must run one after another.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CycleError` when the graph has a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dag::Dag;
+    /// let mut graph = Dag::new();
+    /// let [a, b, c, d] = [(); 4].map(|()| graph.add_node(()));
+    /// graph.add_edge(a, b, 1);
+    /// graph.add_edge(b, d, 1);
+    /// graph.add_edge(a, c, 5);
+    /// graph.add_edge(c, d, 1);
+    ///
+    /// let (length, path) = graph.longest_path(|&cost| cost).unwrap();
+    /// assert_eq!(length, 6);
+    /// assert_eq!(path, [a, c, d]);
+    ///
+    /// let mut graph = Dag::new();
+    /// let [a, b, d] = [(); 3].map(|()| graph.add_node(()));
+    /// graph.add_edge(a, b, -5);
+    /// graph.add_edge(b, d, 10);
+    /// assert_eq!(graph.longest_path(|&cost| cost).unwrap(), (10, vec![b, d]));
+    /// ```
+    pub fn longest_path<W, F>(&self, mut weight: F) -> Result<(W, Vec<NodeId>), CycleError>
+    where
+        W: Copy + Ord + Default + Add<Output = W>,
+        F: FnMut(&E) -> W,
+    {
+        let order = self.topological_order()?;
+        // The longest path ending at each node and its predecessor on it.
+        // Every node starts a path of its own at `W::default()`.
+        let mut best: Vec<(W, Option<usize>)> = vec![(W::default(), None); self.nodes.len()];
+        for &NodeId(node) in &order {
+            let length = best[node].0;
+            for (to, edge) in &self.edges[node] {
+                let candidate = length + weight(edge);
+                let current = &mut best[to.0];
+                if candidate > current.0 {
+                    *current = (candidate, Some(node));
+                }
+            }
+        }
+        let Some(end) = order.iter().map(|id| id.0).reduce(|longest, node| {
+            if best[node].0 > best[longest].0 {
+                node
+            } else {
+                longest
+            }
+        }) else {
+            return Ok((W::default(), Vec::new()));
+        };
+        let mut path = vec![NodeId(end)];
+        let mut node = end;
+        while let Some(prev) = best[node].1 {
+            path.push(NodeId(prev));
+            node = prev;
+        }
+        path.reverse();
+        Ok((best[end].0, path))
+    }
+}
+
+impl<N, E> Default for Dag<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E> Index<NodeId> for Dag<N, E> {
+    type Output = N;
+
+    fn index(&self, node: NodeId) -> &N {
+        &self.nodes[node.0]
+    }
+}
+
+impl<N, E> IndexMut<NodeId> for Dag<N, E> {
+    fn index_mut(&mut self, node: NodeId) -> &mut N {
+        &mut self.nodes[node.0]
+    }
+}
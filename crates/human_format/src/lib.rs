@@ -0,0 +1,168 @@
+//! `Display` wrappers formatting quantities for humans.
+//!
+//! - `Bytes` formats a byte size with binary prefixes, such as `1.2 GiB`.
+//! - `Duration` formats a duration in its two largest units, such as
+//!   `3m 42s`.
+//! - `Count` formats a large count with SI prefixes, such as `1.5M`.
+//!
+//! Fractional output has one decimal place unless a precision is given,
+//! and width and alignment are honoured, so the wrappers drop into tables.
+//!
+//! # Example
+//! ```
+//! use human_format::{Bytes, Count, Duration};
+//!
+//! assert_eq!(Bytes(1_288_490_189).to_string(), "1.2 GiB");
+//! assert_eq!(Duration(std::time::Duration::from_secs(222)).to_string(), "3m 42s");
+//! assert_eq!(format!("{} elements", Count(1_500_000)), "1.5M elements");
+//! assert_eq!(format!("[{:>8.2}]", Bytes(1536)), "[1.50 KiB]");
+//! ```
+
+use std::fmt;
+
+/// A byte size, formatted with binary (IEC) prefixes.
+///
+/// # Example
+///
+/// ```
+/// use human_format::Bytes;
+/// assert_eq!(Bytes(512).to_string(), "512 B");
+/// assert_eq!(Bytes(1024).to_string(), "1.0 KiB");
+/// assert_eq!(Bytes(u64::MAX).to_string(), "16.0 EiB");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes(pub u64);
+
+/// A length of time, formatted in its two largest non-zero units from days
+/// down to seconds, or in a single unit below a minute.
+///
+/// # Example
+///
+/// ```
+/// use std::time;
+/// use human_format::Duration;
+/// assert_eq!(Duration(time::Duration::from_secs(90_061)).to_string(), "1d 1h");
+/// assert_eq!(Duration(time::Duration::from_secs(3600)).to_string(), "1h");
+/// assert_eq!(Duration(time::Duration::from_secs(86_700)).to_string(), "1d 5m");
+/// assert_eq!(Duration(time::Duration::from_millis(4250)).to_string(), "4.2s");
+/// assert_eq!(Duration(time::Duration::from_micros(350)).to_string(), "350µs");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(pub std::time::Duration);
+
+/// A count, formatted with SI prefixes.
+///
+/// # Example
+///
+/// ```
+/// use human_format::Count;
+/// assert_eq!(Count(999).to_string(), "999");
+/// assert_eq!(Count(12_345).to_string(), "12.3k");
+/// assert_eq!(format!("{:.0}", Count(7_000_000_000)), "7G");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Count(pub u64);
+
+const DEFAULT_PRECISION: usize = 1;
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+        if self.0 < 1024 {
+            return pad(f, &format!("{} B", self.0));
+        }
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+        let (value, unit) = scale(self.0 as f64, 1024.0, &UNITS, precision);
+        pad(f, &format!("{:.*} {}", precision, value, unit))
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 6] = ["k", "M", "G", "T", "P", "E"];
+        if self.0 < 1000 {
+            return pad(f, &self.0.to_string());
+        }
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+        let (value, unit) = scale(self.0 as f64, 1000.0, &UNITS, precision);
+        pad(f, &format!("{:.*}{}", precision, value, unit))
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [(u64, &str); 4] = [(86_400, "d"), (3600, "h"), (60, "m"), (1, "s")];
+        let secs = self.0.as_secs();
+        let nanos = self.0.subsec_nanos();
+        if secs >= 60 {
+            let mut parts = UNITS
+                .iter()
+                .enumerate()
+                .map(|(idx, &(unit, suffix))| {
+                    // Whole units left over after the larger units.
+                    let larger = if idx == 0 { u64::MAX } else { UNITS[idx - 1].0 };
+                    (secs % larger / unit, suffix)
+                })
+                .filter(|&(amount, _)| amount > 0)
+                .take(2)
+                .map(|(amount, suffix)| format!("{}{}", amount, suffix));
+            let first = parts.next().unwrap_or_default();
+            let formatted = match parts.next() {
+                Some(second) => format!("{} {}", first, second),
+                None => first,
+            };
+            return pad(f, &formatted);
+        }
+        if secs >= 1 {
+            let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+            // Truncate rather than round so 59.99s does not print as 60.0s.
+            let scale = 10f64.powi(precision as i32);
+            let value = (self.0.as_secs_f64() * scale).trunc() / scale;
+            return pad(f, &format!("{:.*}s", precision, value));
+        }
+        let formatted = match nanos {
+            1_000_000.. => format!("{}ms", nanos / 1_000_000),
+            1_000.. => format!("{}µs", nanos / 1_000),
+            _ => format!("{}ns", nanos),
+        };
+        pad(f, &formatted)
+    }
+}
+
+/// Divides `value` by `base` until it is below `base`, returning it and the
+/// matching unit from `units`, which start at one factor of `base`.
+///
+/// Moves to the next unit if rounding to `precision` decimals would print
+/// `base` itself, such as `1024.0 KiB`.
+fn scale<'u>(mut value: f64, base: f64, units: &[&'u str], precision: usize) -> (f64, &'u str) {
+    let rounding = 10f64.powi(precision as i32);
+    let mut idx = 0;
+    value /= base;
+    while idx + 1 < units.len() && (value * rounding).round() / rounding >= base {
+        value /= base;
+        idx += 1;
+    }
+    (value, units[idx])
+}
+
+/// Writes `s` padded to the formatter's width, fill and alignment,
+/// defaulting to right alignment like numbers. Unlike `Formatter::pad` the
+/// precision is not used to truncate, as it already set the decimals.
+fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let len = s.chars().count();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
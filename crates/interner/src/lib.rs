@@ -0,0 +1,140 @@
+//! A string interner.
+//!
+//! Each distinct string is stored once and identified by a `Symbol`, a
+//! `u32`-sized handle which is cheap to copy, compare and hash. Grids of
+//! categorical or text data can then hold symbols instead of `String`s.
+//!
+//! # Example
+//! ```
+//! use interner::Interner;
+//! use two_dim_array::TwoDimensionalArray;
+//!
+//! let mut interner = Interner::new();
+//! let mut cells: Vec<_> = ["grass", "water", "grass", "rock", "water", "grass"]
+//!     .into_iter()
+//!     .map(|name| interner.get_or_intern(name))
+//!     .collect();
+//! let terrain = TwoDimensionalArray::new(&mut cells, 2, 3).unwrap();
+//!
+//! assert_eq!(interner.len(), 3);
+//! assert_eq!(terrain.get_panic(0, 0), terrain.get_panic(0, 2));
+//! assert_eq!(interner.resolve(*terrain.get_panic(1, 0)), Some("rock"));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A handle to a string stored in an `Interner`.
+///
+/// Symbols are only meaningful for the interner which created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the index of the symbol, which counts up from zero in the
+    /// order strings were first interned.
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the index of the symbol as a `usize`, for indexing tables
+    /// kept alongside the interner.
+    pub fn as_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Stores each distinct string once, handing out a `Symbol` for it.
+///
+/// See the crate documentation for an example.
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    symbols: HashMap<Arc<str>, Symbol>,
+    strings: Vec<Arc<str>>,
+}
+
+impl Interner {
+    /// Construct an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct an empty interner with room for `capacity` strings.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            symbols: HashMap::with_capacity(capacity),
+            strings: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the symbol for `string`, interning it first if it has not
+    /// been seen before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct strings are interned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interner::Interner;
+    /// let mut interner = Interner::new();
+    /// let a = interner.get_or_intern("a");
+    /// let b = interner.get_or_intern("b");
+    /// assert_ne!(a, b);
+    /// assert_eq!(interner.get_or_intern("a"), a);
+    /// ```
+    pub fn get_or_intern(&mut self, string: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(string) {
+            return symbol;
+        }
+        let idx = u32::try_from(self.strings.len()).expect("interner holds u32::MAX strings");
+        let symbol = Symbol(idx);
+        let stored: Arc<str> = Arc::from(string);
+        self.strings.push(Arc::clone(&stored));
+        self.symbols.insert(stored, symbol);
+        symbol
+    }
+
+    /// Returns the symbol for `string` if it has been interned.
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        self.symbols.get(string).copied()
+    }
+
+    /// Returns the string `symbol` stands for, or `None` if it did not come
+    /// from this interner.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.strings.get(symbol.as_usize()).map(|string| &**string)
+    }
+
+    /// Returns the number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns whether no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Returns an iterator over the symbols and their strings, in the order
+    /// they were interned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interner::Interner;
+    /// let mut interner = Interner::new();
+    /// for word in ["b", "a", "b"] {
+    ///     interner.get_or_intern(word);
+    /// }
+    /// let words: Vec<_> = interner.iter().map(|(_, word)| word).collect();
+    /// assert_eq!(words, ["b", "a"]);
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Symbol, &str)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(idx, string)| (Symbol(idx as u32), &**string))
+    }
+}
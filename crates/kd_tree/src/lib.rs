@@ -0,0 +1,277 @@
+//! A k-d tree spatial index over points in `K` dimensions, typically 2 or 3.
+//!
+//! The tree is built once from a set of points, each carrying a payload,
+//! and answers nearest-neighbour, radius and axis-aligned box queries.
+//!
+//! With the `two_dim_array` feature, `KdTree::from_grid` indexes the cells
+//! of a `TwoDimensionalArray` which satisfy a predicate.
+//!
+//! # Example
+//! ```
+//! use kd_tree::KdTree;
+//!
+//! let tree = KdTree::new(vec![
+//!     ([0.0, 0.0], "origin"),
+//!     ([5.0, 5.0], "centre"),
+//!     ([9.0, 1.0], "corner"),
+//! ]);
+//!
+//! let nearest = tree.nearest(&[6.0, 4.0]).unwrap();
+//! assert_eq!(*nearest.payload, "centre");
+//! assert_eq!(nearest.distance_squared, 2.0);
+//!
+//! let close: Vec<_> = tree
+//!     .within_radius(&[1.0, 1.0], 2.0)
+//!     .into_iter()
+//!     .map(|found| *found.payload)
+//!     .collect();
+//! assert_eq!(close, ["origin"]);
+//! ```
+
+use std::cmp::Ordering;
+
+/// A point of a `KdTree` found by a query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Found<'a, const K: usize, P> {
+    /// The coordinates of the point.
+    pub point: &'a [f64; K],
+    /// The payload stored with the point.
+    pub payload: &'a P,
+    /// The squared Euclidean distance from the query point, or zero for box
+    /// queries.
+    pub distance_squared: f64,
+}
+
+/// A k-d tree over points `[f64; K]` each carrying a payload `P`.
+///
+/// The tree is stored implicitly: the points are arranged so that the
+/// median of every subrange splits it on the axis for its depth.
+#[derive(Debug, Clone)]
+pub struct KdTree<const K: usize, P = ()> {
+    points: Vec<([f64; K], P)>,
+}
+
+impl<const K: usize, P> KdTree<K, P> {
+    /// Builds a balanced tree from `points`, in `O(n log n)` time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is zero.
+    pub fn new(mut points: Vec<([f64; K], P)>) -> Self {
+        assert!(K > 0, "k-d tree needs at least one dimension");
+        build(&mut points, 0);
+        Self { points }
+    }
+
+    /// Returns the number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns whether the tree holds no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns an iterator over the points and payloads, in no particular
+    /// order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&[f64; K], &P)> {
+        self.points.iter().map(|(point, payload)| (point, payload))
+    }
+
+    /// Returns the point nearest to `query`, or `None` if the tree is empty.
+    /// Ties are broken arbitrarily.
+    pub fn nearest(&self, query: &[f64; K]) -> Option<Found<'_, K, P>> {
+        let mut best = None;
+        self.nearest_in(0, self.points.len(), 0, query, &mut best);
+        best.map(|(idx, distance_squared)| self.found(idx, distance_squared))
+    }
+
+    /// Returns every point within `radius` of `query`, inclusive, in no
+    /// particular order.
+    pub fn within_radius(&self, query: &[f64; K], radius: f64) -> Vec<Found<'_, K, P>> {
+        let mut found = Vec::new();
+        self.radius_in(0, self.points.len(), 0, query, radius * radius, &mut found);
+        found
+    }
+
+    /// Returns every point inside the axis-aligned box from `min` to `max`,
+    /// inclusive, in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kd_tree::KdTree;
+    /// let tree = KdTree::new((0..10).map(|i| ([i as f64, (i * i) as f64], i)).collect());
+    /// let mut inside: Vec<_> = tree
+    ///     .in_box(&[1.0, 0.0], &[8.0, 30.0])
+    ///     .into_iter()
+    ///     .map(|found| *found.payload)
+    ///     .collect();
+    /// inside.sort();
+    /// assert_eq!(inside, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn in_box(&self, min: &[f64; K], max: &[f64; K]) -> Vec<Found<'_, K, P>> {
+        let mut found = Vec::new();
+        self.box_in(0, self.points.len(), 0, min, max, &mut found);
+        found
+    }
+
+    fn found(&self, idx: usize, distance_squared: f64) -> Found<'_, K, P> {
+        let (point, payload) = &self.points[idx];
+        Found {
+            point,
+            payload,
+            distance_squared,
+        }
+    }
+
+    /// Searches the subtree `start..end`, split on `axis`, updating
+    /// `best` with `(index, distance_squared)`.
+    fn nearest_in(
+        &self,
+        start: usize,
+        end: usize,
+        axis: usize,
+        query: &[f64; K],
+        best: &mut Option<(usize, f64)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let point = &self.points[mid].0;
+        let distance = distance_squared(point, query);
+        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+            *best = Some((mid, distance));
+        }
+        let diff = query[axis] - point[axis];
+        let (near, far) = if diff < 0.0 {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+        let next_axis = (axis + 1) % K;
+        self.nearest_in(near.0, near.1, next_axis, query, best);
+        // Only cross the splitting plane if it is closer than the best so far.
+        if best.is_none_or(|(_, best_distance)| diff * diff < best_distance) {
+            self.nearest_in(far.0, far.1, next_axis, query, best);
+        }
+    }
+
+    fn radius_in<'a>(
+        &'a self,
+        start: usize,
+        end: usize,
+        axis: usize,
+        query: &[f64; K],
+        radius_squared: f64,
+        found: &mut Vec<Found<'a, K, P>>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let point = &self.points[mid].0;
+        let distance = distance_squared(point, query);
+        if distance <= radius_squared {
+            found.push(self.found(mid, distance));
+        }
+        let diff = query[axis] - point[axis];
+        let next_axis = (axis + 1) % K;
+        if diff <= 0.0 || diff * diff <= radius_squared {
+            self.radius_in(start, mid, next_axis, query, radius_squared, found);
+        }
+        if diff >= 0.0 || diff * diff <= radius_squared {
+            self.radius_in(mid + 1, end, next_axis, query, radius_squared, found);
+        }
+    }
+
+    fn box_in<'a>(
+        &'a self,
+        start: usize,
+        end: usize,
+        axis: usize,
+        min: &[f64; K],
+        max: &[f64; K],
+        found: &mut Vec<Found<'a, K, P>>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let point = &self.points[mid].0;
+        if (0..K).all(|dim| min[dim] <= point[dim] && point[dim] <= max[dim]) {
+            found.push(self.found(mid, 0.0));
+        }
+        let next_axis = (axis + 1) % K;
+        if min[axis] <= point[axis] {
+            self.box_in(start, mid, next_axis, min, max, found);
+        }
+        if point[axis] <= max[axis] {
+            self.box_in(mid + 1, end, next_axis, min, max, found);
+        }
+    }
+}
+
+#[cfg(feature = "two_dim_array")]
+impl KdTree<2, (usize, usize)> {
+    /// Builds a tree of the cells of `grid` for which `predicate` holds,
+    /// with each cell at `[row, col]` and carrying `(row, col)` as its
+    /// payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kd_tree::KdTree;
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// let mut a = [
+    ///     0, 0, 0, 1,
+    ///     0, 0, 0, 0,
+    ///     1, 0, 0, 0,
+    /// ];
+    /// let grid = TwoDimensionalArray::new(&mut a, 3, 4).unwrap();
+    /// let occupied = KdTree::from_grid(&grid, |&cell| cell != 0);
+    ///
+    /// assert_eq!(occupied.len(), 2);
+    /// assert_eq!(*occupied.nearest(&[1.0, 1.0]).unwrap().payload, (2, 0));
+    /// ```
+    pub fn from_grid<T, F>(
+        grid: &two_dim_array::TwoDimensionalArray<'_, T>,
+        mut predicate: F,
+    ) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut points = Vec::new();
+        for row in 0..grid.num_rows() {
+            for (col, cell) in grid.get_panic(row, ..).iter().enumerate() {
+                if predicate(cell) {
+                    points.push(([row as f64, col as f64], (row, col)));
+                }
+            }
+        }
+        Self::new(points)
+    }
+}
+
+/// Arranges `points` so the median splits it on `axis`, recursively.
+fn build<const K: usize, P>(points: &mut [([f64; K], P)], axis: usize) {
+    if points.len() <= 1 {
+        return;
+    }
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by(mid, |a, b| compare(a.0[axis], b.0[axis]));
+    let (left, right) = points.split_at_mut(mid);
+    build(left, (axis + 1) % K);
+    build(&mut right[1..], (axis + 1) % K);
+}
+
+fn compare(a: f64, b: f64) -> Ordering {
+    a.total_cmp(&b)
+}
+
+fn distance_squared<const K: usize>(a: &[f64; K], b: &[f64; K]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
@@ -0,0 +1,259 @@
+//! A synchronous token-bucket rate limiter.
+//!
+//! A `TokenBucket` holds up to `capacity` tokens and refills continuously
+//! at `rate` tokens per second. Each operation spends tokens, so bursts of
+//! up to `capacity` are allowed while the long-run rate is bounded.
+//!
+//! `Throttled` wraps a reader or writer and spends one token per byte.
+//!
+//! # Example
+//! ```
+//! use std::time::{Duration, Instant};
+//! use rate_limiter::TokenBucket;
+//!
+//! let start = Instant::now();
+//! let mut bucket = TokenBucket::new_at(2.0, 1.0, start);
+//!
+//! assert!(bucket.try_acquire_at(2.0, start));
+//! assert!(!bucket.try_acquire_at(1.0, start));
+//! assert_eq!(bucket.time_until_at(1.0, start), Duration::from_secs(1));
+//! assert!(bucket.try_acquire_at(1.0, start + Duration::from_secs(1)));
+//! ```
+
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+/// A token bucket refilling continuously at a fixed rate.
+///
+/// Methods ending in `_at` take the current time explicitly, which makes
+/// the bucket deterministic in tests; the others use `Instant::now()`.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Construct a full bucket holding up to `capacity` tokens, refilling
+    /// at `rate` tokens per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `capacity` and `rate` are positive and finite.
+    pub fn new(capacity: f64, rate: f64) -> Self {
+        Self::new_at(capacity, rate, Instant::now())
+    }
+
+    /// Like `new`, treating `now` as the current time.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `capacity` and `rate` are positive and finite.
+    pub fn new_at(capacity: f64, rate: f64, now: Instant) -> Self {
+        assert!(
+            capacity > 0.0 && capacity.is_finite(),
+            "capacity {} is not positive and finite",
+            capacity
+        );
+        assert!(
+            rate > 0.0 && rate.is_finite(),
+            "rate {} is not positive and finite",
+            rate
+        );
+        Self {
+            capacity,
+            rate,
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    /// Returns the maximum number of tokens the bucket holds.
+    pub fn capacity(&self) -> f64 {
+        self.capacity
+    }
+
+    /// Returns the refill rate in tokens per second.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Returns the number of tokens available at `now`.
+    pub fn available_at(&mut self, now: Instant) -> f64 {
+        self.refill(now);
+        self.tokens
+    }
+
+    /// Spends `tokens` if that many are available now, returning whether
+    /// they were spent.
+    pub fn try_acquire(&mut self, tokens: f64) -> bool {
+        self.try_acquire_at(tokens, Instant::now())
+    }
+
+    /// Like `try_acquire`, treating `now` as the current time.
+    pub fn try_acquire_at(&mut self, tokens: f64, now: Instant) -> bool {
+        self.refill(now);
+        if tokens > self.tokens {
+            return false;
+        }
+        self.tokens -= tokens;
+        true
+    }
+
+    /// Returns how long from `now` until `tokens` are available, which is
+    /// zero if they already are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tokens` exceeds the capacity, as they would never be
+    /// available.
+    pub fn time_until_at(&mut self, tokens: f64, now: Instant) -> Duration {
+        self.assert_within_capacity(tokens);
+        self.refill(now);
+        let missing = (tokens - self.tokens).max(0.0);
+        Duration::from_secs_f64(missing / self.rate)
+    }
+
+    /// Blocks the thread until `tokens` are available, then spends them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tokens` exceeds the capacity, as they would never be
+    /// available.
+    pub fn acquire(&mut self, tokens: f64) {
+        loop {
+            let now = Instant::now();
+            let wait = self.time_until_at(tokens, now);
+            if wait.is_zero() && self.try_acquire_at(tokens, now) {
+                return;
+            }
+            std::thread::sleep(wait.max(Duration::from_micros(1)));
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.capacity);
+        self.last_refill = self.last_refill.max(now);
+    }
+
+    fn assert_within_capacity(&self, tokens: f64) {
+        assert!(
+            tokens <= self.capacity,
+            "{} tokens exceed the bucket capacity of {}",
+            tokens,
+            self.capacity
+        );
+    }
+}
+
+/// A reader or writer throttled by a `TokenBucket`, spending one token per
+/// byte.
+///
+/// Each read or write moves at most `capacity` bytes, blocking until the
+/// bucket allows them. Only the bytes actually moved are charged: tokens
+/// for a call which fails or moves fewer bytes go back to the bucket.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+/// use rate_limiter::{Throttled, TokenBucket};
+///
+/// // 64 KiB bursts, 1 MiB/s sustained.
+/// let bucket = TokenBucket::new(65_536.0, 1_048_576.0);
+/// let mut out = Throttled::new(Vec::new(), bucket);
+/// out.write_all(b"row 0\nrow 1\n").unwrap();
+/// assert_eq!(out.into_inner(), b"row 0\nrow 1\n");
+/// ```
+///
+/// Failed calls cost nothing:
+/// ```
+/// use std::io::{self, Read};
+/// use std::time::Instant;
+/// use rate_limiter::{Throttled, TokenBucket};
+///
+/// struct Interrupted;
+/// impl Read for Interrupted {
+///     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+///         Err(io::ErrorKind::Interrupted.into())
+///     }
+/// }
+///
+/// let mut input = Throttled::new(Interrupted, TokenBucket::new(4.0, 1e-9));
+/// for _ in 0..3 {
+///     assert!(input.read(&mut [0; 4]).is_err());
+/// }
+/// assert_eq!(input.bucket_mut().available_at(Instant::now()), 4.0);
+/// ```
+#[derive(Debug)]
+pub struct Throttled<T> {
+    inner: T,
+    bucket: TokenBucket,
+}
+
+impl<T> Throttled<T> {
+    /// Wraps `inner`, throttling it with `bucket`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bucket cannot hold a single token, as no byte could
+    /// ever be moved.
+    pub fn new(inner: T, bucket: TokenBucket) -> Self {
+        bucket.assert_within_capacity(1.0);
+        Self { inner, bucket }
+    }
+
+    /// Returns a reference to the wrapped reader or writer.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the bucket throttling the wrapped reader or writer.
+    pub fn bucket_mut(&mut self) -> &mut TokenBucket {
+        &mut self.bucket
+    }
+
+    /// Unwraps the reader or writer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Waits for and spends the tokens to move as much of a buffer of `len`
+    /// bytes as the capacity allows, returning how many bytes that is.
+    fn acquire_chunk(&mut self, len: usize) -> usize {
+        let chunk = len.min(self.bucket.capacity as usize);
+        if chunk > 0 {
+            self.bucket.acquire(chunk as f64);
+        }
+        chunk
+    }
+
+    /// Returns the tokens for bytes which were acquired but not moved.
+    fn refund(&mut self, unused: usize) {
+        self.bucket.tokens = (self.bucket.tokens + unused as f64).min(self.bucket.capacity);
+    }
+}
+
+impl<R: Read> Read for Throttled<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let chunk = self.acquire_chunk(buf.len());
+        let result = self.inner.read(&mut buf[..chunk]);
+        self.refund(chunk - result.as_ref().map_or(0, |&read| read));
+        result
+    }
+}
+
+impl<W: Write> Write for Throttled<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let chunk = self.acquire_chunk(buf.len());
+        let result = self.inner.write(&buf[..chunk]);
+        self.refund(chunk - result.as_ref().map_or(0, |&written| written));
+        result
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
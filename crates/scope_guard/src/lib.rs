@@ -0,0 +1,219 @@
+//! RAII scope guards which run a closure when they go out of scope.
+//!
+//! A `ScopeGuard` owns a value and a closure. When the guard is dropped the
+//! closure is called with the value, depending on its `Strategy`: always,
+//! only when the scope is left normally (`OnSuccess`) or only while
+//! unwinding from a panic (`OnUnwind`). The guard dereferences to the value
+//! so it can be used in the meantime.
+//!
+//! # Example
+//!
+//! Temporarily viewing a matrix in another shape, restoring the original
+//! shape however the scope is left:
+//! ```
+//! use scope_guard::guard;
+//! use two_dim_array::TwoDimensionalArray;
+//!
+//! let mut a = [1, 2, 3, 4, 5, 6];
+//! let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+//! {
+//!     let (num_rows, num_cols) = x.shape();
+//!     let mut flat = guard(&mut x, |x| x.reshape(num_rows, num_cols).unwrap());
+//!     flat.reshape(1, 6).unwrap();
+//!     assert_eq!(flat.get_panic(0, ..), [1, 2, 3, 4, 5, 6]);
+//! }
+//! assert_eq!(x.shape(), (2, 3));
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+
+/// Decides whether a `ScopeGuard` runs its closure when dropped.
+pub trait Strategy {
+    /// Returns whether the closure should run.
+    fn should_run() -> bool;
+}
+
+/// Always run the closure.
+#[derive(Debug)]
+pub enum Always {}
+
+/// Run the closure only when the scope is left without panicking.
+#[derive(Debug)]
+pub enum OnSuccess {}
+
+/// Run the closure only while unwinding from a panic.
+#[derive(Debug)]
+pub enum OnUnwind {}
+
+impl Strategy for Always {
+    fn should_run() -> bool {
+        true
+    }
+}
+
+impl Strategy for OnSuccess {
+    fn should_run() -> bool {
+        !std::thread::panicking()
+    }
+}
+
+impl Strategy for OnUnwind {
+    fn should_run() -> bool {
+        std::thread::panicking()
+    }
+}
+
+/// A guard owning `value` which calls `dropfn(value)` when dropped, if the
+/// strategy `S` allows it.
+///
+/// Created with `guard`, `guard_on_success` or `guard_on_unwind`.
+pub struct ScopeGuard<T, F: FnOnce(T), S: Strategy = Always> {
+    value: ManuallyDrop<T>,
+    dropfn: ManuallyDrop<F>,
+    strategy: PhantomData<fn(S) -> S>,
+}
+
+impl<T, F: FnOnce(T), S: Strategy> ScopeGuard<T, F, S> {
+    /// Construct a guard with an explicit strategy.
+    pub fn with_strategy(value: T, dropfn: F) -> Self {
+        Self {
+            value: ManuallyDrop::new(value),
+            dropfn: ManuallyDrop::new(dropfn),
+            strategy: PhantomData,
+        }
+    }
+
+    /// Dismisses the guard without running its closure, returning the
+    /// value.
+    ///
+    /// This is an associated function so it does not shadow a method of the
+    /// value reached through `Deref`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use scope_guard::{guard, ScopeGuard};
+    /// let mut ran = false;
+    /// let g = guard(5, |_| ran = true);
+    /// assert_eq!(ScopeGuard::into_inner(g), 5);
+    /// assert!(!ran);
+    /// ```
+    pub fn into_inner(guard: Self) -> T {
+        let mut guard = ManuallyDrop::new(guard);
+        // SAFETY: `guard` is never dropped, so both fields are taken
+        // exactly once here.
+        unsafe {
+            let value = ManuallyDrop::take(&mut guard.value);
+            ManuallyDrop::drop(&mut guard.dropfn);
+            value
+        }
+    }
+}
+
+/// Returns a guard which calls `dropfn(value)` when dropped.
+pub fn guard<T, F: FnOnce(T)>(value: T, dropfn: F) -> ScopeGuard<T, F, Always> {
+    ScopeGuard::with_strategy(value, dropfn)
+}
+
+/// Returns a guard which calls `dropfn(value)` when dropped, unless the
+/// thread is panicking.
+///
+/// # Example
+///
+/// ```
+/// use scope_guard::guard_on_success;
+/// let mut log = Vec::new();
+/// {
+///     let _commit = guard_on_success(&mut log, |log| log.push("committed"));
+/// }
+/// assert_eq!(log, ["committed"]);
+/// ```
+pub fn guard_on_success<T, F: FnOnce(T)>(value: T, dropfn: F) -> ScopeGuard<T, F, OnSuccess> {
+    ScopeGuard::with_strategy(value, dropfn)
+}
+
+/// Returns a guard which calls `dropfn(value)` when dropped while the
+/// thread is panicking, for rolling back partial work.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use scope_guard::guard_on_unwind;
+///
+/// let rolled_back = Cell::new(false);
+/// let result = catch_unwind(AssertUnwindSafe(|| {
+///     let _rollback = guard_on_unwind((), |()| rolled_back.set(true));
+///     panic!("partial update failed");
+/// }));
+/// assert!(result.is_err());
+/// assert!(rolled_back.get());
+/// ```
+pub fn guard_on_unwind<T, F: FnOnce(T)>(value: T, dropfn: F) -> ScopeGuard<T, F, OnUnwind> {
+    ScopeGuard::with_strategy(value, dropfn)
+}
+
+/// Runs the given statements when the enclosing scope is left, like a
+/// `guard` holding no value.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use scope_guard::defer;
+///
+/// let order = RefCell::new(Vec::new());
+/// {
+///     defer! { order.borrow_mut().push("deferred"); }
+///     order.borrow_mut().push("body");
+/// }
+/// assert_eq!(*order.borrow(), ["body", "deferred"]);
+/// ```
+#[macro_export]
+macro_rules! defer {
+    ($($body:tt)*) => {
+        let _guard = $crate::guard((), |()| { $($body)* });
+    };
+}
+
+impl<T, F: FnOnce(T), S: Strategy> Deref for ScopeGuard<T, F, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, F: FnOnce(T), S: Strategy> DerefMut for ScopeGuard<T, F, S> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T, F: FnOnce(T), S: Strategy> Drop for ScopeGuard<T, F, S> {
+    fn drop(&mut self) {
+        // SAFETY: `drop` runs at most once and `into_inner` prevents it from
+        // running after the fields have been taken.
+        let (value, dropfn) = unsafe {
+            (
+                ManuallyDrop::take(&mut self.value),
+                ManuallyDrop::take(&mut self.dropfn),
+            )
+        };
+        if S::should_run() {
+            dropfn(value);
+        }
+    }
+}
+
+impl<T: fmt::Debug, F: FnOnce(T), S: Strategy> fmt::Debug for ScopeGuard<T, F, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopeGuard")
+            .field("value", &*self.value)
+            .finish_non_exhaustive()
+    }
+}
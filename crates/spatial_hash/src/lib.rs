@@ -0,0 +1,231 @@
+//! A spatial hash: 2D points bucketed into square cells of a fixed size,
+//! for broad-phase collision detection and neighbour lookups over moving
+//! particles.
+//!
+//! Points are `[x, y]` arrays. Cell `(cx, cy)` covers
+//! `cx * cell_size <= x < (cx + 1) * cell_size`, and likewise for `y`.
+//!
+//! With the `two_dim_array` feature, `SpatialHash::rasterize_occupancy`
+//! writes the number of points in each cell of a region into a
+//! `TwoDimensionalArray`.
+//!
+//! # Example
+//! ```
+//! use spatial_hash::SpatialHash;
+//!
+//! let mut particles = SpatialHash::new(1.0);
+//! let a = particles.insert([0.5, 0.5], "a");
+//! let b = particles.insert([1.2, 0.4], "b");
+//! let _c = particles.insert([9.0, 9.0], "c");
+//!
+//! let mut near: Vec<_> = particles
+//!     .query_radius([0.6, 0.6], 1.0)
+//!     .map(|(_, _, name)| *name)
+//!     .collect();
+//! near.sort();
+//! assert_eq!(near, ["a", "b"]);
+//!
+//! particles.set_position(b, [8.5, 9.0]);
+//! assert_eq!(particles.remove(a), Some("a"));
+//! assert_eq!(particles.query_radius([0.6, 0.6], 1.0).count(), 0);
+//! ```
+
+use std::collections::HashMap;
+
+/// Identifies a point inserted into a `SpatialHash`. Ids are never reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntryId(u64);
+
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    position: [f64; 2],
+    value: T,
+}
+
+/// 2D points carrying values of type `T`, bucketed by cell.
+#[derive(Debug, Clone)]
+pub struct SpatialHash<T> {
+    cell_size: f64,
+    buckets: HashMap<(i64, i64), Vec<EntryId>>,
+    entries: HashMap<EntryId, Entry<T>>,
+    next_id: u64,
+}
+
+impl<T> SpatialHash<T> {
+    /// Construct an empty spatial hash with square cells of side
+    /// `cell_size`. Radius queries are fastest when the radius is about
+    /// the cell size.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `cell_size` is positive and finite.
+    pub fn new(cell_size: f64) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "cell size {} is not positive and finite",
+            cell_size
+        );
+        Self {
+            cell_size,
+            buckets: HashMap::new(),
+            entries: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the side length of the cells.
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the cell containing `position`.
+    pub fn cell_of(&self, position: [f64; 2]) -> (i64, i64) {
+        cell_of(self.cell_size, position)
+    }
+
+    /// Inserts a point at `position` carrying `value`, returning its id.
+    pub fn insert(&mut self, position: [f64; 2], value: T) -> EntryId {
+        let id = EntryId(self.next_id);
+        self.next_id += 1;
+        self.buckets
+            .entry(self.cell_of(position))
+            .or_default()
+            .push(id);
+        self.entries.insert(id, Entry { position, value });
+        id
+    }
+
+    /// Removes the point `id`, returning its value, or `None` if it is not
+    /// present.
+    pub fn remove(&mut self, id: EntryId) -> Option<T> {
+        let entry = self.entries.remove(&id)?;
+        self.unlink(id, self.cell_of(entry.position));
+        Some(entry.value)
+    }
+
+    /// Moves the point `id` to `position`, returning `false` if it is not
+    /// present.
+    pub fn set_position(&mut self, id: EntryId, position: [f64; 2]) -> bool {
+        let new_cell = self.cell_of(position);
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return false;
+        };
+        let old_cell = cell_of(self.cell_size, entry.position);
+        entry.position = position;
+        if old_cell != new_cell {
+            self.unlink(id, old_cell);
+            self.buckets.entry(new_cell).or_default().push(id);
+        }
+        true
+    }
+
+    /// Returns the position and value of the point `id`, or `None` if it is
+    /// not present.
+    pub fn get(&self, id: EntryId) -> Option<([f64; 2], &T)> {
+        self.entries
+            .get(&id)
+            .map(|entry| (entry.position, &entry.value))
+    }
+
+    /// Returns the value of the point `id` mutably, or `None` if it is not
+    /// present.
+    pub fn get_mut(&mut self, id: EntryId) -> Option<&mut T> {
+        self.entries.get_mut(&id).map(|entry| &mut entry.value)
+    }
+
+    /// Returns an iterator over the points in `cell`.
+    pub fn cell_entries(&self, cell: (i64, i64)) -> impl Iterator<Item = (EntryId, [f64; 2], &T)> {
+        self.buckets.get(&cell).into_iter().flatten().map(|id| {
+            let entry = &self.entries[id];
+            (*id, entry.position, &entry.value)
+        })
+    }
+
+    /// Returns an iterator over the points within `radius` of `center`,
+    /// inclusive, in no particular order.
+    pub fn query_radius(
+        &self,
+        center: [f64; 2],
+        radius: f64,
+    ) -> impl Iterator<Item = (EntryId, [f64; 2], &T)> {
+        let (min_x, min_y) = self.cell_of([center[0] - radius, center[1] - radius]);
+        let (max_x, max_y) = self.cell_of([center[0] + radius, center[1] + radius]);
+        let radius_squared = radius * radius;
+        (min_y..=max_y)
+            .flat_map(move |cy| (min_x..=max_x).map(move |cx| (cx, cy)))
+            .flat_map(|cell| self.cell_entries(cell))
+            .filter(move |(_, position, _)| {
+                let (dx, dy) = (position[0] - center[0], position[1] - center[1]);
+                dx * dx + dy * dy <= radius_squared
+            })
+    }
+
+    /// Returns an iterator over the occupied cells and the number of points
+    /// in each, in no particular order.
+    pub fn occupied_cells(&self) -> impl Iterator<Item = ((i64, i64), usize)> {
+        self.buckets.iter().map(|(&cell, ids)| (cell, ids.len()))
+    }
+
+    /// Removes `id` from the bucket of `cell`, dropping the bucket once it
+    /// is empty.
+    fn unlink(&mut self, id: EntryId, cell: (i64, i64)) {
+        if let Some(bucket) = self.buckets.get_mut(&cell) {
+            bucket.retain(|&other| other != id);
+            if bucket.is_empty() {
+                self.buckets.remove(&cell);
+            }
+        }
+    }
+}
+
+fn cell_of(cell_size: f64, position: [f64; 2]) -> (i64, i64) {
+    (
+        (position[0] / cell_size).floor() as i64,
+        (position[1] / cell_size).floor() as i64,
+    )
+}
+
+#[cfg(feature = "two_dim_array")]
+impl<T> SpatialHash<T> {
+    /// Writes the number of points in each cell of a region into `grid`,
+    /// where element `(row, col)` is cell `(origin.0 + col, origin.1 + row)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use spatial_hash::SpatialHash;
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// let mut particles = SpatialHash::new(10.0);
+    /// particles.insert([5.0, 5.0], ());
+    /// particles.insert([6.0, 7.0], ());
+    /// particles.insert([25.0, 15.0], ());
+    ///
+    /// let mut counts = [0; 6];
+    /// let mut grid = TwoDimensionalArray::new(&mut counts, 2, 3).unwrap();
+    /// particles.rasterize_occupancy((0, 0), &mut grid);
+    /// assert_eq!(counts, [2, 0, 0, 0, 0, 1]);
+    /// ```
+    pub fn rasterize_occupancy(
+        &self,
+        origin: (i64, i64),
+        grid: &mut two_dim_array::TwoDimensionalArray<'_, usize>,
+    ) {
+        for row in 0..grid.num_rows() {
+            for (col, count) in grid.get_mut_panic(row, ..).iter_mut().enumerate() {
+                let cell = (origin.0 + col as i64, origin.1 + row as i64);
+                *count = self.buckets.get(&cell).map_or(0, Vec::len);
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::error::check_shape;
+use crate::{ShapeError, TwoDimensionalArray};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The direction of a per-axis operation such as `fold_axis`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Along each row, producing one result per row.
+    Row,
+    /// Down each column, producing one result per column.
+    Col,
+}
+
+/// Reductions along the rows or columns of the array.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Folds each row or column into an accumulator starting from `init`,
+    /// visiting elements in index order, and returns one accumulator per
+    /// row for `Axis::Row` or per column for `Axis::Col`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Axis, TwoDimensionalArray};
+    /// let mut a = [0b001u8, 0b010, 0b100, 0b100];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// assert_eq!(x.fold_axis(Axis::Row, 0, |acc, &bits| acc | bits), [0b011, 0b100]);
+    /// assert_eq!(x.fold_axis(Axis::Col, 0, |acc, &bits| acc | bits), [0b101, 0b110]);
+    ///
+    /// let mut w = ["a", "b", "c", "d"];
+    /// let words = TwoDimensionalArray::new(&mut w, 2, 2).unwrap();
+    /// let joined = words.fold_axis(Axis::Col, String::new(), |acc, word| acc + word);
+    /// assert_eq!(joined, ["ac", "bd"]);
+    ///
+    /// // Each column of an array without rows folds to `init`.
+    /// let empty = TwoDimensionalArray::<u8>::new(&mut [], 0, 3).unwrap();
+    /// assert_eq!(empty.fold_axis(Axis::Col, 7, |acc, &elem| acc + elem), [7, 7, 7]);
+    /// assert!(empty.fold_axis(Axis::Row, 7, |acc, &elem| acc + elem).is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn fold_axis<A, F>(&self, axis: Axis, init: A, mut f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: FnMut(A, &T) -> A,
+    {
+        match axis {
+            Axis::Row => (0..self.num_rows)
+                .map(|row_idx| {
+                    self.get_panic(row_idx, ..)
+                        .iter()
+                        .fold(init.clone(), &mut f)
+                })
+                .collect(),
+            Axis::Col => (0..self.num_cols)
+                .map(|col_idx| {
+                    (0..self.num_rows)
+                        .map(|row_idx| &self.buffer[row_idx * self.num_cols + col_idx])
+                        .fold(init.clone(), &mut f)
+                })
+                .collect(),
+        }
+    }
+
+    /// Replaces each element with the running combination of the elements
+    /// before it along `axis`, so element `i` becomes
+    /// `f(...f(f(init, x_0), x_1)..., x_i)`. Generalises cumulative sums to
+    /// running maxima, products and carry-style propagation.
+    ///
+    /// See `scan_axis_into` for accumulators of another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Axis, TwoDimensionalArray};
+    /// let mut a = [1, 3, 2, 4, 0, 5];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.scan_axis(Axis::Row, 0, |&acc, &elem| acc.max(elem));
+    /// assert_eq!(x.as_slice(), [1, 3, 3, 4, 4, 5]);
+    ///
+    /// x.scan_axis(Axis::Col, 0, |&acc, &elem| acc + elem);
+    /// assert_eq!(x.as_slice(), [1, 3, 3, 5, 7, 8]);
+    /// ```
+    pub fn scan_axis<F>(&mut self, axis: Axis, init: T, mut f: F)
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> T,
+    {
+        let (outer, inner, outer_stride, inner_stride) = self.scan_layout(axis);
+        for start in (0..outer).map(|idx| idx * outer_stride) {
+            let mut acc = init.clone();
+            for offset in (0..inner).map(|idx| start + idx * inner_stride) {
+                acc = f(&acc, &self.buffer[offset]);
+                self.buffer[offset].clone_from(&acc);
+            }
+        }
+    }
+
+    /// Writes the running combination of the elements along `axis` into
+    /// `out`, which may hold a different element type. See `scan_axis`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `out` does not have the same
+    /// shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Axis, TwoDimensionalArray};
+    /// let mut a = [true, false, true, true, true, false];
+    /// let mut c = [0; 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut c, 2, 3).unwrap();
+    ///
+    /// // Length of the run of `true` ending at each element.
+    /// x.scan_axis_into(Axis::Row, 0, |&run, &set| if set { run + 1 } else { 0 }, &mut out)
+    ///     .unwrap();
+    /// assert_eq!(c, [1, 0, 1, 1, 2, 0]);
+    /// ```
+    pub fn scan_axis_into<A, F>(
+        &self,
+        axis: Axis,
+        init: A,
+        mut f: F,
+        out: &mut TwoDimensionalArray<'_, A>,
+    ) -> Result<(), ShapeError>
+    where
+        A: Clone,
+        F: FnMut(&A, &T) -> A,
+    {
+        check_shape(self.shape(), out.shape())?;
+        let (outer, inner, outer_stride, inner_stride) = self.scan_layout(axis);
+        for start in (0..outer).map(|idx| idx * outer_stride) {
+            let mut acc = init.clone();
+            for offset in (0..inner).map(|idx| start + idx * inner_stride) {
+                acc = f(&acc, &self.buffer[offset]);
+                out.buffer[offset].clone_from(&acc);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of lines, their length, and the buffer strides
+    /// between and within lines along `axis`.
+    fn scan_layout(&self, axis: Axis) -> (usize, usize, usize, usize) {
+        match axis {
+            Axis::Row => (self.num_rows, self.num_cols, self.num_cols, 1),
+            Axis::Col => (self.num_cols, self.num_rows, 1, self.num_cols),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use core::ops::{Add, Mul};
+
+use crate::error::check_shape;
+use crate::{ShapeError, TwoDimensionalArray};
+
+/// Broadcasted elementwise operations, applying a row vector to
+/// every row or a column vector to every column.
+impl<T: Copy> TwoDimensionalArray<'_, T> {
+    /// Adds `row` to every row of the array in place.
+    ///
+    /// See `add_row_vector_into` to write the result into another array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `row.len()` is not
+    /// `num_cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.add_row_vector(&[10, 20]).unwrap();
+    /// assert_eq!(a, [11, 22, 13, 24]);
+    /// ```
+    pub fn add_row_vector(&mut self, row: &[T]) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.broadcast_row(row, |a, b| a + b)
+    }
+
+    /// Multiplies every row of the array elementwise by `row` in place.
+    ///
+    /// See `mul_row_vector_into` to write the result into another array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `row.len()` is not
+    /// `num_cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.mul_row_vector(&[10, 20]).unwrap();
+    /// assert_eq!(a, [10, 40, 30, 80]);
+    /// ```
+    pub fn mul_row_vector(&mut self, row: &[T]) -> Result<(), ShapeError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.broadcast_row(row, |a, b| a * b)
+    }
+
+    /// Adds `col[i]` to every element of row `i` in place.
+    ///
+    /// See `add_col_vector_into` to write the result into another array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `col.len()` is not
+    /// `num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.add_col_vector(&[10, 20]).unwrap();
+    /// assert_eq!(a, [11, 12, 23, 24]);
+    /// ```
+    pub fn add_col_vector(&mut self, col: &[T]) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.broadcast_col(col, |a, b| a + b)
+    }
+
+    /// Multiplies every element of row `i` by `col[i]` in place.
+    ///
+    /// See `mul_col_vector_into` to write the result into another array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `col.len()` is not
+    /// `num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.mul_col_vector(&[10, 20]).unwrap();
+    /// assert_eq!(a, [10, 20, 60, 80]);
+    /// ```
+    pub fn mul_col_vector(&mut self, col: &[T]) -> Result<(), ShapeError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.broadcast_col(col, |a, b| a * b)
+    }
+
+    /// Writes the result of `add_row_vector` into `out`, leaving `self`
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `out` does not have the
+    /// same shape as `self` and `ShapeError::LengthMismatch` when
+    /// `row.len()` is not `num_cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.add_row_vector_into(&[10, 20], &mut out).unwrap();
+    /// assert_eq!(b, [11, 22, 13, 24]);
+    /// ```
+    pub fn add_row_vector_into(
+        &self,
+        row: &[T],
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.check_row_vector(row)?;
+        self.copy_into(out)?;
+        out.add_row_vector(row)
+    }
+
+    /// Writes the result of `mul_row_vector` into `out`, leaving `self`
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `out` does not have the
+    /// same shape as `self` and `ShapeError::LengthMismatch` when
+    /// `row.len()` is not `num_cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.mul_row_vector_into(&[10, 20], &mut out).unwrap();
+    /// assert_eq!(b, [10, 40, 30, 80]);
+    /// ```
+    pub fn mul_row_vector_into(
+        &self,
+        row: &[T],
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.check_row_vector(row)?;
+        self.copy_into(out)?;
+        out.mul_row_vector(row)
+    }
+
+    /// Writes the result of `add_col_vector` into `out`, leaving `self`
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `out` does not have the
+    /// same shape as `self` and `ShapeError::LengthMismatch` when
+    /// `col.len()` is not `num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.add_col_vector_into(&[10, 20], &mut out).unwrap();
+    /// assert_eq!(b, [11, 12, 23, 24]);
+    /// ```
+    pub fn add_col_vector_into(
+        &self,
+        col: &[T],
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.check_col_vector(col)?;
+        self.copy_into(out)?;
+        out.add_col_vector(col)
+    }
+
+    /// Writes the result of `mul_col_vector` into `out`, leaving `self`
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `out` does not have the
+    /// same shape as `self` and `ShapeError::LengthMismatch` when
+    /// `col.len()` is not `num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.mul_col_vector_into(&[10, 20], &mut out).unwrap();
+    /// assert_eq!(b, [10, 20, 60, 80]);
+    /// ```
+    pub fn mul_col_vector_into(
+        &self,
+        col: &[T],
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.check_col_vector(col)?;
+        self.copy_into(out)?;
+        out.mul_col_vector(col)
+    }
+
+    fn broadcast_row(&mut self, row: &[T], op: impl Fn(T, T) -> T) -> Result<(), ShapeError> {
+        self.check_row_vector(row)?;
+        if self.num_cols == 0 {
+            return Ok(());
+        }
+        for dst in self.buffer.chunks_exact_mut(self.num_cols) {
+            for (a, &b) in dst.iter_mut().zip(row) {
+                *a = op(*a, b);
+            }
+        }
+        Ok(())
+    }
+
+    fn broadcast_col(&mut self, col: &[T], op: impl Fn(T, T) -> T) -> Result<(), ShapeError> {
+        self.check_col_vector(col)?;
+        if self.num_cols == 0 {
+            return Ok(());
+        }
+        for (dst, &b) in self.buffer.chunks_exact_mut(self.num_cols).zip(col) {
+            for a in dst {
+                *a = op(*a, b);
+            }
+        }
+        Ok(())
+    }
+
+    fn check_row_vector(&self, row: &[T]) -> Result<(), ShapeError> {
+        if row.len() != self.num_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_cols,
+                found: row.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_col_vector(&self, col: &[T]) -> Result<(), ShapeError> {
+        if col.len() != self.num_rows {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_rows,
+                found: col.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn copy_into(&self, out: &mut TwoDimensionalArray<'_, T>) -> Result<(), ShapeError> {
+        check_shape(self.shape(), out.shape())?;
+        out.buffer.copy_from_slice(self.buffer);
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(feature = "alloc")]
+use alloc::vec;
+use bytemuck::{Pod, PodCastError};
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::error::checked_len;
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// Returns `ShapeError::LengthMismatch` unless `bytes_len` is the number of
+/// bytes in a `num_rows x num_cols` array of `T`.
+fn check_byte_len<T>(bytes_len: usize, num_rows: usize, num_cols: usize) -> Result<(), ShapeError> {
+    let expected = checked_len(num_rows, num_cols)?
+        .checked_mul(size_of::<T>())
+        .ok_or(ShapeError::Overflow { num_rows, num_cols })?;
+    if bytes_len != expected {
+        return Err(ShapeError::LengthMismatch {
+            expected,
+            found: bytes_len,
+        });
+    }
+    Ok(())
+}
+
+/// Maps a failed cast of a byte buffer of the right length, which can only
+/// be misaligned.
+fn misaligned<T>(_: PodCastError) -> ShapeError {
+    ShapeError::Misaligned {
+        align: align_of::<T>(),
+    }
+}
+
+/// Zero-copy casting to and from bytes, available with the `bytemuck`
+/// feature.
+///
+/// The bytes are the elements' in-memory representation, so they are in
+/// native byte order. The `bytes` module gives a portable encoding.
+impl<'a, T: Pod> TwoDimensionalArrayRef<'a, T> {
+    /// Construct a `TwoDimensionalArrayRef` viewing `bytes` as a
+    /// `num_rows x num_cols` array of `T`, without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `bytes` is not
+    /// `num_rows * num_cols * size_of::<T>()` bytes long,
+    /// `ShapeError::Overflow` when that overflows, and
+    /// `ShapeError::Misaligned` when `bytes` is not aligned for `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// let words = [1u16, 2, 3, 4, 5, 6];
+    /// let bytes = TwoDimensionalArrayRef::new(&words, 2, 3).unwrap().as_bytes();
+    /// assert_eq!(bytes.len(), 12);
+    ///
+    /// let x = TwoDimensionalArrayRef::<u16>::from_bytes(bytes, 3, 2).unwrap();
+    /// assert_eq!(x.get(2, 0), Some(&5));
+    ///
+    /// assert!(TwoDimensionalArrayRef::<u16>::from_bytes(bytes, 2, 2).is_err());
+    /// assert!(TwoDimensionalArrayRef::<u16>::from_bytes(&bytes[1..11], 1, 5).is_err());
+    /// ```
+    pub fn from_bytes(
+        bytes: &'a [u8],
+        num_rows: usize,
+        num_cols: usize,
+    ) -> Result<Self, ShapeError> {
+        check_byte_len::<T>(bytes.len(), num_rows, num_cols)?;
+        let buffer = bytemuck::try_cast_slice(bytes).map_err(misaligned::<T>)?;
+        Self::new(buffer, num_rows, num_cols)
+    }
+
+    /// Returns the bytes of the buffer in row-major order.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        bytemuck::cast_slice(self.buffer)
+    }
+}
+
+impl<'a, T: Pod> TwoDimensionalArray<'a, T> {
+    /// Construct a `TwoDimensionalArray` viewing `bytes` as a
+    /// `num_rows x num_cols` array of `T`, without copying. See
+    /// `TwoDimensionalArrayRef::from_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// As for `TwoDimensionalArrayRef::from_bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut words = [0u32; 4];
+    /// let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+    ///
+    /// let mut x = TwoDimensionalArray::<u32>::from_bytes(bytes, 2, 2).unwrap();
+    /// *x.get_mut_panic(1, 0) = 7;
+    /// assert_eq!(words, [0, 0, 7, 0]);
+    /// ```
+    pub fn from_bytes(
+        bytes: &'a mut [u8],
+        num_rows: usize,
+        num_cols: usize,
+    ) -> Result<Self, ShapeError> {
+        check_byte_len::<T>(bytes.len(), num_rows, num_cols)?;
+        let buffer = bytemuck::try_cast_slice_mut(bytes).map_err(misaligned::<T>)?;
+        Self::new(buffer, num_rows, num_cols)
+    }
+
+    /// Returns the bytes of the buffer in row-major order.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.buffer)
+    }
+
+    /// Returns the bytes of the buffer in row-major order, mutably.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0u16; 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.as_bytes_mut().fill(0xff);
+    /// assert_eq!(a, [u16::MAX; 4]);
+    /// ```
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Pod> TwoDimensionalVec<T> {
+    /// Construct a `num_rows x num_cols` `TwoDimensionalVec` by copying
+    /// `bytes`.
+    ///
+    /// The bytes are copied into a buffer aligned for `T`, so unlike
+    /// `TwoDimensionalArrayRef::from_bytes` any alignment is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `bytes` is not
+    /// `num_rows * num_cols * size_of::<T>()` bytes long and
+    /// `ShapeError::Overflow` when that overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let bytes = [0, 1u16, 2, 3].map(u16::to_ne_bytes).concat();
+    ///
+    /// // Offset by one byte, so not aligned for `u16`.
+    /// let mut unaligned = vec![0u8];
+    /// unaligned.extend(&bytes);
+    ///
+    /// let x = TwoDimensionalVec::<u16>::from_bytes(&unaligned[1..], 2, 2).unwrap();
+    /// assert_eq!(x.as_slice(), [0, 1, 2, 3]);
+    /// assert_eq!(x.as_bytes(), bytes);
+    /// ```
+    pub fn from_bytes(bytes: &[u8], num_rows: usize, num_cols: usize) -> Result<Self, ShapeError> {
+        check_byte_len::<T>(bytes.len(), num_rows, num_cols)?;
+        let mut buffer = vec![T::zeroed(); num_rows * num_cols];
+        bytemuck::cast_slice_mut(&mut buffer).copy_from_slice(bytes);
+        Self::from_vec(buffer, num_rows, num_cols)
+    }
+
+    /// Returns the bytes of the buffer in row-major order.
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.buffer)
+    }
+
+    /// Returns the bytes of the buffer in row-major order, mutably. See
+    /// `TwoDimensionalArray::as_bytes_mut`.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.buffer)
+    }
+}
@@ -0,0 +1,55 @@
+//! Fixed-width little-endian encoding of elements, used by the binary
+//! formats of the crate.
+
+/// Element types with a fixed-width little-endian byte representation.
+///
+/// Implemented for the primitive integer and floating point types and
+/// `bool`.
+pub trait FixedWidth: Sized {
+    /// The number of bytes in the encoding.
+    const WIDTH: usize;
+
+    /// Writes the encoding of `self` into `buf`, which is `WIDTH` bytes
+    /// long.
+    fn write_le(&self, buf: &mut [u8]);
+
+    /// Decodes a value from `buf`, which is `WIDTH` bytes long, returning
+    /// `None` if the bytes are not a valid encoding.
+    fn read_le(buf: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_fixed_width {
+    ($($t:ty)*) => {
+        $(
+            impl FixedWidth for $t {
+                const WIDTH: usize = core::mem::size_of::<$t>();
+
+                fn write_le(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(buf: &[u8]) -> Option<Self> {
+                    Some(<$t>::from_le_bytes(buf.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_width!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl FixedWidth for bool {
+    const WIDTH: usize = 1;
+
+    fn write_le(&self, buf: &mut [u8]) {
+        buf[0] = *self as u8;
+    }
+
+    fn read_le(buf: &[u8]) -> Option<Self> {
+        match buf {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,359 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{StridedView, StridedViewMut, TwoDimensionalArray};
+
+/// A read-only view of one column of a `TwoDimensionalArray`.
+///
+/// The elements of a column are `num_cols` apart in the buffer, so the
+/// column is walked with a stride rather than borrowed as a slice. Created
+/// by `TwoDimensionalArray::col`.
+///
+/// # Example
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+///
+/// let mut buffer = [1, 2, 3, 4, 5, 6];
+/// let view = TwoDimensionalArray::new(&mut buffer, 3, 2).unwrap();
+/// let col = view.col(1);
+///
+/// assert_eq!(col.len(), 3);
+/// assert_eq!(col.get(2), Some(&6));
+/// assert_eq!(col.iter().sum::<i32>(), 12);
+/// ```
+pub struct Column<'a, T> {
+    view: StridedView<'a, T>,
+}
+
+/// A mutable view of one column of a `TwoDimensionalArray`.
+///
+/// The mutable counterpart of `Column`, created by
+/// `TwoDimensionalArray::col_mut`.
+pub struct ColumnMut<'a, T> {
+    view: StridedViewMut<'a, T>,
+}
+
+impl<T> Clone for Column<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Column<'_, T> {}
+
+impl<'a, T> Column<'a, T> {
+    /// Returns the number of elements in the column, the number of rows of
+    /// the array.
+    pub fn len(&self) -> usize {
+        self.view.num_rows()
+    }
+
+    /// Returns whether the column has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element in row `row_idx`, or `None` if it
+    /// is out of bounds.
+    pub fn get(&self, row_idx: usize) -> Option<&'a T> {
+        self.view.get(row_idx, 0)
+    }
+
+    /// Returns a reference to the element in row `row_idx`. Panics on out
+    /// of bounds access.
+    pub fn get_panic(&self, row_idx: usize) -> &'a T {
+        self.view.get_panic(row_idx, 0)
+    }
+
+    /// Returns an iterator over the elements of the column from the top.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + use<'a, T> {
+        self.view.iter()
+    }
+
+    /// Returns the column as an `n x 1` strided view.
+    pub fn as_strided(&self) -> StridedView<'a, T> {
+        self.view
+    }
+
+    /// Copies the elements of the column into a `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<'a, T> ColumnMut<'a, T> {
+    /// Returns a read-only view of the same column.
+    pub fn as_column(&self) -> Column<'_, T> {
+        Column {
+            view: self.view.as_view(),
+        }
+    }
+
+    /// Converts the view into a read-only view for the full lifetime `'a`.
+    pub fn into_column(self) -> Column<'a, T> {
+        Column {
+            view: self.view.into_view(),
+        }
+    }
+
+    /// Returns the number of elements in the column, the number of rows of
+    /// the array.
+    pub fn len(&self) -> usize {
+        self.view.num_rows()
+    }
+
+    /// Returns whether the column has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element in row `row_idx`, or `None` if it
+    /// is out of bounds.
+    pub fn get(&self, row_idx: usize) -> Option<&T> {
+        self.view.get(row_idx, 0)
+    }
+
+    /// Returns a mutable reference to the element in row `row_idx`, or
+    /// `None` if it is out of bounds.
+    pub fn get_mut(&mut self, row_idx: usize) -> Option<&mut T> {
+        self.view.get_mut(row_idx, 0)
+    }
+
+    /// Returns a reference to the element in row `row_idx`. Panics on out
+    /// of bounds access.
+    pub fn get_panic(&self, row_idx: usize) -> &T {
+        self.view.get_panic(row_idx, 0)
+    }
+
+    /// Returns a mutable reference to the element in row `row_idx`. Panics
+    /// on out of bounds access.
+    pub fn get_mut_panic(&mut self, row_idx: usize) -> &mut T {
+        self.view.get_mut_panic(row_idx, 0)
+    }
+
+    /// Returns an iterator over the elements of the column from the top.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.view.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// column from the top.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        self.view.iter_mut()
+    }
+
+    /// Sets every element of the column to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.iter_mut().for_each(|elem| *elem = value.clone());
+    }
+
+    /// Copies the elements of `src` into the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` does not have one element per row.
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            src.len(),
+            self.len(),
+            "source length does not match the column length"
+        );
+        self.iter_mut()
+            .zip(src)
+            .for_each(|(elem, &value)| *elem = value);
+    }
+
+    /// Returns the column as an `n x 1` mutable strided view.
+    pub fn into_strided(self) -> StridedViewMut<'a, T> {
+        self.view
+    }
+}
+
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns a read-only view of column `col_idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_idx >= num_cols`.
+    pub fn col(&self, col_idx: usize) -> Column<'_, T> {
+        let view = self
+            .sub_view(0..self.num_rows, col_idx..col_idx.saturating_add(1))
+            .unwrap_or_else(|| col_out_of_bounds(col_idx, self.num_cols));
+        Column { view }
+    }
+
+    /// Returns a mutable view of column `col_idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_idx >= num_cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// x.col_mut(1).fill(0);
+    /// *x.col_mut(2).get_mut_panic(0) = 9;
+    /// assert_eq!(a, [1, 0, 9, 4, 0, 6]);
+    /// ```
+    pub fn col_mut(&mut self, col_idx: usize) -> ColumnMut<'_, T> {
+        let (num_rows, num_cols) = self.shape();
+        let view = self
+            .sub_view_mut(0..num_rows, col_idx..col_idx.saturating_add(1))
+            .unwrap_or_else(|| col_out_of_bounds(col_idx, num_cols));
+        ColumnMut { view }
+    }
+}
+
+/// An iterator over the columns of a `TwoDimensionalArray` from the left,
+/// created by `TwoDimensionalArray::cols`.
+pub struct Cols<'a, T> {
+    view: StridedView<'a, T>,
+    remaining: Range<usize>,
+}
+
+impl<'a, T> Cols<'a, T> {
+    fn column(&self, col_idx: usize) -> Column<'a, T> {
+        let view = self
+            .view
+            .sub_view(0..self.view.num_rows(), col_idx..col_idx + 1)
+            .expect("remaining columns are in bounds");
+        Column { view }
+    }
+}
+
+impl<'a, T> Iterator for Cols<'a, T> {
+    type Item = Column<'a, T>;
+
+    fn next(&mut self) -> Option<Column<'a, T>> {
+        let col_idx = self.remaining.next()?;
+        Some(self.column(col_idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Cols<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let col_idx = self.remaining.next_back()?;
+        Some(self.column(col_idx))
+    }
+}
+
+impl<T> ExactSizeIterator for Cols<'_, T> {}
+
+impl<T> FusedIterator for Cols<'_, T> {}
+
+/// An iterator over disjoint mutable views of the columns of a
+/// `TwoDimensionalArray` from the left, created by
+/// `TwoDimensionalArray::cols_mut`.
+///
+/// Each column is split off the remaining columns, so all the yielded
+/// views may be held at once.
+pub struct ColsMut<'a, T> {
+    /// The columns not yet yielded, or `None` once there are none.
+    rest: Option<StridedViewMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for ColsMut<'a, T> {
+    type Item = ColumnMut<'a, T>;
+
+    fn next(&mut self) -> Option<ColumnMut<'a, T>> {
+        let rest = self.rest.take()?;
+        let [_, _, first, rest] = rest.split_quadrants(0, 1);
+        if rest.num_cols() > 0 {
+            self.rest = Some(rest);
+        }
+        Some(ColumnMut { view: first })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.as_ref().map_or(0, |rest| rest.num_cols());
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ColsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take()?;
+        let num_cols = rest.num_cols();
+        let [_, _, rest, last] = rest.split_quadrants(0, num_cols - 1);
+        if rest.num_cols() > 0 {
+            self.rest = Some(rest);
+        }
+        Some(ColumnMut { view: last })
+    }
+}
+
+impl<T> ExactSizeIterator for ColsMut<'_, T> {}
+
+impl<T> FusedIterator for ColsMut<'_, T> {}
+
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator yielding a read-only view of each column, from
+    /// the left.
+    ///
+    /// For mutable views see `cols_mut`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// let sums: Vec<i32> = x.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, [5, 7, 9]);
+    /// ```
+    pub fn cols(&self) -> Cols<'_, T> {
+        Cols {
+            view: self.as_strided(),
+            remaining: 0..self.num_cols,
+        }
+    }
+
+    /// Returns an iterator yielding a mutable view of each column, from the
+    /// left. The views are disjoint, so they may be held at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// let mut cols: Vec<_> = x.cols_mut().collect();
+    /// let [first, .., last] = &mut cols[..] else { unreachable!() };
+    /// for (dst, src) in first.iter_mut().zip(last.iter()) {
+    ///     *dst += *src;
+    /// }
+    /// assert_eq!(a, [4, 2, 3, 10, 5, 6]);
+    /// ```
+    pub fn cols_mut(&mut self) -> ColsMut<'_, T> {
+        let rest = (self.num_cols > 0).then(|| self.as_strided_mut());
+        ColsMut { rest }
+    }
+}
+
+fn col_out_of_bounds(col_idx: usize, num_cols: usize) -> ! {
+    panic!(
+        "column {} out of bounds for array with {} columns",
+        col_idx, num_cols
+    )
+}
@@ -0,0 +1,160 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+
+use crate::TwoDimensionalArray;
+use crate::num::Float;
+
+/// A crossing point of the isoline, identified by the grid edge it lies on.
+/// `Horizontal(r, c)` joins `(r, c)` and `(r, c + 1)`; `Vertical(r, c)`
+/// joins `(r, c)` and `(r + 1, c)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EdgeKey {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Returns the isolines of the array at `threshold`, found by marching
+    /// squares with linear interpolation along the cell edges.
+    ///
+    /// Each contour is a polyline of `[row, col]` points in grid
+    /// coordinates, where element `(r, c)` sits at `[r, c]`. Elements at or
+    /// above `threshold` count as inside, so `NaN` counts as outside.
+    /// Closed contours repeat their first point at the end; open ones start
+    /// and end on the border of the grid. Ambiguous saddle cells are
+    /// resolved by the average of their four corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     0.0, 0.0, 0.0,
+    ///     0.0, 1.0, 0.0,
+    ///     0.0, 0.0, 0.0,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let contours = x.contours(0.5);
+    ///
+    /// // A closed diamond around the peak.
+    /// assert_eq!(contours.len(), 1);
+    /// let diamond = &contours[0];
+    /// assert_eq!(diamond.len(), 5);
+    /// assert_eq!(diamond.first(), diamond.last());
+    /// for point in [[1.0, 1.5], [1.5, 1.0], [1.0, 0.5], [0.5, 1.0]] {
+    ///     assert!(diamond.contains(&point));
+    /// }
+    ///
+    /// // A ramp is cut by one open contour from border to border.
+    /// let mut b = [0.0, 1.0, 2.0, 0.0, 1.0, 2.0];
+    /// let ramp = TwoDimensionalArray::new(&mut b, 2, 3).unwrap();
+    /// assert_eq!(ramp.contours(1.5), [vec![[0.0, 1.5], [1.0, 1.5]]]);
+    /// ```
+    pub fn contours(&self, threshold: T) -> Vec<Vec<[T; 2]>> {
+        let num_cols = self.num_cols;
+        let at = |row: usize, col: usize| self.buffer[row * num_cols + col];
+        let point = |key: EdgeKey| {
+            let (row, col) = match key {
+                EdgeKey::Horizontal(row, col) | EdgeKey::Vertical(row, col) => (row, col),
+            };
+            let (next_row, next_col) = match key {
+                EdgeKey::Horizontal(..) => (row, col + 1),
+                EdgeKey::Vertical(..) => (row + 1, col),
+            };
+            let (a, b) = (at(row, col), at(next_row, next_col));
+            let t = (threshold - a) / (b - a);
+            let (row, col) = (T::from_f64(row as f64), T::from_f64(col as f64));
+            match key {
+                EdgeKey::Horizontal(..) => [row, col + t],
+                EdgeKey::Vertical(..) => [row + t, col],
+            }
+        };
+
+        let mut segments = Vec::new();
+        for row in 0..self.num_rows.saturating_sub(1) {
+            for col in 0..num_cols.saturating_sub(1) {
+                let corners = [
+                    at(row, col),
+                    at(row, col + 1),
+                    at(row + 1, col + 1),
+                    at(row + 1, col),
+                ];
+                let case = corners.iter().fold(0, |case, &value| {
+                    (case << 1) | usize::from(value >= threshold)
+                });
+                let top = EdgeKey::Horizontal(row, col);
+                let right = EdgeKey::Vertical(row, col + 1);
+                let bottom = EdgeKey::Horizontal(row + 1, col);
+                let left = EdgeKey::Vertical(row, col);
+                let centre_inside = || {
+                    let total = corners.iter().fold(T::zero(), |acc, &value| acc + value);
+                    total / T::from_f64(4.0) >= threshold
+                };
+                // Corner bits, from most significant: top left, top right,
+                // bottom right, bottom left.
+                match case {
+                    0b0000 | 0b1111 => {}
+                    0b0001 | 0b1110 => segments.push([left, bottom]),
+                    0b0010 | 0b1101 => segments.push([bottom, right]),
+                    0b0011 | 0b1100 => segments.push([left, right]),
+                    0b0100 | 0b1011 => segments.push([top, right]),
+                    0b0110 | 0b1001 => segments.push([top, bottom]),
+                    0b0111 | 0b1000 => segments.push([left, top]),
+                    0b0101 if centre_inside() => {
+                        segments.push([left, top]);
+                        segments.push([bottom, right]);
+                    }
+                    0b1010 if !centre_inside() => {
+                        segments.push([left, top]);
+                        segments.push([bottom, right]);
+                    }
+                    0b0101 | 0b1010 => {
+                        segments.push([left, bottom]);
+                        segments.push([top, right]);
+                    }
+                    _ => unreachable!("four corners give a four bit case"),
+                }
+            }
+        }
+
+        // Every crossing lies on at most two segments, one per cell sharing
+        // its edge, so the segments link up into chains.
+        let mut touching: BTreeMap<EdgeKey, Vec<usize>> = BTreeMap::new();
+        for (idx, segment) in segments.iter().enumerate() {
+            for &key in segment {
+                touching.entry(key).or_default().push(idx);
+            }
+        }
+        let mut used = vec![false; segments.len()];
+        let trace = |start: usize, from: EdgeKey, used: &mut [bool]| {
+            let mut line = vec![point(from)];
+            let (mut idx, mut key) = (start, from);
+            loop {
+                used[idx] = true;
+                let [a, b] = segments[idx];
+                key = if a == key { b } else { a };
+                line.push(point(key));
+                match touching[&key].iter().find(|&&next| !used[next]) {
+                    Some(&next) => idx = next,
+                    None => return line,
+                }
+            }
+        };
+
+        let mut contours = Vec::new();
+        // Open contours first, starting from their ends on the border.
+        for idx in 0..segments.len() {
+            for key in segments[idx] {
+                if !used[idx] && touching[&key].len() == 1 {
+                    contours.push(trace(idx, key, &mut used));
+                }
+            }
+        }
+        for idx in 0..segments.len() {
+            if !used[idx] {
+                contours.push(trace(idx, segments[idx][0], &mut used));
+            }
+        }
+        contours
+    }
+}
@@ -0,0 +1,269 @@
+//! Reading and writing arrays as CSV, one line per row.
+//!
+//! Fields containing the delimiter, a double quote or a line break are
+//! enclosed in double quotes, with quotes inside doubled, as in RFC 4180.
+//! Lines end with `\n` when writing; `\r\n` is also accepted when reading.
+//! There is no header line.
+//!
+//! Blank lines are skipped when reading, so an array with rows but no
+//! columns, which is written as empty lines, reads back as `0 x 0`. An
+//! empty field in an array with a single column is written as `""` so
+//! that its line is not blank.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalArray;
+//! use two_dim_array::TwoDimensionalVec;
+//! use two_dim_array::csv::CsvFormat;
+//!
+//! let mut a = [1.0, 2.5, -3.0, 4.125];
+//! let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+//!
+//! let mut out = Vec::new();
+//! x.write_csv(&mut out, &CsvFormat::default()).unwrap();
+//! assert_eq!(out, b"1,2.5\n-3,4.125\n");
+//!
+//! let y = TwoDimensionalVec::<f64>::read_csv(out.as_slice(), &CsvFormat::default()).unwrap();
+//! assert_eq!(y, x);
+//! ```
+
+use std::fmt::{Display, Write as _};
+use std::io::{self, Read, Write};
+use std::mem;
+use std::str::FromStr;
+
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+/// Options for reading and writing CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvFormat {
+    /// The character separating fields, such as `,`, `;` or `\t`. It must
+    /// not be a double quote or a line break.
+    pub delimiter: char,
+    /// The number of digits written after the decimal point, or `None` for
+    /// the shortest representation which reads back as the same value.
+    /// Only affects types whose `Display` implementations honour a
+    /// precision, such as the floating point types.
+    pub precision: Option<usize>,
+}
+
+impl Default for CsvFormat {
+    /// Comma separated, with the shortest representation of each value.
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            precision: None,
+        }
+    }
+}
+
+impl<T: Display> TwoDimensionalArrayRef<'_, T> {
+    /// Writes the array to `writer` as CSV. See the `csv` module.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// use two_dim_array::csv::CsvFormat;
+    ///
+    /// let x = TwoDimensionalArrayRef::new(&[0.5, 1.0 / 3.0, 2.0, -1.0], 2, 2).unwrap();
+    /// let format = CsvFormat {
+    ///     delimiter: '\t',
+    ///     precision: Some(2),
+    /// };
+    ///
+    /// let mut out = Vec::new();
+    /// x.write_csv(&mut out, &format).unwrap();
+    /// assert_eq!(out, b"0.50\t0.33\n2.00\t-1.00\n");
+    /// ```
+    pub fn write_csv<W: Write>(&self, mut writer: W, format: &CsvFormat) -> io::Result<()> {
+        let mut line = String::new();
+        let mut field = String::new();
+        for row_idx in 0..self.num_rows {
+            line.clear();
+            for (col_idx, elem) in self.get_panic(row_idx, ..).iter().enumerate() {
+                if col_idx > 0 {
+                    line.push(format.delimiter);
+                }
+                field.clear();
+                match format.precision {
+                    Some(precision) => write!(field, "{:.*}", precision, elem),
+                    None => write!(field, "{}", elem),
+                }
+                .expect("writing to a String cannot fail");
+                if field.is_empty() && self.num_cols == 1 {
+                    // An empty lone field would otherwise be a blank line,
+                    // which is skipped when reading.
+                    line.push_str("\"\"");
+                } else {
+                    push_field(&mut line, &field, format.delimiter);
+                }
+            }
+            line.push('\n');
+            writer.write_all(line.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> TwoDimensionalArray<'_, T> {
+    /// Writes the array to `writer` as CSV. See
+    /// `TwoDimensionalArrayRef::write_csv`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_csv<W: Write>(&self, writer: W, format: &CsvFormat) -> io::Result<()> {
+        self.as_view().write_csv(writer, format)
+    }
+}
+
+impl<T: Display> TwoDimensionalVec<T> {
+    /// Writes the array to `writer` as CSV. See
+    /// `TwoDimensionalArrayRef::write_csv`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_csv<W: Write>(&self, writer: W, format: &CsvFormat) -> io::Result<()> {
+        self.as_view().write_csv(writer, format)
+    }
+}
+
+impl<T: FromStr> TwoDimensionalVec<T> {
+    /// Reads an array from CSV, one row per non-blank line, parsing each
+    /// field with `FromStr`. See the `csv` module.
+    ///
+    /// Fields are parsed exactly as written, so surrounding spaces are
+    /// only accepted by types which accept them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `io::ErrorKind::InvalidData` when the input is not UTF-8,
+    /// when a quoted field is not closed, when a line has a different
+    /// number of fields to the first or when a field cannot be parsed, and
+    /// any error produced by `reader`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// use two_dim_array::csv::CsvFormat;
+    ///
+    /// let format = CsvFormat {
+    ///     delimiter: ';',
+    ///     ..CsvFormat::default()
+    /// };
+    /// let x = TwoDimensionalVec::<i32>::read_csv("1;2;3\r\n4;5;6\r\n".as_bytes(), &format).unwrap();
+    /// assert_eq!(x, [[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert!(TwoDimensionalVec::<i32>::read_csv("1;2\n3\n".as_bytes(), &format).is_err());
+    /// assert!(TwoDimensionalVec::<i32>::read_csv("1;x\n".as_bytes(), &format).is_err());
+    ///
+    /// // Empty fields in a single column survive the round trip.
+    /// let words = TwoDimensionalVec::from_vec(vec![String::new(), "a".into(), String::new()], 3, 1).unwrap();
+    /// let mut out = Vec::new();
+    /// words.write_csv(&mut out, &CsvFormat::default()).unwrap();
+    /// assert_eq!(out, b"\"\"\na\n\"\"\n");
+    /// let read = TwoDimensionalVec::<String>::read_csv(out.as_slice(), &CsvFormat::default()).unwrap();
+    /// assert_eq!(read, words);
+    /// ```
+    pub fn read_csv<R: Read>(mut reader: R, format: &CsvFormat) -> io::Result<Self> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        let mut buffer = Vec::new();
+        let mut num_rows = 0;
+        let mut num_cols = 0;
+        let mut chars = text.chars().peekable();
+        let mut fields = Vec::new();
+        while chars.peek().is_some() {
+            if chars.next_if_eq(&'\n').is_some() {
+                continue;
+            }
+            if chars.clone().take(2).eq(['\r', '\n']) {
+                chars.nth(1);
+                continue;
+            }
+            read_record(&mut chars, format.delimiter, &mut fields)?;
+            if num_rows == 0 {
+                num_cols = fields.len();
+            } else if fields.len() != num_cols {
+                return Err(invalid_data(format!(
+                    "row {} has {} fields but the first row has {}",
+                    num_rows,
+                    fields.len(),
+                    num_cols
+                )));
+            }
+            for (col_idx, field) in fields.drain(..).enumerate() {
+                let elem = field.parse().map_err(|_| {
+                    invalid_data(format!(
+                        "cannot parse {:?} in row {}, column {}",
+                        field, num_rows, col_idx
+                    ))
+                })?;
+                buffer.push(elem);
+            }
+            num_rows += 1;
+        }
+        Ok(Self {
+            buffer,
+            num_rows,
+            num_cols,
+        })
+    }
+}
+
+/// Appends `field` to `line`, quoted if it contains the delimiter, a double
+/// quote or a line break.
+fn push_field(line: &mut String, field: &str, delimiter: char) {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        line.push('"');
+        line.push_str(&field.replace('"', "\"\""));
+        line.push('"');
+    } else {
+        line.push_str(field);
+    }
+}
+
+/// Reads the fields of one line into `fields`, consuming its line ending.
+fn read_record<I>(
+    chars: &mut std::iter::Peekable<I>,
+    delimiter: char,
+    fields: &mut Vec<String>,
+) -> io::Result<()>
+where
+    I: Iterator<Item = char>,
+{
+    let mut field = String::new();
+    let mut in_quotes = false;
+    loop {
+        match chars.next() {
+            Some('"') if in_quotes => {
+                if chars.next_if_eq(&'"').is_some() {
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            Some(c) if in_quotes => field.push(c),
+            None if in_quotes => return Err(invalid_data("unterminated quoted field")),
+            Some('"') if field.is_empty() => in_quotes = true,
+            Some(c) if c == delimiter => fields.push(mem::take(&mut field)),
+            Some('\r') if chars.next_if_eq(&'\n').is_some() => break,
+            Some('\n') | None => break,
+            Some(c) => field.push(c),
+        }
+    }
+    fields.push(field);
+    Ok(())
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
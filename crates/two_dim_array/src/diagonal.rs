@@ -0,0 +1,104 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::ptr::NonNull;
+
+use crate::{StridedView, TwoDimensionalArray};
+
+/// An iterator over the anti-diagonals of an array, created by
+/// `TwoDimensionalArray::anti_diagonals`.
+pub struct AntiDiagonals<'a, T> {
+    buffer: &'a [T],
+    num_rows: usize,
+    num_cols: usize,
+    remaining: Range<usize>,
+}
+
+impl<'a, T> AntiDiagonals<'a, T> {
+    /// Returns the anti-diagonal of the positions with `row + col == sum`.
+    fn anti_diagonal(&self, sum: usize) -> StridedView<'a, T> {
+        let (num_rows, num_cols) = (self.num_rows, self.num_cols);
+        let first_row = sum.saturating_sub(num_cols - 1);
+        let first_col = sum - first_row;
+        let len = sum.min(num_rows - 1) - first_row + 1;
+        // SAFETY: Stepping one row down and one column left moves
+        // `num_cols - 1` elements through the buffer, and the `len` steps
+        // from `(first_row, first_col)` stay within the array.
+        unsafe {
+            StridedView::from_raw_parts(
+                NonNull::from(self.buffer)
+                    .cast()
+                    .add(first_row * num_cols + first_col),
+                len,
+                1,
+                num_cols - 1,
+                0,
+            )
+        }
+    }
+}
+
+impl<'a, T> Iterator for AntiDiagonals<'a, T> {
+    type Item = StridedView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sum = self.remaining.next()?;
+        Some(self.anti_diagonal(sum))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for AntiDiagonals<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let sum = self.remaining.next_back()?;
+        Some(self.anti_diagonal(sum))
+    }
+}
+
+impl<T> ExactSizeIterator for AntiDiagonals<'_, T> {}
+
+impl<T> FusedIterator for AntiDiagonals<'_, T> {}
+
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the anti-diagonals of the array, each the
+    /// positions with the same `row + col`, starting from the top-left
+    /// corner.
+    ///
+    /// Each anti-diagonal is an `n x 1` strided view running from its
+    /// top-right element to its bottom-left one. Every element of an
+    /// anti-diagonal only depends on the previous ones in dynamic
+    /// programming recurrences such as edit distance, so each can be
+    /// processed in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// let runs: Vec<Vec<i32>> = x
+    ///     .anti_diagonals()
+    ///     .map(|diagonal| diagonal.iter().copied().collect())
+    ///     .collect();
+    /// assert_eq!(runs, [vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+    /// ```
+    pub fn anti_diagonals(&self) -> AntiDiagonals<'_, T> {
+        let count = if self.is_empty() {
+            0
+        } else {
+            self.num_rows + self.num_cols - 1
+        };
+        AntiDiagonals {
+            buffer: self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+            remaining: 0..count,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::ShapeError;
+#[cfg(feature = "alloc")]
+use crate::error::check_shape;
+use crate::{PatchError, TwoDimensionalArray};
+
+/// A single changed element, as `((row, col), old, new)`.
+pub type Change<T> = ((usize, usize), T, T);
+
+/// Element-level differences between two arrays of the same shape.
+impl<T: Clone + PartialEq> TwoDimensionalArray<'_, T> {
+    /// Returns the elements which differ between the array and `other`, in
+    /// row-major order, as `((row, col), old, new)` where `old` is the
+    /// element of `self` and `new` that of `other`.
+    ///
+    /// Applying the result to the array with `apply_patch` makes it equal
+    /// to `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` does not have the
+    /// same shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [1, 5, 3, 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// assert_eq!(x.diff(&y).unwrap(), [((0, 1), 2, 5), ((1, 1), 4, 6)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn diff(&self, other: &TwoDimensionalArray<'_, T>) -> Result<Vec<Change<T>>, ShapeError> {
+        check_shape(self.shape(), other.shape())?;
+        let num_cols = self.num_cols;
+        Ok(self
+            .buffer
+            .iter()
+            .zip(other.buffer.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(idx, (old, new))| ((idx / num_cols, idx % num_cols), old.clone(), new.clone()))
+            .collect())
+    }
+
+    /// Applies changes produced by `diff`, writing each `new` value at its
+    /// index.
+    ///
+    /// Every change is checked before anything is written: the index must
+    /// be in bounds and the current element must equal `old`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PatchError::OutOfBounds` for an index outside the array and
+    /// `PatchError::Conflict` when the current element is not the expected
+    /// `old` value. The array is left unmodified on error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{PatchError, TwoDimensionalArray};
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [1, 5, 3, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// let patch = x.diff(&y).unwrap();
+    ///
+    /// x.apply_patch(&patch).unwrap();
+    /// assert_eq!(x.as_slice(), [1, 5, 3, 6]);
+    ///
+    /// // The patch no longer applies now the old values are gone.
+    /// assert!(matches!(
+    ///     x.apply_patch(&patch),
+    ///     Err(PatchError::Conflict { row: 0, col: 1 })
+    /// ));
+    /// ```
+    pub fn apply_patch(&mut self, patch: &[Change<T>]) -> Result<(), PatchError> {
+        for ((row, col), old, _) in patch {
+            let (row, col) = (*row, *col);
+            match self.get(row, col) {
+                None => return Err(PatchError::OutOfBounds { row, col }),
+                Some(current) if current != old => return Err(PatchError::Conflict { row, col }),
+                Some(_) => {}
+            }
+        }
+        for ((row, col), _, new) in patch {
+            self.get_mut_panic(*row, *col).clone_from(new);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,486 @@
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::error::check_shape;
+use crate::{ShapeError, TwoDimensionalArray};
+
+/// Elementwise combinations of two arrays of the same shape.
+impl<T: Clone> TwoDimensionalArray<'_, T> {
+    /// Replaces every element with the minimum of itself and the
+    /// corresponding element of `other`.
+    ///
+    /// Elements are only replaced when `other` compares strictly less,
+    /// so incomparable values (such as `NaN`) keep the value in `self`.
+    ///
+    /// See `elementwise_min_into` to write the result into another array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` does not have the
+    /// same shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 5, 3, 8];
+    /// let mut b = [4, 2, 6, 7];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.elementwise_min(&y).unwrap();
+    /// assert_eq!(a, [1, 2, 3, 7]);
+    /// ```
+    pub fn elementwise_min(&mut self, other: &TwoDimensionalArray<'_, T>) -> Result<(), ShapeError>
+    where
+        T: PartialOrd,
+    {
+        self.combine_with(other, |a, b| b < a)
+    }
+
+    /// Replaces every element with the maximum of itself and the
+    /// corresponding element of `other`.
+    ///
+    /// Elements are only replaced when `other` compares strictly greater,
+    /// so incomparable values (such as `NaN`) keep the value in `self`.
+    ///
+    /// See `elementwise_max_into` to write the result into another array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` does not have the
+    /// same shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 5, 3, 8];
+    /// let mut b = [4, 2, 6, 7];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.elementwise_max(&y).unwrap();
+    /// assert_eq!(a, [4, 5, 6, 8]);
+    /// ```
+    pub fn elementwise_max(&mut self, other: &TwoDimensionalArray<'_, T>) -> Result<(), ShapeError>
+    where
+        T: PartialOrd,
+    {
+        self.combine_with(other, |a, b| b > a)
+    }
+
+    /// Writes the elementwise minimum of `self` and `other` into `out`.
+    /// See `elementwise_min`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` or `out` does not
+    /// have the same shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 5, 3, 8];
+    /// let mut b = [4, 2, 6, 7];
+    /// let mut c = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut c, 2, 2).unwrap();
+    /// x.elementwise_min_into(&y, &mut out).unwrap();
+    /// assert_eq!(c, [1, 2, 3, 7]);
+    /// ```
+    pub fn elementwise_min_into(
+        &self,
+        other: &TwoDimensionalArray<'_, T>,
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: PartialOrd,
+    {
+        self.combine_into(other, out, |a, b| b < a)
+    }
+
+    /// Writes the elementwise maximum of `self` and `other` into `out`.
+    /// See `elementwise_max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` or `out` does not
+    /// have the same shape as `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 5, 3, 8];
+    /// let mut b = [4, 2, 6, 7];
+    /// let mut c = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut c, 2, 2).unwrap();
+    /// x.elementwise_max_into(&y, &mut out).unwrap();
+    /// assert_eq!(c, [4, 5, 6, 8]);
+    /// ```
+    pub fn elementwise_max_into(
+        &self,
+        other: &TwoDimensionalArray<'_, T>,
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: PartialOrd,
+    {
+        self.combine_into(other, out, |a, b| b > a)
+    }
+
+    /// Replaces elements of `self` with those of `other` wherever
+    /// `take_other(self_elem, other_elem)` holds.
+    fn combine_with(
+        &mut self,
+        other: &TwoDimensionalArray<'_, T>,
+        take_other: impl Fn(&T, &T) -> bool,
+    ) -> Result<(), ShapeError> {
+        check_shape(self.shape(), other.shape())?;
+        for (a, b) in self.buffer.iter_mut().zip(other.buffer.iter()) {
+            if take_other(a, b) {
+                a.clone_from(b);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the element of `other` into `out` wherever
+    /// `take_other(self_elem, other_elem)` holds, otherwise that of `self`.
+    fn combine_into(
+        &self,
+        other: &TwoDimensionalArray<'_, T>,
+        out: &mut TwoDimensionalArray<'_, T>,
+        take_other: impl Fn(&T, &T) -> bool,
+    ) -> Result<(), ShapeError> {
+        check_shape(self.shape(), other.shape())?;
+        check_shape(self.shape(), out.shape())?;
+        for ((a, b), dst) in self
+            .buffer
+            .iter()
+            .zip(other.buffer.iter())
+            .zip(out.buffer.iter_mut())
+        {
+            dst.clone_from(if take_other(a, b) { b } else { a });
+        }
+        Ok(())
+    }
+}
+
+/// Elementwise selection between two arrays driven by a boolean mask.
+impl TwoDimensionalArray<'_, bool> {
+    /// Returns a new owned array holding the element of `a` wherever the
+    /// mask is `true` and that of `b` wherever it is `false`, like
+    /// `numpy.where`.
+    ///
+    /// See `select_into` to write the result into an existing array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `a` or `b` does not have the
+    /// same shape as the mask.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut m = [true, false, false, true];
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [-1, -2, -3, -4];
+    /// let mask = TwoDimensionalArray::new(&mut m, 2, 2).unwrap();
+    /// let a = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let b = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    ///
+    /// let selected = mask.select(&a, &b).unwrap();
+    /// assert_eq!(selected.as_slice(), [1, -2, -3, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn select<T: Clone>(
+        &self,
+        a: &TwoDimensionalArray<'_, T>,
+        b: &TwoDimensionalArray<'_, T>,
+    ) -> Result<TwoDimensionalVec<T>, ShapeError> {
+        check_shape(self.shape(), a.shape())?;
+        check_shape(self.shape(), b.shape())?;
+        let buffer = self
+            .buffer
+            .iter()
+            .zip(a.buffer.iter().zip(b.buffer.iter()))
+            .map(|(&m, (a, b))| if m { a.clone() } else { b.clone() })
+            .collect();
+        Ok(TwoDimensionalVec {
+            buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        })
+    }
+
+    /// Writes the element of `a` into `out` wherever the mask is `true` and
+    /// that of `b` wherever it is `false`. See `select`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `a`, `b` or `out` does not
+    /// have the same shape as the mask.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut m = [true, false, false, true];
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [0; 4];
+    /// let mask = TwoDimensionalArray::new(&mut m, 2, 2).unwrap();
+    /// let a = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let b = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    ///
+    /// // Zero out the elements where the mask is false.
+    /// let mut out = [0; 4];
+    /// let mut out_view = TwoDimensionalArray::new(&mut out, 2, 2).unwrap();
+    /// mask.select_into(&a, &b, &mut out_view).unwrap();
+    /// assert_eq!(out, [1, 0, 0, 4]);
+    /// ```
+    pub fn select_into<T: Clone>(
+        &self,
+        a: &TwoDimensionalArray<'_, T>,
+        b: &TwoDimensionalArray<'_, T>,
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError> {
+        check_shape(self.shape(), a.shape())?;
+        check_shape(self.shape(), b.shape())?;
+        check_shape(self.shape(), out.shape())?;
+        for (&m, (dst, (a, b))) in self.buffer.iter().zip(
+            out.buffer
+                .iter_mut()
+                .zip(a.buffer.iter().zip(b.buffer.iter())),
+        ) {
+            dst.clone_from(if m { a } else { b });
+        }
+        Ok(())
+    }
+}
+
+/// Bulk initialisation of every element.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Sets every element to `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.fill(0);
+    /// assert_eq!(a, [0; 4]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.buffer.fill(value);
+    }
+
+    /// Sets every element to a value returned by calling `f` repeatedly,
+    /// in row-major order.
+    pub fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.buffer.fill_with(f);
+    }
+
+    /// Sets every element to `f(row, col)`, in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 9];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// x.fill_with_indexed(|row, col| (row + col) % 2);
+    /// assert_eq!(a, [0, 1, 0, 1, 0, 1, 0, 1, 0]);
+    /// ```
+    pub fn fill_with_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let num_cols = self.num_cols;
+        for (idx, elem) in self.buffer.iter_mut().enumerate() {
+            *elem = f(idx / num_cols, idx % num_cols);
+        }
+    }
+}
+
+/// In-place transformation of every element.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Replaces every element with `f` of its current value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1.0, -2.0, 3.0, -4.0];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.map_inplace(|&value| f64::max(value, 0.0));
+    /// assert_eq!(a, [1.0, 0.0, 3.0, 0.0]);
+    /// ```
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> T,
+    {
+        for elem in self.buffer.iter_mut() {
+            *elem = f(elem);
+        }
+    }
+
+    /// Replaces every element with `f(row, col, value)` of its position and
+    /// current value, in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1; 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.map_inplace_indexed(|row, col, &value| value + 10 * row + col);
+    /// assert_eq!(a, [1, 2, 3, 11, 12, 13]);
+    /// ```
+    pub fn map_inplace_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &T) -> T,
+    {
+        let num_cols = self.num_cols;
+        for (idx, elem) in self.buffer.iter_mut().enumerate() {
+            *elem = f(idx / num_cols, idx % num_cols, elem);
+        }
+    }
+
+    /// Calls `f` with a mutable reference to every element, in row-major
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [vec![1], vec![2]];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 1, 2).unwrap();
+    /// x.for_each_mut(|values| values.push(0));
+    /// assert_eq!(a, [vec![1, 0], vec![2, 0]]);
+    /// ```
+    pub fn for_each_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.buffer.iter_mut().for_each(f);
+    }
+}
+
+/// Shape-checked bulk copies into the array.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Copies every element of `other` into the array.
+    ///
+    /// See `clone_from_array` for types which are only `Clone`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` does not have the
+    /// same shape as `self`, leaving the array unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 4];
+    /// let mut b = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.copy_from(&y).unwrap();
+    /// assert_eq!(a, [1, 2, 3, 4]);
+    /// ```
+    pub fn copy_from(&mut self, other: &TwoDimensionalArray<'_, T>) -> Result<(), ShapeError>
+    where
+        T: Copy,
+    {
+        check_shape(self.shape(), other.shape())?;
+        self.buffer.copy_from_slice(other.buffer);
+        Ok(())
+    }
+
+    /// Clones every element of `other` into the array. See `copy_from`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `other` does not have the
+    /// same shape as `self`, leaving the array unchanged.
+    pub fn clone_from_array(&mut self, other: &TwoDimensionalArray<'_, T>) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        check_shape(self.shape(), other.shape())?;
+        self.buffer.clone_from_slice(other.buffer);
+        Ok(())
+    }
+
+    /// Copies `src` into row `row_idx`.
+    ///
+    /// See `clone_row_from` for types which are only `Clone`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `src` does not have one
+    /// element per column, leaving the array unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_idx` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.copy_row_from(1, &[7, 8, 9]).unwrap();
+    /// assert!(x.copy_row_from(0, &[1, 2]).is_err());
+    /// assert_eq!(a, [0, 0, 0, 7, 8, 9]);
+    /// ```
+    pub fn copy_row_from(&mut self, row_idx: usize, src: &[T]) -> Result<(), ShapeError>
+    where
+        T: Copy,
+    {
+        self.check_row_len(src.len())?;
+        self.get_mut_panic(row_idx, ..).copy_from_slice(src);
+        Ok(())
+    }
+
+    /// Clones `src` into row `row_idx`. See `copy_row_from`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `src` does not have one
+    /// element per column, leaving the array unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_idx` is out of bounds.
+    pub fn clone_row_from(&mut self, row_idx: usize, src: &[T]) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        self.check_row_len(src.len())?;
+        self.get_mut_panic(row_idx, ..).clone_from_slice(src);
+        Ok(())
+    }
+
+    fn check_row_len(&self, len: usize) -> Result<(), ShapeError> {
+        if len != self.num_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_cols,
+                found: len,
+            });
+        }
+        Ok(())
+    }
+}
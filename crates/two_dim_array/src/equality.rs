@@ -0,0 +1,75 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// Implements equality of shape and contents between the array types, and
+/// with nested arrays and `Vec`s of rows, for each listed type.
+macro_rules! impl_partial_eq {
+    ($([$($lt:lifetime)?] $lhs:ty;)*) => {
+        $(
+            impl<'rhs, $($lt,)? T, U> PartialEq<TwoDimensionalArray<'rhs, U>> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &TwoDimensionalArray<'rhs, U>) -> bool {
+                    self.shape() == other.shape() && self.as_slice() == other.as_slice()
+                }
+            }
+
+            impl<'rhs, $($lt,)? T, U> PartialEq<TwoDimensionalArrayRef<'rhs, U>> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &TwoDimensionalArrayRef<'rhs, U>) -> bool {
+                    self.shape() == other.shape() && self.as_slice() == other.as_slice()
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<$($lt,)? T, U> PartialEq<TwoDimensionalVec<U>> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &TwoDimensionalVec<U>) -> bool {
+                    self.shape() == other.shape() && self.as_slice() == other.as_slice()
+                }
+            }
+
+            impl<$($lt,)? T, U, const R: usize, const C: usize> PartialEq<[[U; C]; R]> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &[[U; C]; R]) -> bool {
+                    self.shape() == (R, C) && self.as_slice() == other.as_flattened()
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<$($lt,)? T, U> PartialEq<Vec<Vec<U>>> for $lhs
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &Vec<Vec<U>>) -> bool {
+                    self.num_rows() == other.len()
+                        && other.iter().all(|row| row.len() == self.num_cols())
+                        && self.as_slice().iter().eq(other.iter().flatten())
+                }
+            }
+
+            impl<$($lt,)? T: Eq> Eq for $lhs {}
+        )*
+    };
+}
+
+impl_partial_eq! {
+    ['a] TwoDimensionalArray<'a, T>;
+    ['a] TwoDimensionalArrayRef<'a, T>;
+}
+
+#[cfg(feature = "alloc")]
+impl_partial_eq! {
+    [] TwoDimensionalVec<T>;
+}
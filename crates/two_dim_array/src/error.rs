@@ -0,0 +1,293 @@
+/// Generic error for trying to assign an impossible shape
+/// to `TwoDimensionalArray`.
+///
+/// The error enums of this crate are `#[non_exhaustive]`, so new failure
+/// modes can be added without breaking downstream matches, which need a
+/// wildcard arm.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::{ShapeError, TwoDimensionalArray};
+/// let mut a = [0; 6];
+/// let message = match TwoDimensionalArray::new(&mut a, 4, 2) {
+///     Ok(_) => "fits",
+///     Err(ShapeError::InvalidShape { .. }) => "wrong length",
+///     Err(ShapeError::Overflow { .. }) => "too large",
+///     Err(_) => "other",
+/// };
+/// assert_eq!(message, "wrong length");
+///
+/// let mut b = [0; 1];
+/// assert!(matches!(
+///     TwoDimensionalArray::new(&mut b, usize::MAX, 2),
+///     Err(ShapeError::Overflow { .. })
+/// ));
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ShapeError {
+    /// A buffer of `buffer_len` elements could not be arranged as a
+    /// `num_rows x num_cols` array.
+    InvalidShape {
+        buffer_len: usize,
+        num_rows: usize,
+        num_cols: usize,
+    },
+    /// A one-dimensional operand did not have the length required
+    /// to line up with the rows or columns of the array.
+    LengthMismatch { expected: usize, found: usize },
+    /// Two arrays which must share a shape, `(num_rows, num_cols)`,
+    /// did not.
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// An operation requiring a square array was given a non-square one.
+    NotSquare { num_rows: usize, num_cols: usize },
+    /// `num_rows * num_cols` does not fit in a `usize`.
+    Overflow { num_rows: usize, num_cols: usize },
+    /// A dimension could not be inferred because the number of elements is
+    /// not a multiple of the given dimension.
+    NotDivisible { buffer_len: usize, dim: usize },
+    /// A strided operand was not laid out contiguously in row-major order,
+    /// so it could not be viewed as a buffer.
+    NotContiguous,
+    /// A byte buffer was not aligned for the element type it was cast to.
+    Misaligned { align: usize },
+    /// A dimension did not fit in the integer type used by the destination,
+    /// such as the `u32` width and height of an image.
+    DimensionOverflow { num_rows: usize, num_cols: usize },
+}
+impl core::error::Error for ShapeError {}
+
+impl core::fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidShape {
+                buffer_len,
+                num_rows,
+                num_cols,
+            } => f.write_fmt(format_args!(
+                "Cannot reshape two dimensional array with number of elements {} into {}x{} array",
+                buffer_len, num_rows, num_cols
+            )),
+            Self::LengthMismatch { expected, found } => f.write_fmt(format_args!(
+                "Expected a vector of length {} but found one of length {}",
+                expected, found
+            )),
+            Self::ShapeMismatch { expected, found } => f.write_fmt(format_args!(
+                "Expected a {}x{} array but found a {}x{} array",
+                expected.0, expected.1, found.0, found.1
+            )),
+            Self::NotSquare { num_rows, num_cols } => f.write_fmt(format_args!(
+                "Expected a square array but found a {}x{} array",
+                num_rows, num_cols
+            )),
+            Self::Overflow { num_rows, num_cols } => f.write_fmt(format_args!(
+                "The number of elements of a {}x{} array overflows usize",
+                num_rows, num_cols
+            )),
+            Self::NotDivisible { buffer_len, dim } => f.write_fmt(format_args!(
+                "Cannot infer a dimension: {} elements cannot be split into a dimension of {}",
+                buffer_len, dim
+            )),
+            Self::NotContiguous => {
+                f.write_str("Expected an array laid out contiguously in row-major order")
+            }
+            Self::Misaligned { align } => f.write_fmt(format_args!(
+                "Expected a byte buffer aligned to {} bytes",
+                align
+            )),
+            Self::DimensionOverflow { num_rows, num_cols } => f.write_fmt(format_args!(
+                "The dimensions of a {}x{} array do not fit in the destination",
+                num_rows, num_cols
+            )),
+        }
+    }
+}
+
+/// Error for accessing an element outside the array, returned by
+/// `TwoDimensionalArray::try_get` and `TwoDimensionalArray::try_get_mut`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexError {
+    /// The requested row.
+    pub row: usize,
+    /// The requested column.
+    pub col: usize,
+    /// The shape of the array, `(num_rows, num_cols)`.
+    pub shape: (usize, usize),
+}
+
+impl core::error::Error for IndexError {}
+
+impl core::fmt::Display for IndexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_fmt(format_args!(
+            "Index ({}, {}) is out of bounds for a {}x{} array",
+            self.row, self.col, self.shape.0, self.shape.1
+        ))
+    }
+}
+
+/// Error returned by `TwoDimensionalArray::scatter_rows`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ScatterError {
+    /// The source array did not have one row per index, or its rows did
+    /// not match the destination's.
+    Shape(ShapeError),
+    /// A row index appeared more than once under `DuplicatePolicy::Error`.
+    DuplicateIndex(usize),
+}
+
+impl core::error::Error for ScatterError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Shape(err) => Some(err),
+            Self::DuplicateIndex(_) => None,
+        }
+    }
+}
+
+impl core::fmt::Display for ScatterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Shape(err) => err.fmt(f),
+            Self::DuplicateIndex(row_idx) => f.write_fmt(format_args!(
+                "Row index {} was given more than once",
+                row_idx
+            )),
+        }
+    }
+}
+
+impl From<ShapeError> for ScatterError {
+    fn from(err: ShapeError) -> Self {
+        Self::Shape(err)
+    }
+}
+
+/// Error returned by the linear algebra routines, such as
+/// `TwoDimensionalArray::solve_lower_triangular`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LinalgError {
+    /// The operands had incompatible shapes.
+    Shape(ShapeError),
+    /// The matrix is singular; a zero was found on the diagonal at
+    /// `index`.
+    Singular { index: usize },
+    /// The matrix is not positive-definite; the factorisation broke down
+    /// at diagonal element `index`.
+    NotPositiveDefinite { index: usize },
+}
+
+impl core::error::Error for LinalgError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Shape(err) => Some(err),
+            Self::Singular { .. } | Self::NotPositiveDefinite { .. } => None,
+        }
+    }
+}
+
+impl core::fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Shape(err) => err.fmt(f),
+            Self::Singular { index } => f.write_fmt(format_args!(
+                "Matrix is singular: diagonal element {} is zero",
+                index
+            )),
+            Self::NotPositiveDefinite { index } => f.write_fmt(format_args!(
+                "Matrix is not positive-definite: factorisation failed at diagonal element {}",
+                index
+            )),
+        }
+    }
+}
+
+impl From<ShapeError> for LinalgError {
+    fn from(err: ShapeError) -> Self {
+        Self::Shape(err)
+    }
+}
+
+/// Returns `ShapeError::ShapeMismatch` unless `found` equals `expected`.
+pub(crate) fn check_shape(
+    expected: (usize, usize),
+    found: (usize, usize),
+) -> Result<(), ShapeError> {
+    if expected != found {
+        return Err(ShapeError::ShapeMismatch { expected, found });
+    }
+    Ok(())
+}
+
+/// Returns `ShapeError::NotSquare` unless `shape` is square.
+pub(crate) fn check_square(shape: (usize, usize)) -> Result<(), ShapeError> {
+    if shape.0 != shape.1 {
+        return Err(ShapeError::NotSquare {
+            num_rows: shape.0,
+            num_cols: shape.1,
+        });
+    }
+    Ok(())
+}
+
+/// Error returned by `TwoDimensionalArray::apply_patch`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PatchError {
+    /// A change referred to an element outside the array.
+    OutOfBounds { row: usize, col: usize },
+    /// The element at a change's index did not hold the expected old value.
+    Conflict { row: usize, col: usize },
+}
+
+impl core::error::Error for PatchError {}
+
+impl core::fmt::Display for PatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfBounds { row, col } => f.write_fmt(format_args!(
+                "Patch index ({}, {}) is out of bounds",
+                row, col
+            )),
+            Self::Conflict { row, col } => f.write_fmt(format_args!(
+                "Patch conflict at ({}, {}): element does not hold the expected old value",
+                row, col
+            )),
+        }
+    }
+}
+
+/// Returns `num_rows * num_cols`, or `ShapeError::Overflow` if it does not
+/// fit in a `usize`.
+pub(crate) fn checked_len(num_rows: usize, num_cols: usize) -> Result<usize, ShapeError> {
+    num_rows
+        .checked_mul(num_cols)
+        .ok_or(ShapeError::Overflow { num_rows, num_cols })
+}
+
+/// Returns the shape `(num_rows, num_cols)` of a buffer with `buffer_len`
+/// elements, given `num_rows` (`infer_cols`) or `num_cols`.
+///
+/// A zero dimension is only accepted for an empty buffer, which infers the
+/// other dimension as zero.
+pub(crate) fn infer_shape(
+    buffer_len: usize,
+    dim: usize,
+    infer_cols: bool,
+) -> Result<(usize, usize), ShapeError> {
+    if !buffer_len.is_multiple_of(dim) {
+        return Err(ShapeError::NotDivisible { buffer_len, dim });
+    }
+    let other = buffer_len.checked_div(dim).unwrap_or(0);
+    Ok(if infer_cols {
+        (dim, other)
+    } else {
+        (other, dim)
+    })
+}
@@ -0,0 +1,306 @@
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::error::check_shape;
+use crate::num::Float;
+use crate::{ShapeError, TwoDimensionalArray};
+
+/// How filters read positions which fall outside the array.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderMode<T> {
+    /// Repeat the nearest edge element: `a a | a b c | c c`.
+    Clamp,
+    /// Mirror about the edge element without repeating it:
+    /// `c b | a b c | b a`.
+    Reflect,
+    /// Wrap around to the opposite edge: `b c | a b c | a b`.
+    Wrap,
+    /// Treat every outside position as holding the given value.
+    Constant(T),
+}
+
+impl<T: Copy> BorderMode<T> {
+    /// Maps a possibly out of range index along an axis of length `len`
+    /// onto the index to read, or `None` when the constant should be
+    /// used. `len` must be non-zero.
+    pub(crate) fn resolve(&self, idx: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+        if (0..len).contains(&idx) {
+            return Some(idx as usize);
+        }
+        match self {
+            Self::Clamp => Some(idx.clamp(0, len - 1) as usize),
+            Self::Reflect => {
+                if len == 1 {
+                    return Some(0);
+                }
+                let period = 2 * (len - 1);
+                let folded = idx.rem_euclid(period);
+                Some(if folded < len {
+                    folded
+                } else {
+                    period - folded
+                } as usize)
+            }
+            Self::Wrap => Some(idx.rem_euclid(len) as usize),
+            Self::Constant(_) => None,
+        }
+    }
+
+    /// Reads `line[idx]`, resolving out of range indices with the border
+    /// mode. `line` must not be empty.
+    #[cfg(feature = "std")]
+    pub(crate) fn read(&self, line: &[T], idx: isize) -> T {
+        match (self.resolve(idx, line.len()), self) {
+            (Some(idx), _) => line[idx],
+            (None, Self::Constant(value)) => *value,
+            (None, _) => unreachable!("only constant borders fall outside the array"),
+        }
+    }
+
+    /// Reads `(row, col)` of `array`, resolving out of range positions
+    /// with the border mode. `array` must not be empty.
+    pub(crate) fn read_2d(&self, array: &TwoDimensionalArray<'_, T>, row: isize, col: isize) -> T {
+        let (num_rows, num_cols) = array.shape();
+        match (
+            self.resolve(row, num_rows),
+            self.resolve(col, num_cols),
+            self,
+        ) {
+            (Some(row), Some(col), _) => array.buffer[row * num_cols + col],
+            (_, _, Self::Constant(value)) => *value,
+            _ => unreachable!("only constant borders fall outside the array"),
+        }
+    }
+}
+
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Blurs the array in place with a Gaussian kernel of standard
+    /// deviation `sigma`, in cells.
+    ///
+    /// The blur is applied as two separable one-dimensional passes, along
+    /// rows and then along columns, through a scratch buffer of the same
+    /// size as the array. The kernel is truncated at `3 * sigma` cells, or
+    /// at the larger dimension of the array if that is smaller, and
+    /// normalised, and `border` decides how cells past the edges are read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sigma` is negative or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{BorderMode, TwoDimensionalArray};
+    /// let mut a = [0.0; 25];
+    /// a[12] = 1.0;
+    /// let mut x = TwoDimensionalArray::new(&mut a, 5, 5).unwrap();
+    /// x.gaussian_blur(1.0, BorderMode::Constant(0.0));
+    ///
+    /// // The impulse spreads out symmetrically.
+    /// assert!(*x.get_panic(2, 2) < 1.0);
+    /// assert_eq!(x.get_panic(1, 2), x.get_panic(2, 1));
+    /// assert_eq!(x.get_panic(0, 0), x.get_panic(4, 4));
+    ///
+    /// // Only the truncated tails of the kernel fall outside the array.
+    /// let total: f64 = x.as_slice().iter().sum();
+    /// assert!((total - 1.0).abs() < 0.1);
+    ///
+    /// // A huge sigma is capped by the size of the array.
+    /// x.gaussian_blur(1e300, BorderMode::Clamp);
+    /// assert!(x.as_slice().iter().all(|elem| elem.is_finite()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn gaussian_blur(&mut self, sigma: T, border: BorderMode<T>) {
+        let sigma = sigma.to_f64();
+        assert!(
+            sigma.is_finite() && sigma >= 0.0,
+            "sigma must be finite and non-negative, found {}",
+            sigma
+        );
+        if self.is_empty() {
+            return;
+        }
+        let (num_rows, num_cols) = self.shape();
+        let kernel = gaussian_kernel::<T>(sigma, num_rows.max(num_cols));
+        let radius = (kernel.len() / 2) as isize;
+        let mut scratch = self.buffer.to_vec();
+
+        // Horizontal pass from the array into the scratch buffer.
+        for (src, dst) in self
+            .buffer
+            .chunks_exact(num_cols)
+            .zip(scratch.chunks_exact_mut(num_cols))
+        {
+            for (col, out) in dst.iter_mut().enumerate() {
+                *out = convolve_at(&kernel, radius, col as isize, |idx| border.read(src, idx));
+            }
+        }
+
+        // Vertical pass from the scratch buffer back into the array.
+        let mut column = Vec::with_capacity(num_rows);
+        for col in 0..num_cols {
+            column.clear();
+            column.extend((0..num_rows).map(|row| scratch[row * num_cols + col]));
+            for row in 0..num_rows {
+                self.buffer[row * num_cols + col] =
+                    convolve_at(&kernel, radius, row as isize, |idx| {
+                        border.read(&column, idx)
+                    });
+            }
+        }
+    }
+}
+
+/// Returns the normalised Gaussian kernel of width `2 * radius + 1`, where
+/// the radius is `ceil(3 * sigma)` capped at `max_radius`.
+#[cfg(feature = "std")]
+fn gaussian_kernel<T: Float>(sigma: f64, max_radius: usize) -> Vec<T> {
+    // A large sigma would otherwise ask for a kernel far wider than the
+    // array, which cannot be allocated.
+    let radius = (3.0 * sigma).ceil().min(max_radius as f64) as isize;
+    if radius == 0 {
+        return vec![T::one()];
+    }
+    let weights: Vec<f64> = (-radius..=radius)
+        .map(|offset| {
+            let offset = offset as f64;
+            (-(offset * offset) / (2.0 * sigma * sigma)).exp()
+        })
+        .collect();
+    let total: f64 = weights.iter().sum();
+    weights.iter().map(|w| T::from_f64(w / total)).collect()
+}
+
+/// Correlates `kernel` with the line read by `read` centred on `centre`.
+#[cfg(feature = "std")]
+fn convolve_at<T: Float>(
+    kernel: &[T],
+    radius: isize,
+    centre: isize,
+    read: impl Fn(isize) -> T,
+) -> T {
+    kernel
+        .iter()
+        .enumerate()
+        .fold(T::zero(), |acc, (offset, &weight)| {
+            acc + weight * read(centre + offset as isize - radius)
+        })
+}
+
+/// Horizontal and vertical image gradients, as computed by
+/// `TwoDimensionalArray::sobel`.
+#[cfg(feature = "alloc")]
+pub struct SobelGradient<T> {
+    /// The gradient along each row, positive where values increase with
+    /// the column index.
+    pub x: TwoDimensionalVec<T>,
+    /// The gradient along each column, positive where values increase with
+    /// the row index.
+    pub y: TwoDimensionalVec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> SobelGradient<T> {
+    /// Returns the gradient magnitude `sqrt(x² + y²)` of every cell.
+    pub fn magnitude(&self) -> TwoDimensionalVec<T> {
+        self.combine(|x, y| (x * x + y * y).sqrt())
+    }
+
+    /// Returns the gradient direction `atan2(y, x)` of every cell, in
+    /// radians.
+    pub fn direction(&self) -> TwoDimensionalVec<T> {
+        self.combine(|x, y| y.atan2(x))
+    }
+
+    fn combine(&self, f: impl Fn(T, T) -> T) -> TwoDimensionalVec<T> {
+        TwoDimensionalVec {
+            buffer: self
+                .x
+                .buffer
+                .iter()
+                .zip(&self.y.buffer)
+                .map(|(&x, &y)| f(x, y))
+                .collect(),
+            num_rows: self.x.num_rows,
+            num_cols: self.x.num_cols,
+        }
+    }
+}
+
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Returns the horizontal and vertical gradients of the array computed
+    /// with the 3x3 Sobel operator, for edge detection or slope analysis.
+    ///
+    /// See `sobel_into` to write the gradients into existing arrays.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{BorderMode, TwoDimensionalArray};
+    /// // A ramp increasing by one per column.
+    /// let mut a = [0.0, 1.0, 2.0, 0.0, 1.0, 2.0, 0.0, 1.0, 2.0];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let gradient = x.sobel(BorderMode::Clamp);
+    ///
+    /// assert_eq!(gradient.x.as_slice()[4], 8.0);
+    /// assert_eq!(gradient.y.as_slice()[4], 0.0);
+    /// assert_eq!(gradient.magnitude().as_slice()[4], 8.0);
+    /// assert_eq!(gradient.direction().as_slice()[4], 0.0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sobel(&self, border: BorderMode<T>) -> SobelGradient<T> {
+        let zeros = || TwoDimensionalVec {
+            buffer: vec![T::zero(); self.len()],
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        };
+        let mut gradient = SobelGradient {
+            x: zeros(),
+            y: zeros(),
+        };
+        self.sobel_into(
+            border,
+            &mut gradient.x.as_view_mut(),
+            &mut gradient.y.as_view_mut(),
+        )
+        .expect("gradients have the same shape as the array");
+        gradient
+    }
+
+    /// Writes the horizontal and vertical Sobel gradients of the array into
+    /// `grad_x` and `grad_y`. See `sobel`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` when `grad_x` or `grad_y` does not
+    /// have the same shape as the array.
+    pub fn sobel_into(
+        &self,
+        border: BorderMode<T>,
+        grad_x: &mut TwoDimensionalArray<'_, T>,
+        grad_y: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError> {
+        check_shape(self.shape(), grad_x.shape())?;
+        check_shape(self.shape(), grad_y.shape())?;
+        let two = T::one() + T::one();
+        for row in 0..self.num_rows {
+            for col in 0..self.num_cols {
+                let at = |dr: isize, dc: isize| {
+                    border.read_2d(self, row as isize + dr, col as isize + dc)
+                };
+                let gx = (at(-1, 1) + two * at(0, 1) + at(1, 1))
+                    - (at(-1, -1) + two * at(0, -1) + at(1, -1));
+                let gy = (at(1, -1) + two * at(1, 0) + at(1, 1))
+                    - (at(-1, -1) + two * at(-1, 0) + at(-1, 1));
+                grad_x.buffer[row * self.num_cols + col] = gx;
+                grad_y.buffer[row * self.num_cols + col] = gy;
+            }
+        }
+        Ok(())
+    }
+}
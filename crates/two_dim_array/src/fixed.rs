@@ -0,0 +1,205 @@
+use crate::error::check_shape;
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// A stack-allocated two-dimensional array with its shape in the type, for
+/// small matrices known at compile time such as 3x3 transforms.
+///
+/// Stores `R` rows of `C` elements each, so construction cannot fail and
+/// indices given as const generics to `at` and `at_mut` are bounds checked
+/// at compile time. Uses the same row-major layout as
+/// `TwoDimensionalArray` and hands out dynamic views of itself through
+/// `as_view` and `as_view_mut`.
+///
+/// # Example
+/// ```
+/// use two_dim_array::Array2D;
+///
+/// let mut rotation = Array2D::new([
+///     [0.0, -1.0],
+///     [1.0, 0.0],
+/// ]);
+/// assert_eq!(rotation.shape(), (2, 2));
+/// assert_eq!(*rotation.at::<1, 0>(), 1.0);
+///
+/// let mut view = rotation.as_view_mut();
+/// *view.get_mut_panic(0, 0) = 2.0;
+/// assert_eq!(rotation.get(0, 0), Some(&2.0));
+/// ```
+///
+/// Indexing out of bounds through `at` does not compile:
+/// ```compile_fail
+/// use two_dim_array::Array2D;
+/// let identity = Array2D::new([[1, 0], [0, 1]]);
+/// identity.at::<2, 0>();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Array2D<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Array2D<T, R, C> {
+    /// The number of rows.
+    pub const NUM_ROWS: usize = R;
+
+    /// The number of columns.
+    pub const NUM_COLS: usize = C;
+
+    /// Construct an `Array2D` from its rows.
+    pub const fn new(data: [[T; C]; R]) -> Self {
+        Self { data }
+    }
+
+    /// Construct an `Array2D`, setting each element to `f(row, col)` in
+    /// row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::Array2D;
+    /// let identity: Array2D<i32, 3, 3> = Array2D::from_fn(|row, col| (row == col) as i32);
+    /// assert_eq!(identity.as_slice(), [1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self {
+            data: core::array::from_fn(|row| core::array::from_fn(|col| f(row, col))),
+        }
+    }
+
+    /// Returns the shape of the array.
+    ///
+    /// Return order: `(num_rows, num_cols)`.
+    pub const fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// The number of rows in the array.
+    pub const fn num_rows(&self) -> usize {
+        R
+    }
+
+    /// The number of columns in the array.
+    pub const fn num_cols(&self) -> usize {
+        C
+    }
+
+    /// Returns the total number of elements (`R * C`).
+    pub const fn len(&self) -> usize {
+        R * C
+    }
+
+    /// Returns whether the array holds no elements.
+    pub const fn is_empty(&self) -> bool {
+        R * C == 0
+    }
+
+    /// Returns a reference to the element at `(ROW, COL)`, checking the
+    /// bounds at compile time.
+    pub const fn at<const ROW: usize, const COL: usize>(&self) -> &T {
+        const { assert!(ROW < R && COL < C, "index out of bounds") };
+        &self.data[ROW][COL]
+    }
+
+    /// Returns a mutable reference to the element at `(ROW, COL)`, checking
+    /// the bounds at compile time.
+    pub const fn at_mut<const ROW: usize, const COL: usize>(&mut self) -> &mut T {
+        const { assert!(ROW < R && COL < C, "index out of bounds") };
+        &mut self.data[ROW][COL]
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)`, or
+    /// `None` if it is out of bounds.
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Option<&T> {
+        self.data.get(row_idx)?.get(col_idx)
+    }
+
+    /// Returns a mutable reference to the element at `(row_idx, col_idx)`,
+    /// or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, row_idx: usize, col_idx: usize) -> Option<&mut T> {
+        self.data.get_mut(row_idx)?.get_mut(col_idx)
+    }
+
+    /// Returns an iterator yielding references to the rows of the array.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T; C]> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator yielding mutable references to the rows of the
+    /// array.
+    pub fn rows_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [T; C]> {
+        self.data.iter_mut()
+    }
+
+    /// Returns a reference to the elements in row-major order.
+    pub const fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// Returns a mutable reference to the elements in row-major order.
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+
+    /// Returns a read-only `TwoDimensionalArrayRef` view of the array.
+    pub const fn as_view(&self) -> TwoDimensionalArrayRef<'_, T> {
+        TwoDimensionalArrayRef {
+            buffer: self.data.as_flattened(),
+            num_rows: R,
+            num_cols: C,
+        }
+    }
+
+    /// Returns a mutable `TwoDimensionalArray` view of the array.
+    pub const fn as_view_mut(&mut self) -> TwoDimensionalArray<'_, T> {
+        TwoDimensionalArray {
+            buffer: self.data.as_flattened_mut(),
+            num_rows: R,
+            num_cols: C,
+        }
+    }
+
+    /// Consumes the array, returning its rows.
+    pub fn into_inner(self) -> [[T; C]; R] {
+        self.data
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for Array2D<T, R, C> {
+    fn default() -> Self {
+        Self::from_fn(|_, _| T::default())
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Array2D<T, R, C> {
+    fn from(data: [[T; C]; R]) -> Self {
+        Self::new(data)
+    }
+}
+
+/// Copies a dynamic view into a fixed-shape array.
+///
+/// # Errors
+///
+/// Returns `ShapeError::ShapeMismatch` when the view is not `R` by `C`.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::{Array2D, TwoDimensionalArrayRef};
+/// let view = TwoDimensionalArrayRef::new(&[1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+/// let fixed = Array2D::<i32, 2, 3>::try_from(view).unwrap();
+/// assert_eq!(fixed.into_inner(), [[1, 2, 3], [4, 5, 6]]);
+/// assert!(Array2D::<i32, 3, 2>::try_from(view).is_err());
+/// ```
+impl<T: Copy, const R: usize, const C: usize> TryFrom<TwoDimensionalArrayRef<'_, T>>
+    for Array2D<T, R, C>
+{
+    type Error = ShapeError;
+
+    fn try_from(view: TwoDimensionalArrayRef<'_, T>) -> Result<Self, ShapeError> {
+        check_shape((R, C), view.shape())?;
+        Ok(Self::from_fn(|row, col| view.buffer[row * C + col]))
+    }
+}
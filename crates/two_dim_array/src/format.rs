@@ -0,0 +1,226 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+use core::fmt::{self, Debug, DebugList, Formatter};
+#[cfg(feature = "alloc")]
+use core::fmt::{Alignment, Display};
+
+#[cfg(feature = "std")]
+use human_format::Count;
+
+#[cfg(feature = "alloc")]
+use crate::{Array2D, TwoDimensionalVec};
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// The number of rows, and of elements in each row, shown by `Debug`
+/// before the rest are elided.
+const DEBUG_LIMIT: usize = 32;
+
+/// Formats the array as a grid with one line per row and aligned columns.
+///
+/// Each element is formatted with the precision of the formatter, if any,
+/// and each column is padded to its widest element or the width of the
+/// formatter, whichever is larger. Elements are right-aligned unless the
+/// formatter asks for left or centre alignment.
+///
+/// Every row is printed, however large the array. `Debug` is the
+/// summarising format: it elides rows past the first 32 and, with the
+/// `std` feature, counts what it left out.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1.0, -2.5, 30.3, 4.0];
+/// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+///
+/// assert_eq!(x.to_string(), "   1 -2.5\n30.3    4");
+/// assert_eq!(format!("{x:.1}"), " 1.0 -2.5\n30.3  4.0");
+/// assert_eq!(format!("{x:<5.1}"), "1.0   -2.5 \n30.3  4.0  ");
+/// ```
+#[cfg(feature = "alloc")]
+impl<T: Display> Display for TwoDimensionalArrayRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.num_cols == 0 {
+            return Ok(());
+        }
+        let cells: Vec<String> = self
+            .buffer
+            .iter()
+            .map(|elem| match f.precision() {
+                Some(precision) => format!("{elem:.precision$}"),
+                None => elem.to_string(),
+            })
+            .collect();
+        let mut widths = vec![f.width().unwrap_or(0); self.num_cols];
+        for (idx, cell) in cells.iter().enumerate() {
+            let width = &mut widths[idx % self.num_cols];
+            *width = (*width).max(cell.chars().count());
+        }
+        for (row_idx, row) in cells.chunks_exact(self.num_cols).enumerate() {
+            if row_idx > 0 {
+                f.write_str("\n")?;
+            }
+            for (col_idx, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                if col_idx > 0 {
+                    f.write_str(" ")?;
+                }
+                match f.align() {
+                    Some(Alignment::Left) => write!(f, "{cell:<width$}")?,
+                    Some(Alignment::Center) => write!(f, "{cell:^width$}")?,
+                    Some(Alignment::Right) | None => write!(f, "{cell:>width$}")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the array as an aligned grid. See `TwoDimensionalArrayRef`.
+#[cfg(feature = "alloc")]
+impl<T: Display> Display for TwoDimensionalArray<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_view(), f)
+    }
+}
+
+/// Formats the array as an aligned grid. See `TwoDimensionalArrayRef`.
+#[cfg(feature = "alloc")]
+impl<T: Display> Display for TwoDimensionalVec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_view(), f)
+    }
+}
+
+/// Formats the array as an aligned grid. See `TwoDimensionalArrayRef`.
+#[cfg(feature = "alloc")]
+impl<T: Display, const R: usize, const C: usize> Display for Array2D<T, R, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_view(), f)
+    }
+}
+
+/// Debug-formats the rows of an array as a list of lists, eliding rows and
+/// elements past `DEBUG_LIMIT`.
+struct DebugRows<'a, T>(TwoDimensionalArrayRef<'a, T>);
+
+impl<T: Debug> Debug for DebugRows<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut rows = f.debug_list();
+        // Indexing rather than `rows` keeps the empty rows of an array
+        // without columns.
+        for row_idx in 0..self.0.num_rows.min(DEBUG_LIMIT) {
+            rows.entry(&DebugRow(self.0.get_panic(row_idx, ..)));
+        }
+        if self.0.num_rows > DEBUG_LIMIT {
+            finish_elided(rows, self.0.num_rows - DEBUG_LIMIT, self.0.num_cols)
+        } else {
+            rows.finish()
+        }
+    }
+}
+
+/// Ends a list of rows with a summary of the `num_rows` rows left out.
+#[cfg(feature = "std")]
+fn finish_elided(mut rows: DebugList<'_, '_>, num_rows: usize, num_cols: usize) -> fmt::Result {
+    rows.entry(&ElidedRows { num_rows, num_cols });
+    rows.finish()
+}
+
+/// Ends a list of rows with `..` for the rows left out.
+#[cfg(not(feature = "std"))]
+fn finish_elided(mut rows: DebugList<'_, '_>, _: usize, _: usize) -> fmt::Result {
+    rows.finish_non_exhaustive()
+}
+
+/// Summarises the rows left out by `Debug`, such as
+/// `.. 968 more rows (31.0k elements)`.
+#[cfg(feature = "std")]
+struct ElidedRows {
+    num_rows: usize,
+    num_cols: usize,
+}
+
+#[cfg(feature = "std")]
+impl Debug for ElidedRows {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let num_elems = self.num_rows.saturating_mul(self.num_cols);
+        write!(
+            f,
+            ".. {} more rows ({} elements)",
+            Count(self.num_rows as u64),
+            Count(num_elems as u64)
+        )
+    }
+}
+
+struct DebugRow<'a, T>(&'a [T]);
+
+impl<T: Debug> Debug for DebugRow<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut row = f.debug_list();
+        row.entries(self.0.iter().take(DEBUG_LIMIT));
+        if self.0.len() > DEBUG_LIMIT {
+            row.finish_non_exhaustive()
+        } else {
+            row.finish()
+        }
+    }
+}
+
+fn debug_array<T: Debug>(
+    name: &str,
+    view: TwoDimensionalArrayRef<'_, T>,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    f.debug_struct(name)
+        .field("shape", &view.shape())
+        .field("rows", &DebugRows(view))
+        .finish()
+}
+
+/// Shows the shape and the rows of the array, eliding all but the first
+/// 32 rows and the first 32 elements of each row. With the `std` feature
+/// the elided rows are summarised with their counts.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1, 2, 3, 4];
+/// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+/// assert_eq!(
+///     format!("{x:?}"),
+///     "TwoDimensionalArray { shape: (2, 2), rows: [[1, 2], [3, 4]] }"
+/// );
+///
+/// let mut wide = [0; 40];
+/// let y = TwoDimensionalArray::new(&mut wide, 1, 40).unwrap();
+/// assert!(format!("{y:?}").ends_with("0, 0, ..]] }"));
+///
+/// let mut tall = vec![0; 50_000];
+/// let t = TwoDimensionalArray::new(&mut tall, 1000, 50).unwrap();
+/// assert!(format!("{t:?}").ends_with(".. 968 more rows (48.4k elements)] }"));
+///
+/// let z = TwoDimensionalArray::<i32>::new(&mut [], 2, 0).unwrap();
+/// assert_eq!(format!("{z:?}"), "TwoDimensionalArray { shape: (2, 0), rows: [[], []] }");
+/// ```
+impl<T: Debug> Debug for TwoDimensionalArray<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        debug_array("TwoDimensionalArray", self.as_view(), f)
+    }
+}
+
+/// Shows the shape and the rows of the array. See `TwoDimensionalArray`.
+impl<T: Debug> Debug for TwoDimensionalArrayRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        debug_array("TwoDimensionalArrayRef", *self, f)
+    }
+}
+
+/// Shows the shape and the rows of the array. See `TwoDimensionalArray`.
+#[cfg(feature = "alloc")]
+impl<T: Debug> Debug for TwoDimensionalVec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        debug_array("TwoDimensionalVec", self.as_view(), f)
+    }
+}
@@ -0,0 +1,661 @@
+//! GPU compute for bulk operations on large `f32` arrays, using wgpu.
+//!
+//! A `Gpu` holds a device and the compiled compute pipelines. Arrays are
+//! uploaded once into `GpuArray` buffers, combined there without copying
+//! back to the host, and downloaded into `TwoDimensionalVec`s when the
+//! results are needed. Only `f32` is supported, as WGSL has no portable
+//! 64-bit floats.
+//!
+//! Every operation blocks until the GPU has finished. Transfers and
+//! dispatch cost far more than a CPU loop over a small array, so this pays
+//! off for arrays of millions of elements.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalVec;
+//! use two_dim_array::gpu::{BinaryOp, Gpu, Reduction};
+//!
+//! // Skip the example on machines without a usable adapter.
+//! let Ok(gpu) = Gpu::new() else { return };
+//!
+//! let a = TwoDimensionalVec::from_vec(vec![1.0, 2.0, 3.0, 4.0], 2, 2).unwrap();
+//! let b = TwoDimensionalVec::from_vec(vec![10.0, 20.0, 30.0, 40.0], 2, 2).unwrap();
+//! let (a, b) = (gpu.upload(&a.as_view()), gpu.upload(&b.as_view()));
+//!
+//! let sum = gpu.elementwise(&a, &b, BinaryOp::Add).unwrap();
+//! assert_eq!(gpu.download(&sum).unwrap().as_slice(), [11.0, 22.0, 33.0, 44.0]);
+//!
+//! let product = gpu.matmul(&a, &b).unwrap();
+//! assert_eq!(gpu.download(&product).unwrap().as_slice(), [70.0, 100.0, 150.0, 220.0]);
+//!
+//! assert_eq!(gpu.reduce(&a, Reduction::Sum).unwrap(), 10.0);
+//! ```
+
+use std::sync::mpsc;
+
+use crate::error::check_shape;
+use crate::{BorderMode, ShapeError, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+const ELEMENTWISE_SHADER: &str = r#"
+struct Params { len: u32, op: u32, row_stride: u32, pad: u32 }
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> result: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x + id.y * params.row_stride;
+    if (i >= params.len) {
+        return;
+    }
+    let x = a[i];
+    let y = b[i];
+    var value: f32;
+    switch params.op {
+        case 0u: { value = x + y; }
+        case 1u: { value = x - y; }
+        case 2u: { value = x * y; }
+        case 3u: { value = x / y; }
+        case 4u: { value = min(x, y); }
+        default: { value = max(x, y); }
+    }
+    result[i] = value;
+}
+"#;
+
+const REDUCE_SHADER: &str = r#"
+struct Params { len: u32, op: u32, groups_x: u32, num_groups: u32, identity: f32, pad0: u32, pad1: u32, pad2: u32 }
+
+@group(0) @binding(0) var<storage, read> input: array<f32>;
+@group(0) @binding(1) var<storage, read_write> partials: array<f32>;
+@group(0) @binding(2) var<uniform> params: Params;
+
+var<workgroup> scratch: array<f32, 256>;
+
+fn combine(x: f32, y: f32) -> f32 {
+    switch params.op {
+        case 0u: { return x + y; }
+        case 1u: { return min(x, y); }
+        default: { return max(x, y); }
+    }
+}
+
+@compute @workgroup_size(256)
+fn main(
+    @builtin(local_invocation_index) local: u32,
+    @builtin(workgroup_id) workgroup: vec3<u32>,
+) {
+    let group = workgroup.x + workgroup.y * params.groups_x;
+    let i = group * 256u + local;
+    var value = params.identity;
+    if (i < params.len) {
+        value = input[i];
+    }
+    scratch[local] = value;
+    workgroupBarrier();
+    for (var stride = 128u; stride > 0u; stride = stride >> 1u) {
+        if (local < stride) {
+            scratch[local] = combine(scratch[local], scratch[local + stride]);
+        }
+        workgroupBarrier();
+    }
+    if (local == 0u && group < params.num_groups) {
+        partials[group] = scratch[0];
+    }
+}
+"#;
+
+const MATMUL_SHADER: &str = r#"
+struct Params { m: u32, k: u32, n: u32, pad: u32 }
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> result: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = id.y;
+    let col = id.x;
+    if (row >= params.m || col >= params.n) {
+        return;
+    }
+    var acc = 0.0;
+    for (var i = 0u; i < params.k; i++) {
+        acc += a[row * params.k + i] * b[i * params.n + col];
+    }
+    result[row * params.n + col] = acc;
+}
+"#;
+
+const CORRELATE_SHADER: &str = r#"
+struct Params { rows: u32, cols: u32, k_rows: u32, k_cols: u32, mode: u32, constant: f32, pad0: u32, pad1: u32 }
+
+@group(0) @binding(0) var<storage, read> input: array<f32>;
+@group(0) @binding(1) var<storage, read> kernel: array<f32>;
+@group(0) @binding(2) var<storage, read_write> result: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+// Euclidean remainder, with `%` only applied to non-negative operands as
+// some backends leave it undefined otherwise.
+fn rem_euclid(a: i32, b: i32) -> i32 {
+    if (a >= 0) {
+        return a % b;
+    }
+    let r = (-a) % b;
+    return select(b - r, 0, r == 0);
+}
+
+// Maps an index along an axis of length `len` onto the index to read, or
+// -1 when the constant should be used. Mirrors `BorderMode::resolve`.
+fn resolve(idx: i32, len: i32) -> i32 {
+    if (idx >= 0 && idx < len) {
+        return idx;
+    }
+    switch params.mode {
+        case 0u: { return clamp(idx, 0, len - 1); }
+        case 1u: {
+            if (len == 1) {
+                return 0;
+            }
+            let period = 2 * (len - 1);
+            let folded = rem_euclid(idx, period);
+            return select(period - folded, folded, folded < len);
+        }
+        case 2u: { return rem_euclid(idx, len); }
+        default: { return -1; }
+    }
+}
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = id.y;
+    let col = id.x;
+    if (row >= params.rows || col >= params.cols) {
+        return;
+    }
+    var acc = 0.0;
+    for (var kr = 0u; kr < params.k_rows; kr++) {
+        let r = resolve(i32(row + kr) - i32(params.k_rows / 2u), i32(params.rows));
+        for (var kc = 0u; kc < params.k_cols; kc++) {
+            let c = resolve(i32(col + kc) - i32(params.k_cols / 2u), i32(params.cols));
+            var value = params.constant;
+            if (r >= 0 && c >= 0) {
+                value = input[u32(r) * params.cols + u32(c)];
+            }
+            acc += kernel[kr * params.k_cols + kc] * value;
+        }
+    }
+    result[row * params.cols + col] = acc;
+}
+"#;
+
+/// Error returned by `Gpu` operations.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GpuError {
+    /// No adapter was found by `Gpu::new`.
+    Adapter(wgpu::RequestAdapterError),
+    /// The adapter refused to open a device in `Gpu::new`.
+    Device(wgpu::RequestDeviceError),
+    /// The operands did not have compatible shapes.
+    Shape(ShapeError),
+    /// Waiting for the GPU to finish failed.
+    Poll(wgpu::PollError),
+    /// A result buffer could not be mapped for reading.
+    Map(wgpu::BufferAsyncError),
+}
+
+impl std::error::Error for GpuError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Adapter(err) => Some(err),
+            Self::Device(err) => Some(err),
+            Self::Shape(err) => Some(err),
+            Self::Poll(err) => Some(err),
+            Self::Map(err) => Some(err),
+        }
+    }
+}
+
+impl std::fmt::Display for GpuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Adapter(err) => f.write_fmt(format_args!("No GPU adapter available: {}", err)),
+            Self::Device(err) => f.write_fmt(format_args!("Could not open GPU device: {}", err)),
+            Self::Shape(err) => err.fmt(f),
+            Self::Poll(err) => f.write_fmt(format_args!("Waiting for the GPU failed: {}", err)),
+            Self::Map(err) => f.write_fmt(format_args!("Could not read GPU buffer: {}", err)),
+        }
+    }
+}
+
+impl From<ShapeError> for GpuError {
+    fn from(err: ShapeError) -> Self {
+        Self::Shape(err)
+    }
+}
+
+/// Elementwise binary operations for `Gpu::elementwise`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+}
+
+/// Reductions of every element to one value for `Gpu::reduce`.
+///
+/// `Min` and `Max` of arrays holding `NaN` are unspecified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    Sum,
+    Min,
+    Max,
+}
+
+/// An `f32` array stored in a GPU buffer, created by `Gpu::upload` or a
+/// `Gpu` operation.
+#[derive(Debug)]
+pub struct GpuArray {
+    buffer: wgpu::Buffer,
+    num_rows: usize,
+    num_cols: usize,
+}
+
+impl GpuArray {
+    /// Returns the shape of the array as `(num_rows, num_cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.num_rows * self.num_cols
+    }
+
+    /// Returns whether the array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A GPU device with the compute pipelines for the operations of this
+/// module.
+///
+/// Validation errors, such as an array exceeding the device's maximum
+/// buffer size, are reported by wgpu's uncaptured error handler, which
+/// panics by default.
+#[derive(Debug)]
+pub struct Gpu {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    elementwise: wgpu::ComputePipeline,
+    reduce: wgpu::ComputePipeline,
+    matmul: wgpu::ComputePipeline,
+    correlate: wgpu::ComputePipeline,
+}
+
+impl Gpu {
+    /// Opens the default adapter with its full limits and compiles the
+    /// pipelines.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GpuError::Adapter` or `GpuError::Device` when no device can
+    /// be opened.
+    pub fn new() -> Result<Self, GpuError> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))
+        .map_err(GpuError::Adapter)?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("two_dim_array"),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        }))
+        .map_err(GpuError::Device)?;
+        let pipeline = |label: &str, source: &str| {
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(label),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            });
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(label),
+                layout: None,
+                module: &module,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+        Ok(Self {
+            elementwise: pipeline("elementwise", ELEMENTWISE_SHADER),
+            reduce: pipeline("reduce", REDUCE_SHADER),
+            matmul: pipeline("matmul", MATMUL_SHADER),
+            correlate: pipeline("correlate", CORRELATE_SHADER),
+            device,
+            queue,
+        })
+    }
+
+    /// Copies `array` into a new GPU buffer.
+    pub fn upload(&self, array: &TwoDimensionalArrayRef<'_, f32>) -> GpuArray {
+        let (num_rows, num_cols) = array.shape();
+        let result = self.alloc(num_rows, num_cols);
+        if !array.is_empty() {
+            let bytes: Vec<u8> = array
+                .as_slice()
+                .iter()
+                .flat_map(|x| x.to_ne_bytes())
+                .collect();
+            self.queue.write_buffer(&result.buffer, 0, &bytes);
+        }
+        result
+    }
+
+    /// Copies `array` back from the GPU, waiting for any operation still
+    /// writing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GpuError::Poll` or `GpuError::Map` if the buffer cannot be
+    /// read back.
+    pub fn download(&self, array: &GpuArray) -> Result<TwoDimensionalVec<f32>, GpuError> {
+        let buffer = self.read_back(&array.buffer, array.len())?;
+        Ok(TwoDimensionalVec {
+            buffer,
+            num_rows: array.num_rows,
+            num_cols: array.num_cols,
+        })
+    }
+
+    /// Returns `op` applied to each pair of elements of `a` and `b`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GpuError::Shape` when the arrays have different shapes.
+    pub fn elementwise(
+        &self,
+        a: &GpuArray,
+        b: &GpuArray,
+        op: BinaryOp,
+    ) -> Result<GpuArray, GpuError> {
+        check_shape(a.shape(), b.shape())?;
+        let result = self.alloc(a.num_rows, a.num_cols);
+        let (groups_x, groups_y) = self.grid(a.len().div_ceil(256));
+        let params = words(&[a.len() as u32, op as u32, groups_x * 256, 0]);
+        self.dispatch(
+            &self.elementwise,
+            &[&a.buffer, &b.buffer, &result.buffer],
+            &params,
+            (groups_x, groups_y),
+        );
+        Ok(result)
+    }
+
+    /// Reduces every element of `array` to one value. An empty array gives
+    /// `0`, infinity or negative infinity for `Sum`, `Min` and `Max`.
+    ///
+    /// The reduction runs in passes of 256 elements per workgroup, so sums
+    /// may round differently from a sequential loop.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GpuError::Poll` or `GpuError::Map` if the result cannot be
+    /// read back.
+    pub fn reduce(&self, array: &GpuArray, reduction: Reduction) -> Result<f32, GpuError> {
+        let identity = match reduction {
+            Reduction::Sum => 0.0,
+            Reduction::Min => f32::INFINITY,
+            Reduction::Max => f32::NEG_INFINITY,
+        };
+        if array.is_empty() {
+            return Ok(identity);
+        }
+        let mut len = array.len();
+        let mut partials = None;
+        while len > 1 {
+            let num_groups = len.div_ceil(256);
+            let output = self.alloc(num_groups, 1);
+            let (groups_x, groups_y) = self.grid(num_groups);
+            let params = words(&[
+                len as u32,
+                reduction as u32,
+                groups_x,
+                num_groups as u32,
+                identity.to_bits(),
+                0,
+                0,
+                0,
+            ]);
+            let input = partials
+                .as_ref()
+                .map_or(&array.buffer, |p: &GpuArray| &p.buffer);
+            self.dispatch(
+                &self.reduce,
+                &[input, &output.buffer],
+                &params,
+                (groups_x, groups_y),
+            );
+            partials = Some(output);
+            len = num_groups;
+        }
+        let last = partials.as_ref().map_or(&array.buffer, |p| &p.buffer);
+        Ok(self.read_back(last, 1)?[0])
+    }
+
+    /// Returns the matrix product `a * b`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GpuError::Shape` when `b` does not have one row per column
+    /// of `a`.
+    pub fn matmul(&self, a: &GpuArray, b: &GpuArray) -> Result<GpuArray, GpuError> {
+        if a.num_cols != b.num_rows {
+            return Err(ShapeError::ShapeMismatch {
+                expected: (a.num_cols, b.num_cols),
+                found: b.shape(),
+            }
+            .into());
+        }
+        let result = self.alloc(a.num_rows, b.num_cols);
+        let params = words(&[a.num_rows as u32, a.num_cols as u32, b.num_cols as u32, 0]);
+        self.dispatch(
+            &self.matmul,
+            &[&a.buffer, &b.buffer, &result.buffer],
+            &params,
+            (
+                b.num_cols.div_ceil(16) as u32,
+                a.num_rows.div_ceil(16) as u32,
+            ),
+        );
+        Ok(result)
+    }
+
+    /// Returns the correlation of `array` with `kernel`, centred on each
+    /// element, with `border` deciding how elements past the edges are
+    /// read. For odd kernel sizes the centre is the middle element; for
+    /// even sizes it is just after the middle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GpuError::Shape` when `array` is empty and `border` is not
+    /// a constant, as there is nothing to read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{BorderMode, TwoDimensionalVec};
+    /// use two_dim_array::gpu::Gpu;
+    ///
+    /// let Ok(gpu) = Gpu::new() else { return };
+    /// let image = TwoDimensionalVec::from_vec(vec![0.0, 0.0, 9.0, 0.0, 0.0], 1, 5).unwrap();
+    /// let blur = TwoDimensionalVec::from_vec(vec![1.0 / 3.0; 3], 1, 3).unwrap();
+    /// let result = gpu
+    ///     .correlate(&gpu.upload(&image.as_view()), &gpu.upload(&blur.as_view()), BorderMode::Clamp)
+    ///     .unwrap();
+    /// assert_eq!(gpu.download(&result).unwrap().as_slice(), [0.0, 3.0, 3.0, 3.0, 0.0]);
+    /// ```
+    pub fn correlate(
+        &self,
+        array: &GpuArray,
+        kernel: &GpuArray,
+        border: BorderMode<f32>,
+    ) -> Result<GpuArray, GpuError> {
+        let (mode, constant) = match border {
+            BorderMode::Clamp => (0, 0.0),
+            BorderMode::Reflect => (1, 0.0),
+            BorderMode::Wrap => (2, 0.0),
+            BorderMode::Constant(value) => (3, value),
+        };
+        if array.is_empty() && mode != 3 {
+            return Err(ShapeError::InvalidShape {
+                buffer_len: 0,
+                num_rows: array.num_rows,
+                num_cols: array.num_cols,
+            }
+            .into());
+        }
+        let result = self.alloc(array.num_rows, array.num_cols);
+        let params = words(&[
+            array.num_rows as u32,
+            array.num_cols as u32,
+            kernel.num_rows as u32,
+            kernel.num_cols as u32,
+            mode,
+            constant.to_bits(),
+            0,
+            0,
+        ]);
+        self.dispatch(
+            &self.correlate,
+            &[&array.buffer, &kernel.buffer, &result.buffer],
+            &params,
+            (
+                array.num_cols.div_ceil(16) as u32,
+                array.num_rows.div_ceil(16) as u32,
+            ),
+        );
+        Ok(result)
+    }
+
+    /// Allocates an uninitialised array. Buffers are never empty, as empty
+    /// bindings are invalid.
+    fn alloc(&self, num_rows: usize, num_cols: usize) -> GpuArray {
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (num_rows * num_cols).max(1) as u64 * 4,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        GpuArray {
+            buffer,
+            num_rows,
+            num_cols,
+        }
+    }
+
+    /// Splits `num_groups` workgroups into a two-dimensional grid within
+    /// the per-dimension limit.
+    fn grid(&self, num_groups: usize) -> (u32, u32) {
+        let max = self.device.limits().max_compute_workgroups_per_dimension as usize;
+        let groups_x = num_groups.clamp(1, max);
+        (groups_x as u32, num_groups.div_ceil(groups_x) as u32)
+    }
+
+    /// Runs `pipeline` with `buffers` bound in order followed by `params`
+    /// as a uniform buffer.
+    fn dispatch(
+        &self,
+        pipeline: &wgpu::ComputePipeline,
+        buffers: &[&wgpu::Buffer],
+        params: &[u8],
+        (groups_x, groups_y): (u32, u32),
+    ) {
+        if groups_x == 0 || groups_y == 0 {
+            return;
+        }
+        let uniform = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: params.len() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        self.queue.write_buffer(&uniform, 0, params);
+        let entries: Vec<_> = buffers
+            .iter()
+            .chain([&&uniform])
+            .enumerate()
+            .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                binding: binding as u32,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &entries,
+        });
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(groups_x, groups_y, 1);
+        }
+        self.queue.submit([encoder.finish()]);
+    }
+
+    /// Reads the first `len` elements of `buffer` back to the host.
+    fn read_back(&self, buffer: &wgpu::Buffer, len: usize) -> Result<Vec<f32>, GpuError> {
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        let size = len as u64 * 4;
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        let (sender, receiver) = mpsc::channel();
+        staging.map_async(wgpu::MapMode::Read, .., move |result| {
+            // The receiver outlives the poll below, so sending cannot fail.
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(GpuError::Poll)?;
+        receiver
+            .recv()
+            .expect("mapping callbacks have run once polling finishes")
+            .map_err(GpuError::Map)?;
+        let values = staging
+            .get_mapped_range(..)
+            .expect("the staging buffer was just mapped")
+            .chunks_exact(4)
+            .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+        staging.unmap();
+        Ok(values)
+    }
+}
+
+/// Encodes uniform parameters, each a `u32` or the bits of an `f32`.
+fn words(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|word| word.to_ne_bytes()).collect()
+}
@@ -0,0 +1,280 @@
+//! Graph algorithms treating a square array as an adjacency matrix, where
+//! element `(i, j)` describes the edge from node `i` to node `j`.
+//!
+//! Whether an element is an edge is decided by the `Edge` trait: `true`
+//! for `bool`, non-zero for numbers and `Some` for `Option`, so the same
+//! matrix can hold plain, weighted or optional-weight edges.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalArray;
+//!
+//! // 0 -> 1 -> 2, and 3 on its own.
+//! let mut a = [
+//!     0, 1, 0, 0,
+//!     0, 0, 1, 0,
+//!     0, 0, 0, 0,
+//!     0, 0, 0, 0,
+//! ];
+//! let graph = TwoDimensionalArray::new(&mut a, 4, 4).unwrap();
+//!
+//! assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), [2]);
+//! assert_eq!(graph.out_degrees(), [1, 1, 0, 0]);
+//! assert_eq!(graph.bfs_order(0).unwrap(), [0, 1, 2]);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::ops::Add;
+
+use crate::error::check_square;
+use crate::{ShapeError, TwoDimensionalArray};
+
+/// Element types of an adjacency matrix, which report whether they
+/// represent an edge.
+pub trait Edge {
+    /// Returns whether the element represents an edge.
+    fn is_edge(&self) -> bool;
+}
+
+impl Edge for bool {
+    fn is_edge(&self) -> bool {
+        *self
+    }
+}
+
+impl<T> Edge for Option<T> {
+    fn is_edge(&self) -> bool {
+        self.is_some()
+    }
+}
+
+macro_rules! impl_edge {
+    ($($t:ty)*) => {
+        $(
+            impl Edge for $t {
+                fn is_edge(&self) -> bool {
+                    *self != (0 as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_edge!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+/// Traversal of the graph described by an adjacency matrix.
+impl<T: Edge> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the nodes `node` has an edge to, in
+    /// ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.get_panic(node, ..)
+            .iter()
+            .enumerate()
+            .filter(|(_, elem)| elem.is_edge())
+            .map(|(idx, _)| idx)
+    }
+
+    /// Returns the number of outgoing edges of each node, that is the
+    /// number of edges in each row.
+    #[cfg(feature = "alloc")]
+    pub fn out_degrees(&self) -> Vec<usize> {
+        (0..self.num_rows)
+            .map(|node| self.neighbors(node).count())
+            .collect()
+    }
+
+    /// Returns the number of incoming edges of each node, that is the
+    /// number of edges in each column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [false, true, true, false, false, true, false, false, false];
+    /// let graph = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// assert_eq!(graph.in_degrees(), [0, 1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn in_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.num_cols];
+        for node in 0..self.num_rows {
+            for neighbor in self.neighbors(node) {
+                degrees[neighbor] += 1;
+            }
+        }
+        degrees
+    }
+
+    /// Returns the nodes reachable from `start` in breadth-first order,
+    /// visiting neighbours in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotSquare` when the array is not square.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is out of bounds.
+    #[cfg(feature = "alloc")]
+    pub fn bfs_order(&self, start: usize) -> Result<Vec<usize>, ShapeError> {
+        check_square(self.shape())?;
+        let mut visited = vec![false; self.num_rows];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for neighbor in self.neighbors(node) {
+                if !core::mem::replace(&mut visited[neighbor], true) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// Returns the nodes reachable from `start` in depth-first preorder,
+    /// visiting neighbours in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotSquare` when the array is not square.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// // 0 -> {1, 2}, 1 -> 3, 2 -> 3.
+    /// let mut a = [
+    ///     0, 1, 1, 0,
+    ///     0, 0, 0, 1,
+    ///     0, 0, 0, 1,
+    ///     0, 0, 0, 0,
+    /// ];
+    /// let graph = TwoDimensionalArray::new(&mut a, 4, 4).unwrap();
+    /// assert_eq!(graph.dfs_order(0).unwrap(), [0, 1, 3, 2]);
+    /// assert_eq!(graph.bfs_order(0).unwrap(), [0, 1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn dfs_order(&self, start: usize) -> Result<Vec<usize>, ShapeError> {
+        check_square(self.shape())?;
+        let mut visited = vec![false; self.num_rows];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if core::mem::replace(&mut visited[node], true) {
+                continue;
+            }
+            order.push(node);
+            // Push in reverse so the smallest neighbour is visited first.
+            let unvisited: Vec<usize> = self.neighbors(node).filter(|&n| !visited[n]).collect();
+            stack.extend(unvisited.into_iter().rev());
+        }
+        Ok(order)
+    }
+}
+
+/// All-pairs shortest paths over optional edge weights.
+impl<T> TwoDimensionalArray<'_, Option<T>>
+where
+    T: Copy + PartialOrd + Add<Output = T>,
+{
+    /// Replaces each element `(i, j)` with the length of the shortest path
+    /// from `i` to `j` using the Floyd–Warshall algorithm, where `None`
+    /// means there is no edge, or no path once complete.
+    ///
+    /// The diagonal is left as given, so set it to zero beforehand for the
+    /// usual distance matrix. Negative weights are allowed, but the result
+    /// is meaningless if the graph has a negative cycle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotSquare` when the array is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     Some(0), Some(4), Some(1),
+    ///     None, Some(0), None,
+    ///     None, Some(2), Some(0),
+    /// ];
+    /// let mut dist = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// dist.floyd_warshall().unwrap();
+    /// assert_eq!(dist.get_panic(0, ..), [Some(0), Some(3), Some(1)]);
+    /// assert_eq!(dist.get_panic(1, ..), [None, Some(0), None]);
+    /// ```
+    pub fn floyd_warshall(&mut self) -> Result<(), ShapeError> {
+        check_square(self.shape())?;
+        let n = self.num_rows;
+        for k in 0..n {
+            for i in 0..n {
+                let Some(to_k) = self.buffer[i * n + k] else {
+                    continue;
+                };
+                for j in 0..n {
+                    let Some(from_k) = self.buffer[k * n + j] else {
+                        continue;
+                    };
+                    let through_k = to_k + from_k;
+                    let current = &mut self.buffer[i * n + j];
+                    if current.is_none_or(|dist| through_k < dist) {
+                        *current = Some(through_k);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reachability over boolean adjacency matrices.
+impl TwoDimensionalArray<'_, bool> {
+    /// Replaces the adjacency matrix with its transitive closure, so element
+    /// `(i, j)` is `true` exactly when there is a path of one or more edges
+    /// from `i` to `j`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotSquare` when the array is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [false, true, false, false, false, true, false, false, false];
+    /// let mut reach = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// reach.transitive_closure().unwrap();
+    /// assert_eq!(reach.get_panic(0, ..), [false, true, true]);
+    /// assert_eq!(reach.get_panic(2, ..), [false, false, false]);
+    /// ```
+    pub fn transitive_closure(&mut self) -> Result<(), ShapeError> {
+        check_square(self.shape())?;
+        let n = self.num_rows;
+        for k in 0..n {
+            for i in 0..n {
+                if !self.buffer[i * n + k] {
+                    continue;
+                }
+                for j in 0..n {
+                    if self.buffer[k * n + j] {
+                        self.buffer[i * n + j] = true;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
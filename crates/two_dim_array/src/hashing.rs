@@ -0,0 +1,117 @@
+#[cfg(feature = "digest")]
+use alloc::vec;
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// Content digests of the array, for change detection and cache keys.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Feeds the shape and then the row-major contents of the array into
+    /// `hasher`.
+    ///
+    /// The shape is included so that arrays holding the same elements in
+    /// different shapes hash differently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// fn digest(x: &TwoDimensionalArray<'_, i32>) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     x.digest_into(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut b = [1, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let y = TwoDimensionalArray::new(&mut b, 1, 4).unwrap();
+    /// assert_eq!(digest(&x), digest(&x));
+    /// assert_ne!(digest(&x), digest(&y));
+    /// ```
+    pub fn digest_into<H: Hasher>(&self, hasher: &mut H)
+    where
+        T: Hash,
+    {
+        self.as_view().hash(hasher);
+    }
+}
+
+/// Hashes the shape and then the row-major contents, consistently with
+/// equality. See `TwoDimensionalArray::digest_into`.
+impl<T: Hash> Hash for TwoDimensionalArrayRef<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.num_rows);
+        state.write_usize(self.num_cols);
+        for elem in self.buffer {
+            elem.hash(state);
+        }
+    }
+}
+
+/// Hashes the shape and then the row-major contents. See
+/// `TwoDimensionalArrayRef`.
+impl<T: Hash> Hash for TwoDimensionalArray<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_view().hash(state);
+    }
+}
+
+/// Hashes the shape and then the row-major contents. See
+/// `TwoDimensionalArrayRef`.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use two_dim_array::TwoDimensionalVec;
+///
+/// let mut seen = HashSet::new();
+/// assert!(seen.insert(TwoDimensionalVec::from_elem(0, 2, 2)));
+/// assert!(!seen.insert(TwoDimensionalVec::from_elem(0, 2, 2)));
+/// assert!(seen.insert(TwoDimensionalVec::from_elem(0, 1, 4)));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T: Hash> Hash for TwoDimensionalVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_view().hash(state);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<T: crate::bytes::FixedWidth> TwoDimensionalArray<'_, T> {
+    /// Feeds the shape and then the row-major contents of the array into a
+    /// cryptographic `digest::Digest`.
+    ///
+    /// The shape is fed as two little-endian `u64`s and each element as its
+    /// `FixedWidth` encoding, so the digest does not depend on the platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sha2::{Digest, Sha256};
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// let mut a = [1u16, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    ///
+    /// let mut hasher = Sha256::new();
+    /// x.update_digest(&mut hasher);
+    /// let digest = hasher.finalize();
+    /// assert_eq!(digest.len(), 32);
+    /// ```
+    pub fn update_digest<D: digest::Digest>(&self, digest: &mut D) {
+        digest.update((self.num_rows as u64).to_le_bytes());
+        digest.update((self.num_cols as u64).to_le_bytes());
+        let mut elem_buf = vec![0; T::WIDTH];
+        for elem in self.buffer.iter() {
+            elem.write_le(&mut elem_buf);
+            digest.update(&elem_buf);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Rendering numeric grids as heatmaps in a terminal.
+//!
+//! Colour maps draw two rows per line of text with the unicode upper half
+//! block `▀`, its foreground coloured by the upper row and its background
+//! by the lower, using 24-bit ANSI colour codes. `ColorMap::Ascii` draws
+//! one row per line with a ramp of increasingly dense characters, for
+//! terminals and logs without colour.
+//!
+//! Non-finite values are drawn in the terminal's default colours, or as a
+//! space by the ASCII ramp.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalArray;
+//! use two_dim_array::heatmap::{ColorMap, Normalization};
+//!
+//! let mut a = [
+//!     0.0, 1.0, 2.0, 3.0,
+//!     4.0, 5.0, 6.0, 9.0,
+//! ];
+//! let x = TwoDimensionalArray::new(&mut a, 2, 4).unwrap();
+//!
+//! let mut out = Vec::new();
+//! x.render_heatmap(&mut out, ColorMap::Ascii, Normalization::MinMax).unwrap();
+//! assert_eq!(String::from_utf8(out).unwrap(), " .:-\n=+*@\n");
+//!
+//! // In colour the two rows share one line of half blocks.
+//! let mut out = Vec::new();
+//! x.render_heatmap(&mut out, ColorMap::Viridis, Normalization::MinMax).unwrap();
+//! let text = String::from_utf8(out).unwrap();
+//! assert_eq!(text.lines().count(), 1);
+//! assert_eq!(text.matches('▀').count(), 4);
+//! ```
+
+use std::io::{self, Write};
+
+use crate::TwoDimensionalArray;
+use crate::num::Float;
+
+/// ASCII characters from the lowest value to the highest.
+const ASCII_RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Maps normalised values in `[0, 1]` to what is drawn for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMap {
+    /// Black to white.
+    Grayscale,
+    /// Black through red and yellow to white.
+    Heat,
+    /// An approximation of matplotlib's perceptually uniform viridis, dark
+    /// purple through teal to yellow.
+    Viridis,
+    /// The characters ` .:-=+*#%@`, without colour.
+    Ascii,
+}
+
+impl ColorMap {
+    /// Returns the colour for `t` in `[0, 1]`, interpolating linearly
+    /// between evenly spaced stops. Returns `None` for `Ascii`.
+    pub fn color(self, t: f64) -> Option<[u8; 3]> {
+        let stops: &[[u8; 3]] = match self {
+            Self::Grayscale => &[[0, 0, 0], [255, 255, 255]],
+            Self::Heat => &[[0, 0, 0], [255, 0, 0], [255, 255, 0], [255, 255, 255]],
+            Self::Viridis => &[
+                [68, 1, 84],
+                [59, 82, 139],
+                [33, 145, 140],
+                [94, 201, 98],
+                [253, 231, 37],
+            ],
+            Self::Ascii => return None,
+        };
+        let scaled = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+        let low = (scaled.floor() as usize).min(stops.len() - 2);
+        let frac = scaled - low as f64;
+        let (a, b) = (stops[low], stops[low + 1]);
+        Some(std::array::from_fn(|channel| {
+            let (a, b) = (f64::from(a[channel]), f64::from(b[channel]));
+            (a + (b - a) * frac).round() as u8
+        }))
+    }
+}
+
+/// How values are mapped into `[0, 1]` before the colour map is applied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Normalization<T> {
+    /// Map the smallest finite value to 0 and the largest to 1. If they are
+    /// equal every finite value maps to 0.5.
+    MinMax,
+    /// Map `min` to 0 and `max` to 1, clamping values outside the range.
+    /// Keeps the colours of successive frames of a simulation comparable.
+    Range { min: T, max: T },
+    /// Map `-m` to 0, zero to 0.5 and `m` to 1, where `m` is the largest
+    /// finite magnitude, for values signed around zero.
+    Symmetric,
+}
+
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Draws the array as a heatmap to `writer`, one line of text per row,
+    /// or per two rows for colour maps. See the `heatmap` module.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from writing to `writer`.
+    pub fn render_heatmap<W: Write>(
+        &self,
+        writer: &mut W,
+        color_map: ColorMap,
+        normalization: Normalization<T>,
+    ) -> io::Result<()> {
+        let normalise = self.normaliser(normalization);
+        let (num_rows, num_cols) = self.shape();
+        let at = |row: usize, col: usize| normalise(self.buffer[row * num_cols + col].to_f64());
+
+        if color_map == ColorMap::Ascii {
+            for row in 0..num_rows {
+                let line: Vec<u8> = (0..num_cols)
+                    .map(|col| match at(row, col) {
+                        Some(t) => ASCII_RAMP[(t * (ASCII_RAMP.len() - 1) as f64).round() as usize],
+                        None => b' ',
+                    })
+                    .collect();
+                writer.write_all(&line)?;
+                writer.write_all(b"\n")?;
+            }
+            return Ok(());
+        }
+
+        for row in (0..num_rows).step_by(2) {
+            for col in 0..num_cols {
+                let upper = at(row, col).and_then(|t| color_map.color(t));
+                let lower = (row + 1 < num_rows)
+                    .then(|| at(row + 1, col))
+                    .flatten()
+                    .and_then(|t| color_map.color(t));
+                match upper {
+                    Some([r, g, b]) => write!(writer, "\x1b[38;2;{};{};{}m", r, g, b)?,
+                    None => writer.write_all(b"\x1b[39m")?,
+                }
+                match lower {
+                    Some([r, g, b]) => write!(writer, "\x1b[48;2;{};{};{}m", r, g, b)?,
+                    None => writer.write_all(b"\x1b[49m")?,
+                }
+                writer.write_all("▀".as_bytes())?;
+            }
+            writer.write_all(b"\x1b[0m\n")?;
+        }
+        Ok(())
+    }
+
+    /// Returns a function mapping a value into `[0, 1]`, or to `None` when
+    /// it is not finite.
+    fn normaliser(&self, normalization: Normalization<T>) -> impl Fn(f64) -> Option<f64> + use<T> {
+        let finite = self
+            .buffer
+            .iter()
+            .map(|value| value.to_f64())
+            .filter(|value| value.is_finite());
+        let (min, max) = match normalization {
+            Normalization::MinMax => finite
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                }),
+            Normalization::Range { min, max } => (min.to_f64(), max.to_f64()),
+            Normalization::Symmetric => {
+                let magnitude = finite.fold(0.0, |max: f64, value| max.max(value.abs()));
+                (-magnitude, magnitude)
+            }
+        };
+        move |value: f64| {
+            if !value.is_finite() {
+                return None;
+            }
+            Some(if max > min {
+                ((value - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.5
+            })
+        }
+    }
+}
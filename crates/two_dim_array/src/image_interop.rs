@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+use image::{ImageBuffer, Pixel};
+
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+/// Returns the number of samples in each row of `image`.
+fn samples_per_row<P: Pixel, C: Deref<Target = [P::Subpixel]>>(image: &ImageBuffer<P, C>) -> usize {
+    usize::from(P::CHANNEL_COUNT) * image.width() as usize
+}
+
+/// Views the samples of an image in place, available with the `image`
+/// feature.
+///
+/// Each row of pixels is a row of the array, with the channels of each
+/// pixel in adjacent columns, so an RGB image `width` pixels wide has
+/// `3 * width` columns.
+///
+/// # Example
+///
+/// ```
+/// use image::RgbImage;
+/// use two_dim_array::TwoDimensionalArrayRef;
+///
+/// let mut image = RgbImage::new(4, 2);
+/// image.put_pixel(1, 0, image::Rgb([10, 20, 30]));
+///
+/// let x = TwoDimensionalArrayRef::from(&image);
+/// assert_eq!(x.shape(), (2, 12));
+/// assert_eq!(x.get_panic(0, 3..6), [10, 20, 30]);
+/// ```
+impl<'a, P, C> From<&'a ImageBuffer<P, C>> for TwoDimensionalArrayRef<'a, P::Subpixel>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    fn from(image: &'a ImageBuffer<P, C>) -> Self {
+        let num_cols = samples_per_row(image);
+        let num_rows = image.height() as usize;
+        // The container may be longer than the image.
+        Self {
+            buffer: &Deref::deref(image)[..num_rows * num_cols],
+            num_rows,
+            num_cols,
+        }
+    }
+}
+
+/// Views the samples of an image in place, mutably. See
+/// `TwoDimensionalArrayRef`.
+///
+/// # Example
+///
+/// ```
+/// use image::GrayImage;
+/// use two_dim_array::TwoDimensionalArray;
+///
+/// let mut image = GrayImage::new(3, 3);
+/// TwoDimensionalArray::from(&mut image).get_mut_panic(1, ..).fill(255);
+/// assert_eq!(image.get_pixel(2, 1).0, [255]);
+/// ```
+impl<'a, P, C> From<&'a mut ImageBuffer<P, C>> for TwoDimensionalArray<'a, P::Subpixel>
+where
+    P: Pixel,
+    C: Deref<Target = [P::Subpixel]> + DerefMut,
+{
+    fn from(image: &'a mut ImageBuffer<P, C>) -> Self {
+        let num_cols = samples_per_row(image);
+        let num_rows = image.height() as usize;
+        Self {
+            buffer: &mut DerefMut::deref_mut(image)[..num_rows * num_cols],
+            num_rows,
+            num_cols,
+        }
+    }
+}
+
+/// Moves the samples of an image into an array, without copying. See
+/// `TwoDimensionalArrayRef`.
+impl<P: Pixel> From<ImageBuffer<P, Vec<P::Subpixel>>> for TwoDimensionalVec<P::Subpixel> {
+    fn from(image: ImageBuffer<P, Vec<P::Subpixel>>) -> Self {
+        let num_cols = samples_per_row(&image);
+        let num_rows = image.height() as usize;
+        let mut buffer = image.into_raw();
+        buffer.truncate(num_rows * num_cols);
+        Self {
+            buffer,
+            num_rows,
+            num_cols,
+        }
+    }
+}
+
+/// Moves the samples of an array into an image, without copying, with
+/// each pixel's channels in adjacent columns.
+///
+/// # Errors
+///
+/// Returns `ShapeError::NotDivisible` when the number of columns is not a
+/// multiple of the channels per pixel and `ShapeError::DimensionOverflow`
+/// when the width or height does not fit in a `u32`.
+///
+/// # Example
+///
+/// ```
+/// use image::RgbImage;
+/// use two_dim_array::TwoDimensionalVec;
+///
+/// let x = TwoDimensionalVec::from_fn(2, 6, |row, col| (10 * row + col) as u8);
+/// let image = RgbImage::try_from(x).unwrap();
+/// assert_eq!(image.dimensions(), (2, 2));
+/// assert_eq!(image.get_pixel(1, 1).0, [13, 14, 15]);
+///
+/// assert!(RgbImage::try_from(TwoDimensionalVec::from_elem(0, 2, 4)).is_err());
+/// ```
+impl<P: Pixel> TryFrom<TwoDimensionalVec<P::Subpixel>> for ImageBuffer<P, Vec<P::Subpixel>> {
+    type Error = ShapeError;
+
+    fn try_from(array: TwoDimensionalVec<P::Subpixel>) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = array.shape();
+        let channels = usize::from(P::CHANNEL_COUNT);
+        if !num_cols.is_multiple_of(channels) {
+            return Err(ShapeError::NotDivisible {
+                buffer_len: num_cols,
+                dim: channels,
+            });
+        }
+        let (width, height) = u32::try_from(num_cols / channels)
+            .ok()
+            .zip(u32::try_from(num_rows).ok())
+            .ok_or(ShapeError::DimensionOverflow { num_rows, num_cols })?;
+        Ok(ImageBuffer::from_raw(width, height, array.buffer).expect("buffer matches the image"))
+    }
+}
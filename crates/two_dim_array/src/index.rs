@@ -0,0 +1,108 @@
+//! `[(row, col)]` element indexing.
+//!
+//! A single element is always contiguous, so tuple indexing is offered
+//! alongside `get_panic` even though row and column slicing through `[]`
+//! is not.
+
+use core::ops::{Index, IndexMut};
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::{Array2D, IndexError, TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// Panics with the message of an `IndexError` for `(row, col)`.
+fn out_of_bounds(row: usize, col: usize, shape: (usize, usize)) -> ! {
+    panic!("{}", IndexError { row, col, shape })
+}
+
+/// Returns the element at `(row, col)`, panicking if it is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1, 2, 3, 4];
+/// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+///
+/// x[(0, 1)] += x[(1, 0)];
+/// assert_eq!(x[(0, 1)], 5);
+/// ```
+///
+/// ```should_panic
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1, 2, 3, 4];
+/// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+///
+/// // Panics, although the flat index would be in bounds.
+/// x[(0, 2)];
+/// ```
+impl<T> Index<(usize, usize)> for TwoDimensionalArray<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.try_get(row, col)
+            .unwrap_or_else(|err| out_of_bounds(err.row, err.col, err.shape))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for TwoDimensionalArray<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let shape = self.shape();
+        self.try_get_mut(row, col)
+            .unwrap_or_else(|_| out_of_bounds(row, col, shape))
+    }
+}
+
+impl<T> Index<(usize, usize)> for TwoDimensionalArrayRef<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.try_get(row, col)
+            .unwrap_or_else(|err| out_of_bounds(err.row, err.col, err.shape))
+    }
+}
+
+/// Returns the element at `(row, col)`, panicking if it is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalVec;
+/// let mut x = TwoDimensionalVec::from_elem(0, 2, 3);
+/// x[(1, 2)] = 7;
+/// assert_eq!(x.as_slice(), [0, 0, 0, 0, 0, 7]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> Index<(usize, usize)> for TwoDimensionalVec<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.try_get(row, col)
+            .unwrap_or_else(|err| out_of_bounds(err.row, err.col, err.shape))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IndexMut<(usize, usize)> for TwoDimensionalVec<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let shape = self.shape();
+        self.try_get_mut(row, col)
+            .unwrap_or_else(|_| out_of_bounds(row, col, shape))
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Array2D<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| out_of_bounds(row, col, (R, C)))
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Array2D<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| out_of_bounds(row, col, (R, C)))
+    }
+}
@@ -0,0 +1,352 @@
+use core::iter::FusedIterator;
+use core::{mem, slice};
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// An iterator over the elements of an array in row-major order, created
+/// by `iter` on the array types.
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    inner: slice::Iter<'a, T>,
+}
+
+/// An iterator over mutable references to the elements of an array in
+/// row-major order, created by `iter_mut` on the array types.
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: slice::IterMut<'a, T>,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        self.inner.nth(n)
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        self.inner.nth(n)
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// An iterator over the rows of a `TwoDimensionalArray` as mutable
+/// slices for the full lifetime of the buffer, created by its
+/// `IntoIterator` implementation.
+#[derive(Debug)]
+pub struct IntoRows<'a, T> {
+    rest: &'a mut [T],
+    num_cols: usize,
+    num_rows: usize,
+}
+
+impl<'a, T> Iterator for IntoRows<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.num_rows == 0 {
+            return None;
+        }
+        self.num_rows -= 1;
+        let (row, rest) = mem::take(&mut self.rest).split_at_mut(self.num_cols);
+        self.rest = rest;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.num_rows, Some(self.num_rows))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoRows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.num_rows == 0 {
+            return None;
+        }
+        self.num_rows -= 1;
+        let rest = mem::take(&mut self.rest);
+        let (rest, row) = rest.split_at_mut(self.num_rows * self.num_cols);
+        self.rest = rest;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoRows<'_, T> {}
+
+impl<T> FusedIterator for IntoRows<'_, T> {}
+
+/// Consumes the view, yielding its rows as mutable slices which outlive
+/// it.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1, 2, 3, 4, 5, 6];
+/// let x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+///
+/// let mut rows: Vec<&mut [i32]> = x.into_iter().collect();
+/// rows[2][0] = rows[0][1];
+/// assert_eq!(a, [1, 2, 3, 4, 2, 6]);
+/// ```
+impl<'a, T> IntoIterator for TwoDimensionalArray<'a, T> {
+    type Item = &'a mut [T];
+    type IntoIter = IntoRows<'a, T>;
+
+    fn into_iter(self) -> IntoRows<'a, T> {
+        IntoRows {
+            rest: self.buffer,
+            num_cols: self.num_cols,
+            num_rows: self.num_rows,
+        }
+    }
+}
+
+/// Iterates the elements in row-major order, as `iter` does.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1, 2, 3, 4];
+/// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+///
+/// for elem in &mut x {
+///     *elem += 1;
+/// }
+/// let mut total = 0;
+/// for elem in &x {
+///     total += elem;
+/// }
+/// assert_eq!(total, 14);
+/// ```
+impl<'a, T> IntoIterator for &'a TwoDimensionalArray<'_, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut TwoDimensionalArray<'_, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for &TwoDimensionalArrayRef<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IntoIterator for &'a TwoDimensionalVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IntoIterator for &'a mut TwoDimensionalVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the elements of the array in row-major
+    /// order.
+    ///
+    /// Unlike iterating `as_slice`, the order is that of the array rather
+    /// than of the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// assert_eq!(x.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.buffer.iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// array in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.iter_mut().for_each(|elem| *elem *= 10);
+    /// assert_eq!(a, [10, 20, 30, 40]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.buffer.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> TwoDimensionalArrayRef<'a, T> {
+    /// Returns an iterator over the elements of the view in row-major
+    /// order.
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            inner: self.buffer.iter(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TwoDimensionalVec<T> {
+    /// Returns an iterator over the elements of the array in row-major
+    /// order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.buffer.iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// array in row-major order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.buffer.iter_mut(),
+        }
+    }
+}
+
+/// Returns the buffer offsets of the elements on the edge of a `num_rows`
+/// by `num_cols` array, clockwise from the top-left corner. Each offset
+/// appears once.
+fn border_offsets(num_rows: usize, num_cols: usize) -> impl Iterator<Item = usize> {
+    let (tall, wide) = (num_rows > 1, num_cols > 1);
+    let top = 0..if num_rows > 0 { num_cols } else { 0 };
+    let right = 1..if num_cols > 0 { num_rows } else { 0 };
+    let bottom = (0..if tall { num_cols.saturating_sub(1) } else { 0 }).rev();
+    let left = (1..if wide { num_rows.saturating_sub(1) } else { 1 }).rev();
+    top.chain(right.map(move |row| row * num_cols + num_cols - 1))
+        .chain(bottom.map(move |col| (num_rows - 1) * num_cols + col))
+        .chain(left.map(move |row| row * num_cols))
+}
+
+/// Iteration over the elements on the edge of the array.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the elements on the outer edge of the
+    /// array, each exactly once, clockwise from the top-left corner: the
+    /// top row, then down the right column, back along the bottom row and
+    /// up the left column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    ///     7, 8, 9,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let edge: Vec<i32> = x.border().copied().collect();
+    /// assert_eq!(edge, [1, 2, 3, 6, 9, 8, 7, 4]);
+    ///
+    /// let mut b = [1, 2, 3];
+    /// let row = TwoDimensionalArray::new(&mut b, 1, 3).unwrap();
+    /// assert_eq!(row.border().count(), 3);
+    /// ```
+    pub fn border(&self) -> impl Iterator<Item = &T> {
+        border_offsets(self.num_rows, self.num_cols).map(|offset| &self.buffer[offset])
+    }
+
+    /// Returns an iterator over mutable references to the elements on the
+    /// outer edge of the array, in the order of `border`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1; 12];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 4).unwrap();
+    ///
+    /// // Apply a zero boundary condition.
+    /// x.border_mut().for_each(|elem| *elem = 0);
+    /// assert_eq!(a, [0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn border_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let ptr = self.buffer.as_mut_ptr();
+        border_offsets(self.num_rows, self.num_cols).map(move |offset| {
+            // SAFETY: The offsets are within the mutably borrowed buffer and
+            // distinct, so the references never alias.
+            unsafe { &mut *ptr.add(offset) }
+        })
+    }
+}
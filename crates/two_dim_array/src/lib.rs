@@ -1,4 +1,106 @@
-use std::slice::SliceIndex;
+//! With the default `std` feature disabled the crate is `no_std`. The
+//! views, `TwoDimensionalArray` and `TwoDimensionalArrayRef`, only need
+//! `core`; `TwoDimensionalVec` and the other allocating operations need the
+//! `alloc` feature, and I/O, floating point math and hash maps need `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod axis;
+mod broadcast;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_interop;
+pub mod bytes;
+mod column;
+#[cfg(feature = "alloc")]
+mod contour;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod diagonal;
+mod diff;
+mod elementwise;
+mod equality;
+mod error;
+mod filter;
+mod fixed;
+mod format;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod graph;
+mod hashing;
+#[cfg(feature = "std")]
+pub mod heatmap;
+#[cfg(feature = "image")]
+mod image_interop;
+mod index;
+mod iter;
+mod linalg;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+mod neighbors;
+mod nested;
+#[cfg(feature = "std")]
+pub mod noise;
+#[cfg(feature = "npy")]
+pub mod npy;
+pub mod num;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod peaks;
+#[cfg(feature = "rand")]
+mod random;
+mod resample;
+#[cfg(feature = "std")]
+pub mod rle;
+mod rows;
+#[cfg(feature = "serde")]
+pub mod serde_flat;
+#[cfg(feature = "serde")]
+mod serialize;
+mod split;
+#[cfg(feature = "alloc")]
+mod stack;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod strided;
+mod tiles;
+mod transform;
+#[cfg(feature = "alloc")]
+mod undo;
+mod view;
+
+pub use axis::Axis;
+pub use column::{Cols, ColsMut, Column, ColumnMut};
+pub use diagonal::AntiDiagonals;
+pub use diff::Change;
+pub use error::{IndexError, LinalgError, PatchError, ScatterError, ShapeError};
+pub use filter::BorderMode;
+#[cfg(feature = "alloc")]
+pub use filter::SobelGradient;
+pub use fixed::Array2D;
+pub use iter::{IntoRows, Iter, IterMut};
+pub use linalg::Diagonal;
+#[cfg(feature = "alloc")]
+pub use owned::{DrainRows, TwoDimensionalVec};
+pub use resample::Filter;
+pub use rows::DuplicatePolicy;
+pub use split::{RowChunks, RowChunksMut};
+#[cfg(feature = "alloc")]
+pub use stack::{hstack, vstack};
+pub use strided::{StridedView, StridedViewMut};
+pub use tiles::{Blocks, BlocksMut, Windows};
+#[cfg(feature = "alloc")]
+pub use undo::UndoableArray;
+pub use view::TwoDimensionalArrayRef;
+
+use core::slice::{GetDisjointMutError, SliceIndex};
+use error::{checked_len, infer_shape};
 
 /// A two-dimensional view of an underlying one-dimensional
 /// buffer. Rows are considered contiguous.
@@ -12,13 +114,21 @@ use std::slice::SliceIndex;
 ///
 /// # Slicing and indexing
 ///
-/// Slicing and indexing through [] are not supported because
-/// it cannot be done such that non-contiguous slices are
-/// not possible. Instead use `get`, `get_unchecked` and
-/// `get_panic`, the latter is equivalent to [] indexing.
-/// Each has an `_mut` equivalent. All accept a column
-/// index or slice and a row index which guarantees contiguous
-/// slicing.
+/// Slicing through [] is not supported because it cannot be
+/// done such that non-contiguous slices are not possible.
+/// Instead use `get`, `get_unchecked` and `get_panic`, the
+/// latter is equivalent to [] indexing. Each has an `_mut`
+/// equivalent. All accept a column index or slice and a row
+/// index which guarantees contiguous slicing.
+///
+/// Single elements can be indexed with a `(row, col)` tuple,
+/// as in `view[(0, 1)]`, which panics when out of bounds.
+///
+/// # Comparison
+///
+/// Arrays are equal when they have the same shape and elements,
+/// whether views or owned. They also compare with nested arrays
+/// and `Vec`s of rows, and hash their shape and elements.
 ///
 /// # Layout
 ///
@@ -35,6 +145,8 @@ use std::slice::SliceIndex;
 ///
 /// assert_eq!(view.shape(), (2, 2));
 /// assert_eq!(view.get(0, 1), Some(&2));
+/// assert_eq!(view, [[1, 2], [3, 4]]);
+/// assert_eq!(view, vec![vec![1, 2], vec![3, 4]]);
 /// ```
 pub struct TwoDimensionalArray<'a, T> {
     buffer: &'a mut [T],
@@ -50,9 +162,10 @@ impl<'a, T> TwoDimensionalArray<'a, T> {
     /// # Errors
     ///
     /// Returns `ShapeError::InvalidShape` when the buffer cannot be arranged
-    /// with `num_rows * num_cols`.
+    /// with `num_rows * num_cols` and `ShapeError::Overflow` when that
+    /// product overflows.
     pub fn new(buffer: &'a mut [T], num_rows: usize, num_cols: usize) -> Result<Self, ShapeError> {
-        if buffer.len() != num_cols * num_rows {
+        if buffer.len() != checked_len(num_rows, num_cols)? {
             Err(ShapeError::InvalidShape {
                 buffer_len: buffer.len(),
                 num_rows,
@@ -67,14 +180,62 @@ impl<'a, T> TwoDimensionalArray<'a, T> {
         }
     }
 
+    /// Construct a `TwoDimensionalArray` from the buffer in a const
+    /// context, such as the initialiser of a `static`.
+    ///
+    /// The const counterpart of `new`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, at compile time when evaluated in a const context, if the
+    /// buffer cannot be arranged with `num_rows * num_cols` or that product
+    /// overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// static mut BUFFER: [u8; 6] = [0; 6];
+    /// static mut GRID: TwoDimensionalArray<'static, u8> =
+    ///     TwoDimensionalArray::from_mut_slice(unsafe { &mut *(&raw mut BUFFER) }, 2, 3);
+    ///
+    /// // Safety: nothing else accesses `GRID` or `BUFFER`.
+    /// let grid = unsafe { &mut *(&raw mut GRID) };
+    /// *grid.get_mut_panic(1, 2) = 7;
+    /// assert_eq!(grid.shape(), (2, 3));
+    /// assert_eq!(grid.as_slice(), [0, 0, 0, 0, 0, 7]);
+    /// ```
+    ///
+    /// A mismatched shape fails to compile when evaluated in a const
+    /// context:
+    /// ```compile_fail
+    /// use two_dim_array::TwoDimensionalArray;
+    /// static mut BUFFER: [u8; 6] = [0; 6];
+    /// static mut GRID: TwoDimensionalArray<'static, u8> =
+    ///     TwoDimensionalArray::from_mut_slice(unsafe { &mut *(&raw mut BUFFER) }, 4, 2);
+    /// ```
+    pub const fn from_mut_slice(buffer: &'a mut [T], num_rows: usize, num_cols: usize) -> Self {
+        match num_rows.checked_mul(num_cols) {
+            Some(len) if len == buffer.len() => Self {
+                buffer,
+                num_rows,
+                num_cols,
+            },
+            Some(_) => panic!("buffer length does not match num_rows * num_cols"),
+            None => panic!("num_rows * num_cols overflows usize"),
+        }
+    }
+
     /// Update the shape of the TwoDimensionalArray to have `num_rows`, `num_cols`.
     ///
     /// # Errors
     ///
     /// Returns `ShapeError::InvalidShape` when the buffer cannot be reshaped
-    /// to the requested shape.
+    /// to the requested shape and `ShapeError::Overflow` when
+    /// `num_rows * num_cols` overflows.
     pub fn reshape(&mut self, num_rows: usize, num_cols: usize) -> Result<(), ShapeError> {
-        if self.buffer.len() != num_cols * num_rows {
+        if self.buffer.len() != checked_len(num_rows, num_cols)? {
             Err(ShapeError::InvalidShape {
                 buffer_len: self.buffer.len(),
                 num_rows,
@@ -87,6 +248,95 @@ impl<'a, T> TwoDimensionalArray<'a, T> {
         }
     }
 
+    /// Construct a `TwoDimensionalArray` with `num_rows` rows, inferring
+    /// the number of columns from the length of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the buffer length is not a
+    /// multiple of `num_rows`. Zero rows are only accepted for an empty
+    /// buffer, giving zero columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new_with_rows(&mut a, 3).unwrap();
+    /// assert_eq!(x.shape(), (3, 2));
+    /// ```
+    pub fn new_with_rows(buffer: &'a mut [T], num_rows: usize) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = infer_shape(buffer.len(), num_rows, true)?;
+        Self::new(buffer, num_rows, num_cols)
+    }
+
+    /// Construct a `TwoDimensionalArray` with `num_cols` columns, inferring
+    /// the number of rows from the length of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the buffer length is not a
+    /// multiple of `num_cols`. Zero columns are only accepted for an empty
+    /// buffer, giving zero rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new_with_cols(&mut a, 3).unwrap();
+    /// assert_eq!(x.shape(), (2, 3));
+    /// ```
+    pub fn new_with_cols(buffer: &'a mut [T], num_cols: usize) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = infer_shape(buffer.len(), num_cols, false)?;
+        Self::new(buffer, num_rows, num_cols)
+    }
+
+    /// Update the shape to have `num_rows` rows, inferring the number of
+    /// columns like numpy's `reshape(num_rows, -1)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the length is not a multiple
+    /// of `num_rows`. The shape is unchanged on error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{ShapeError, TwoDimensionalArray};
+    /// let mut a = [0; 12];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 6).unwrap();
+    /// x.reshape_rows(4).unwrap();
+    /// assert_eq!(x.shape(), (4, 3));
+    /// assert!(matches!(x.reshape_rows(5), Err(ShapeError::NotDivisible { .. })));
+    /// ```
+    pub fn reshape_rows(&mut self, num_rows: usize) -> Result<(), ShapeError> {
+        let (num_rows, num_cols) = infer_shape(self.buffer.len(), num_rows, true)?;
+        self.reshape(num_rows, num_cols)
+    }
+
+    /// Update the shape to have `num_cols` columns, inferring the number of
+    /// rows like numpy's `reshape(-1, num_cols)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the length is not a multiple
+    /// of `num_cols`. The shape is unchanged on error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 12];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 6).unwrap();
+    /// x.reshape_cols(4).unwrap();
+    /// assert_eq!(x.shape(), (3, 4));
+    /// ```
+    pub fn reshape_cols(&mut self, num_cols: usize) -> Result<(), ShapeError> {
+        let (num_rows, num_cols) = infer_shape(self.buffer.len(), num_cols, false)?;
+        self.reshape(num_rows, num_cols)
+    }
+
     /// Returns the current shape that the buffer is being viewed as.
     /// Can be updated with `reshape`.
     ///
@@ -259,6 +509,65 @@ impl<'a, T> TwoDimensionalArray<'a, T> {
             .get_mut(col_idx)
     }
 
+    /// Returns a reference to the element at `(row_idx, col_idx)` or an
+    /// `IndexError` carrying the index and shape if it is out of bounds.
+    ///
+    /// Like `get`, but keeps the context needed for error reports.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` when either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1,2,3,4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    ///
+    /// assert_eq!(x.try_get(1, 0).ok(), Some(&3));
+    /// let err = x.try_get(0, 2).unwrap_err();
+    /// assert_eq!((err.row, err.col, err.shape), (0, 2, (2, 2)));
+    /// ```
+    pub fn try_get(&self, row_idx: usize, col_idx: usize) -> Result<&T, IndexError> {
+        self.check_index(row_idx, col_idx)?;
+        Ok(&self.buffer[row_idx * self.num_cols + col_idx])
+    }
+
+    /// Returns a mutable reference to the element at `(row_idx, col_idx)` or
+    /// an `IndexError` if it is out of bounds. See `try_get`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` when either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1,2,3,4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    ///
+    /// *x.try_get_mut(0, 1).unwrap() = 42;
+    /// assert!(x.try_get_mut(2, 0).is_err());
+    /// assert_eq!(a, [1, 42, 3, 4]);
+    /// ```
+    pub fn try_get_mut(&mut self, row_idx: usize, col_idx: usize) -> Result<&mut T, IndexError> {
+        self.check_index(row_idx, col_idx)?;
+        Ok(&mut self.buffer[row_idx * self.num_cols + col_idx])
+    }
+
+    fn check_index(&self, row_idx: usize, col_idx: usize) -> Result<(), IndexError> {
+        if row_idx >= self.num_rows || col_idx >= self.num_cols {
+            return Err(IndexError {
+                row: row_idx,
+                col: col_idx,
+                shape: self.shape(),
+            });
+        }
+        Ok(())
+    }
+
     /// Returns a bounds checked, reference to an element or row subslice
     /// depending on the type of col_idx (see `get`). Panics on out of bounds access.
     ///
@@ -365,6 +674,56 @@ impl<'a, T> TwoDimensionalArray<'a, T> {
         self.buffer.chunks_mut(self.num_cols)
     }
 
+    /// Returns mutable references to several rows at once, mirroring
+    /// `slice::get_disjoint_mut`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetDisjointMutError::IndexOutOfBounds` when a row index is
+    /// out of bounds and `GetDisjointMutError::OverlappingIndices` when a
+    /// row is requested more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     2.0, 4.0,
+    ///     1.0, 3.0,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    ///
+    /// // Eliminate below the first pivot.
+    /// let [pivot, row] = x.get_disjoint_rows_mut([0, 1]).unwrap();
+    /// let factor = row[0] / pivot[0];
+    /// for (elem, &p) in row.iter_mut().zip(pivot.iter()) {
+    ///     *elem -= factor * p;
+    /// }
+    /// assert_eq!(a, [2.0, 4.0, 0.0, 1.0]);
+    ///
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// assert!(x.get_disjoint_rows_mut([1, 1]).is_err());
+    /// assert!(x.get_disjoint_rows_mut([0, 2]).is_err());
+    /// ```
+    pub fn get_disjoint_rows_mut<const N: usize>(
+        &mut self,
+        row_indices: [usize; N],
+    ) -> Result<[&mut [T]; N], GetDisjointMutError> {
+        for (idx, &row_idx) in row_indices.iter().enumerate() {
+            if row_idx >= self.num_rows {
+                return Err(GetDisjointMutError::IndexOutOfBounds);
+            }
+            // Checked by row as the ranges of empty rows never overlap.
+            if row_indices[..idx].contains(&row_idx) {
+                return Err(GetDisjointMutError::OverlappingIndices);
+            }
+        }
+        let num_cols = self.num_cols;
+        self.buffer.get_disjoint_mut(
+            row_indices.map(|row_idx| row_idx * num_cols..(row_idx + 1) * num_cols),
+        )
+    }
+
     /// Returns a reference to the entire underlying one-dimensional
     /// buffer.
     ///
@@ -400,30 +759,3 @@ impl<'a, T> TwoDimensionalArray<'a, T> {
         self.buffer
     }
 }
-
-/// Generic error for trying to assign an impossible shape
-/// to `TwoDimensionalArray`.
-#[derive(Debug)]
-pub enum ShapeError {
-    InvalidShape {
-        buffer_len: usize,
-        num_rows: usize,
-        num_cols: usize,
-    },
-}
-impl std::error::Error for ShapeError {}
-
-impl std::fmt::Display for ShapeError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::InvalidShape {
-                buffer_len,
-                num_rows,
-                num_cols,
-            } => f.write_fmt(format_args!(
-                "Cannot reshape two dimensional array with number of elements {} into {}x{} array",
-                buffer_len, num_rows, num_cols
-            )),
-        }
-    }
-}
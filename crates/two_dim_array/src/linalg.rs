@@ -0,0 +1,471 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+use crate::error::check_square;
+use crate::num::Float;
+#[cfg(feature = "alloc")]
+use crate::num::{One, Zero};
+use crate::{LinalgError, TwoDimensionalArray};
+#[cfg(feature = "alloc")]
+use crate::{ShapeError, TwoDimensionalVec};
+
+/// Whether a triangular solve should read the diagonal of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagonal {
+    /// Use the diagonal elements stored in the matrix.
+    NonUnit,
+    /// Assume every diagonal element is one, without reading them. Suits
+    /// factors which store a unit diagonal implicitly.
+    Unit,
+}
+
+/// Linear algebra on square arrays.
+#[cfg(feature = "alloc")]
+impl<T> TwoDimensionalArray<'_, T>
+where
+    T: Copy + PartialEq + Zero + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Solves `L x = b` for `x` by forward substitution, where `L` is the
+    /// lower triangle of the array (including the diagonal unless
+    /// `diagonal` is `Diagonal::Unit`). The strict upper triangle is never
+    /// read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinalgError::Shape` when the array is not square or `b` does
+    /// not have one element per row, and `LinalgError::Singular` when a
+    /// diagonal element used is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Diagonal, TwoDimensionalArray};
+    /// let mut a = [
+    ///     2.0, 0.0, 0.0,
+    ///     1.0, 1.0, 0.0,
+    ///     1.0, 2.0, 4.0,
+    /// ];
+    /// let l = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let x = l.solve_lower_triangular(&[2.0, 3.0, 13.0], Diagonal::NonUnit).unwrap();
+    /// assert_eq!(x, [1.0, 2.0, 2.0]);
+    /// ```
+    pub fn solve_lower_triangular(
+        &self,
+        b: &[T],
+        diagonal: Diagonal,
+    ) -> Result<Vec<T>, LinalgError> {
+        self.check_system(b)?;
+        let mut x = b.to_vec();
+        for row_idx in 0..self.num_rows {
+            let row = self.get_panic(row_idx, ..);
+            let mut acc = x[row_idx];
+            for (&l, &x_j) in row[..row_idx].iter().zip(&x[..row_idx]) {
+                acc = acc - l * x_j;
+            }
+            x[row_idx] = self.divide_by_diagonal(acc, row_idx, diagonal)?;
+        }
+        Ok(x)
+    }
+
+    /// Solves `U x = b` for `x` by back substitution, where `U` is the
+    /// upper triangle of the array (including the diagonal unless
+    /// `diagonal` is `Diagonal::Unit`). The strict lower triangle is never
+    /// read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinalgError::Shape` when the array is not square or `b` does
+    /// not have one element per row, and `LinalgError::Singular` when a
+    /// diagonal element used is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Diagonal, LinalgError, TwoDimensionalArray};
+    /// let mut a = [
+    ///     1.0, 2.0, 3.0,
+    ///     0.0, 1.0, 4.0,
+    ///     0.0, 0.0, 1.0,
+    /// ];
+    /// let u = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let x = u.solve_upper_triangular(&[14.0, 14.0, 3.0], Diagonal::Unit).unwrap();
+    /// assert_eq!(x, [1.0, 2.0, 3.0]);
+    ///
+    /// let mut singular = [1.0, 1.0, 0.0, 0.0];
+    /// let s = TwoDimensionalArray::new(&mut singular, 2, 2).unwrap();
+    /// assert!(matches!(
+    ///     s.solve_upper_triangular(&[1.0, 1.0], Diagonal::NonUnit),
+    ///     Err(LinalgError::Singular { index: 1 })
+    /// ));
+    /// ```
+    pub fn solve_upper_triangular(
+        &self,
+        b: &[T],
+        diagonal: Diagonal,
+    ) -> Result<Vec<T>, LinalgError> {
+        self.check_system(b)?;
+        let mut x = b.to_vec();
+        for row_idx in (0..self.num_rows).rev() {
+            let row = self.get_panic(row_idx, ..);
+            let mut acc = x[row_idx];
+            for (&u, &x_j) in row[row_idx + 1..].iter().zip(&x[row_idx + 1..]) {
+                acc = acc - u * x_j;
+            }
+            x[row_idx] = self.divide_by_diagonal(acc, row_idx, diagonal)?;
+        }
+        Ok(x)
+    }
+
+    fn check_system(&self, b: &[T]) -> Result<(), ShapeError> {
+        check_square(self.shape())?;
+        if b.len() != self.num_rows {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_rows,
+                found: b.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn divide_by_diagonal(
+        &self,
+        value: T,
+        idx: usize,
+        diagonal: Diagonal,
+    ) -> Result<T, LinalgError> {
+        match diagonal {
+            Diagonal::Unit => Ok(value),
+            Diagonal::NonUnit => {
+                let d = *self.get_panic(idx, idx);
+                if d == T::zero() {
+                    return Err(LinalgError::Singular { index: idx });
+                }
+                Ok(value / d)
+            }
+        }
+    }
+}
+
+/// Cholesky decomposition of symmetric positive-definite arrays.
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Returns the lower triangular Cholesky factor `L` of the array, such
+    /// that `A = L Lᵀ`, as a new owned array with zeros above the diagonal.
+    ///
+    /// Only the lower triangle of the array is read, so symmetry is assumed
+    /// rather than checked. See `cholesky_in_place` to avoid allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinalgError::Shape` when the array is not square and
+    /// `LinalgError::NotPositiveDefinite` when it is not positive-definite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     4.0, 2.0, 2.0,
+    ///     2.0, 5.0, 3.0,
+    ///     2.0, 3.0, 6.0,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let l = x.cholesky().unwrap();
+    /// assert_eq!(
+    ///     l.as_slice(),
+    ///     [
+    ///         2.0, 0.0, 0.0,
+    ///         1.0, 2.0, 0.0,
+    ///         1.0, 1.0, 2.0,
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn cholesky(&self) -> Result<TwoDimensionalVec<T>, LinalgError> {
+        let mut factor = TwoDimensionalVec {
+            buffer: self.buffer.to_vec(),
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        };
+        factor.as_view_mut().cholesky_in_place()?;
+        Ok(factor)
+    }
+
+    /// Overwrites the array with its lower triangular Cholesky factor `L`,
+    /// such that `A = L Lᵀ`, zeroing the elements above the diagonal.
+    ///
+    /// Only the lower triangle of the array is read, so symmetry is assumed
+    /// rather than checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinalgError::Shape` when the array is not square and
+    /// `LinalgError::NotPositiveDefinite` when it is not positive-definite,
+    /// in which case the array is left partially factorised.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{LinalgError, TwoDimensionalArray};
+    /// let mut a = [1.0, 2.0, 2.0, 1.0];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// assert!(matches!(
+    ///     x.cholesky_in_place(),
+    ///     Err(LinalgError::NotPositiveDefinite { index: 1 })
+    /// ));
+    /// ```
+    pub fn cholesky_in_place(&mut self) -> Result<(), LinalgError> {
+        check_square(self.shape())?;
+        let n = self.num_rows;
+        for i in 0..n {
+            for j in 0..=i {
+                let mut sum = self.buffer[i * n + j];
+                for k in 0..j {
+                    sum = sum - self.buffer[i * n + k] * self.buffer[j * n + k];
+                }
+                self.buffer[i * n + j] = if i == j {
+                    // Written to also reject `NaN`s.
+                    if sum.partial_cmp(&T::zero()) != Some(core::cmp::Ordering::Greater) {
+                        return Err(LinalgError::NotPositiveDefinite { index: i });
+                    }
+                    sum.sqrt()
+                } else {
+                    sum / self.buffer[j * n + j]
+                };
+            }
+            for elem in &mut self.buffer[i * n + i + 1..(i + 1) * n] {
+                *elem = T::zero();
+            }
+        }
+        Ok(())
+    }
+
+    /// Solves `A x = b` for `x`, where the array holds the Cholesky factor
+    /// `L` of `A` as produced by `cholesky`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinalgError::Shape` when the array is not square or `b` does
+    /// not have one element per row, and `LinalgError::Singular` when a
+    /// diagonal element of the factor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     4.0, 2.0, 2.0,
+    ///     2.0, 5.0, 3.0,
+    ///     2.0, 3.0, 6.0,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let mut l = x.cholesky().unwrap();
+    ///
+    /// let solution = l.as_view_mut().solve_cholesky(&[8.0, 10.0, 11.0]).unwrap();
+    /// assert_eq!(solution, [1.0, 1.0, 1.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn solve_cholesky(&self, b: &[T]) -> Result<Vec<T>, LinalgError> {
+        let mut x = self.solve_lower_triangular(b, Diagonal::NonUnit)?;
+        let n = self.num_rows;
+        // Back substitution with Lᵀ, reading L column-wise.
+        for i in (0..n).rev() {
+            let mut acc = x[i];
+            for (j, &x_j) in x.iter().enumerate().skip(i + 1) {
+                acc = acc - self.buffer[j * n + i] * x_j;
+            }
+            x[i] = acc / self.buffer[i * n + i];
+        }
+        Ok(x)
+    }
+}
+
+/// LU decomposition of square arrays.
+#[cfg(feature = "alloc")]
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Solves `A x = b` for `x` by LU decomposition with partial pivoting,
+    /// leaving the array untouched. The factors are built in a copy of the
+    /// array, then `solve_lower_triangular` and `solve_upper_triangular`
+    /// finish the solve.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinalgError::Shape` when the array is not square or `b` does
+    /// not have one element per row, and `LinalgError::Singular` when no
+    /// non-zero pivot is left for a column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{LinalgError, TwoDimensionalArray};
+    /// let mut a = [
+    ///     0.0, 1.0, 1.0,
+    ///     2.0, 1.0, 0.0,
+    ///     4.0, 0.0, 2.0,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// assert_eq!(x.solve(&[5.0, 4.0, 10.0]).unwrap(), [1.0, 2.0, 3.0]);
+    ///
+    /// let mut singular = [1.0, 2.0, 2.0, 4.0];
+    /// let s = TwoDimensionalArray::new(&mut singular, 2, 2).unwrap();
+    /// assert!(matches!(
+    ///     s.solve(&[1.0, 2.0]),
+    ///     Err(LinalgError::Singular { index: 1 })
+    /// ));
+    /// ```
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, LinalgError> {
+        self.check_system(b)?;
+        let n = self.num_rows;
+        let mut factors = self.buffer.to_vec();
+        let mut b = b.to_vec();
+        let mut lu = TwoDimensionalArray::new(&mut factors, n, n)?;
+        for col in 0..n {
+            // Pick the largest magnitude in the column to limit rounding.
+            let abs = |value: T| if value < T::zero() { -value } else { value };
+            let pivot = (col + 1..n).fold(col, |best, row| {
+                if abs(lu.buffer[row * n + col]) > abs(lu.buffer[best * n + col]) {
+                    row
+                } else {
+                    best
+                }
+            });
+            if lu.buffer[pivot * n + col] == T::zero() {
+                return Err(LinalgError::Singular { index: col });
+            }
+            lu.swap_rows(col, pivot);
+            b.swap(col, pivot);
+            let diagonal = lu.buffer[col * n + col];
+            for row in col + 1..n {
+                let factor = lu.buffer[row * n + col] / diagonal;
+                lu.buffer[row * n + col] = factor;
+                for k in col + 1..n {
+                    lu.buffer[row * n + k] =
+                        lu.buffer[row * n + k] - factor * lu.buffer[col * n + k];
+                }
+            }
+        }
+        let y = lu.solve_lower_triangular(&b, Diagonal::Unit)?;
+        lu.solve_upper_triangular(&y, Diagonal::NonUnit)
+    }
+}
+
+/// Integer powers of square arrays.
+#[cfg(feature = "alloc")]
+impl<T> TwoDimensionalArray<'_, T>
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns the matrix power `A^k` as a new owned array, computed by
+    /// exponentiation by squaring. `A^0` is the identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotSquare` when the array is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// // Fibonacci numbers via [[1, 1], [1, 0]]^n.
+    /// let mut a = [1u64, 1, 1, 0];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let p = x.pow(10).unwrap();
+    /// assert_eq!(p.as_slice(), [89, 55, 55, 34]);
+    /// ```
+    pub fn pow(&self, k: u32) -> Result<TwoDimensionalVec<T>, ShapeError> {
+        check_square(self.shape())?;
+        Ok(self.power(self.buffer.to_vec(), T::one(), k, |acc, a, b| acc + a * b))
+    }
+
+    /// Returns the matrix power `A^k` with every element reduced modulo
+    /// `modulus`, reducing after each multiplication so intermediate values
+    /// stay below `modulus²`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotSquare` when the array is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1u64, 1, 1, 0];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// // The 90th Fibonacci number, 2880067194370816120, modulo 1_000_000_007.
+    /// let p = x.pow_mod(90, 1_000_000_007).unwrap();
+    /// assert_eq!(p.as_slice()[1], 2880067194370816120 % 1_000_000_007);
+    /// ```
+    pub fn pow_mod(&self, k: u32, modulus: T) -> Result<TwoDimensionalVec<T>, ShapeError>
+    where
+        T: Rem<Output = T>,
+    {
+        check_square(self.shape())?;
+        let base = self.buffer.iter().map(|&elem| elem % modulus).collect();
+        Ok(self.power(base, T::one() % modulus, k, |acc, a, b| {
+            (acc + (a * b) % modulus) % modulus
+        }))
+    }
+
+    /// Raises the square matrix `base` to the power `k`, starting from the
+    /// identity with `one` on its diagonal.
+    fn power(
+        &self,
+        base: Vec<T>,
+        one: T,
+        k: u32,
+        mul_add: impl Fn(T, T, T) -> T,
+    ) -> TwoDimensionalVec<T> {
+        let n = self.num_rows;
+        let mut identity = vec![T::zero(); n * n];
+        for i in 0..n {
+            identity[i * n + i] = one;
+        }
+        TwoDimensionalVec {
+            buffer: power_by_squaring(base, identity, n, k, mul_add),
+            num_rows: n,
+            num_cols: n,
+        }
+    }
+}
+
+/// Computes `result * base^k` for `n`x`n` row-major matrices, where
+/// `mul_add(acc, a, b)` accumulates `acc + a * b`.
+#[cfg(feature = "alloc")]
+fn power_by_squaring<T: Copy + Zero>(
+    mut base: Vec<T>,
+    mut result: Vec<T>,
+    n: usize,
+    mut k: u32,
+    mul_add: impl Fn(T, T, T) -> T,
+) -> Vec<T> {
+    while k > 0 {
+        if k & 1 == 1 {
+            result = square_matmul(&result, &base, n, &mul_add);
+        }
+        k >>= 1;
+        if k > 0 {
+            base = square_matmul(&base, &base, n, &mul_add);
+        }
+    }
+    result
+}
+
+#[cfg(feature = "alloc")]
+fn square_matmul<T: Copy + Zero>(
+    a: &[T],
+    b: &[T],
+    n: usize,
+    mul_add: impl Fn(T, T, T) -> T,
+) -> Vec<T> {
+    let mut out = vec![T::zero(); n * n];
+    for i in 0..n {
+        for k in 0..n {
+            let a_ik = a[i * n + k];
+            for j in 0..n {
+                out[i * n + j] = mul_add(out[i * n + j], a_ik, b[k * n + j]);
+            }
+        }
+    }
+    out
+}
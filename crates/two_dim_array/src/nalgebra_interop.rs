@@ -0,0 +1,189 @@
+use nalgebra::{DMatrix, DMatrixView, DMatrixViewMut, Dyn, Scalar, U1};
+
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+/// Zero-copy conversion to `nalgebra` views, available with the `nalgebra`
+/// feature.
+///
+/// `nalgebra` matrices are column-major by default, so the views carry a
+/// row stride of `num_cols` and a column stride of one instead. They index
+/// the same as the array. `clone_owned` copies a view into a column-major
+/// `DMatrix`, which `nalgebra`'s products expect.
+impl<'a, T: Scalar> TwoDimensionalArrayRef<'a, T> {
+    /// Returns a `nalgebra` view of the array sharing its buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::DVector;
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    ///
+    /// let x = TwoDimensionalArrayRef::new(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3).unwrap();
+    /// let m = x.as_nalgebra();
+    ///
+    /// assert_eq!(m.shape(), (2, 3));
+    /// assert_eq!(m[(1, 0)], 4.0);
+    /// assert_eq!(m.column(1).sum(), 7.0);
+    ///
+    /// let sums = m.clone_owned() * DVector::from_element(3, 1.0);
+    /// assert_eq!(sums.as_slice(), [6.0, 15.0]);
+    /// ```
+    pub fn as_nalgebra(&self) -> DMatrixView<'a, T, Dyn, U1> {
+        DMatrixView::from_slice_with_strides_generic(
+            self.buffer,
+            Dyn(self.num_rows),
+            Dyn(self.num_cols),
+            Dyn(self.num_cols),
+            U1,
+        )
+    }
+}
+
+impl<T: Scalar> TwoDimensionalArray<'_, T> {
+    /// Returns a `nalgebra` view of the array sharing its buffer. See
+    /// `TwoDimensionalArrayRef::as_nalgebra`.
+    pub fn as_nalgebra(&self) -> DMatrixView<'_, T, Dyn, U1> {
+        self.as_view().as_nalgebra()
+    }
+
+    /// Returns a mutable `nalgebra` view of the array sharing its buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.as_nalgebra_mut().column_mut(1).fill(0);
+    /// assert_eq!(a, [1, 0, 3, 0]);
+    /// ```
+    pub fn as_nalgebra_mut(&mut self) -> DMatrixViewMut<'_, T, Dyn, U1> {
+        let (num_rows, num_cols) = self.shape();
+        DMatrixViewMut::from_slice_with_strides_generic(
+            self.buffer,
+            Dyn(num_rows),
+            Dyn(num_cols),
+            Dyn(num_cols),
+            U1,
+        )
+    }
+}
+
+impl<T: Scalar> TwoDimensionalVec<T> {
+    /// Returns a `nalgebra` view of the array sharing its buffer. See
+    /// `TwoDimensionalArrayRef::as_nalgebra`.
+    pub fn as_nalgebra(&self) -> DMatrixView<'_, T, Dyn, U1> {
+        self.as_view().as_nalgebra()
+    }
+
+    /// Returns a mutable `nalgebra` view of the array sharing its buffer.
+    /// See `TwoDimensionalArray::as_nalgebra_mut`.
+    pub fn as_nalgebra_mut(&mut self) -> DMatrixViewMut<'_, T, Dyn, U1> {
+        DMatrixViewMut::from_slice_with_strides_generic(
+            &mut self.buffer,
+            Dyn(self.num_rows),
+            Dyn(self.num_cols),
+            Dyn(self.num_cols),
+            U1,
+        )
+    }
+}
+
+/// Returns whether a view with row stride `row_stride` and unit column
+/// stride covers a contiguous row-major buffer.
+fn is_row_major((num_rows, num_cols): (usize, usize), row_stride: usize) -> bool {
+    num_rows <= 1 || row_stride == num_cols
+}
+
+/// Views the elements of a row-major `nalgebra` view in place.
+///
+/// # Errors
+///
+/// Returns `ShapeError::NotContiguous` when there is a gap between rows,
+/// such as for a view of some of the columns of a larger matrix.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArrayRef;
+/// let a = [1, 2, 3, 4, 5, 6];
+/// let m = TwoDimensionalArrayRef::new(&a, 2, 3).unwrap().as_nalgebra();
+///
+/// let x = TwoDimensionalArrayRef::try_from(m.rows(1, 1)).unwrap();
+/// assert_eq!(x.as_slice(), [4, 5, 6]);
+/// assert!(TwoDimensionalArrayRef::try_from(m.columns(0, 2)).is_err());
+/// ```
+impl<'a, T: Scalar> TryFrom<DMatrixView<'a, T, Dyn, U1>> for TwoDimensionalArrayRef<'a, T> {
+    type Error = ShapeError;
+
+    fn try_from(view: DMatrixView<'a, T, Dyn, U1>) -> Result<Self, ShapeError> {
+        let shape = view.shape();
+        if !is_row_major(shape, view.strides().0) {
+            return Err(ShapeError::NotContiguous);
+        }
+        // SAFETY: The rows follow one another with no gaps, so the view
+        // covers exactly `num_rows * num_cols` consecutive elements, which
+        // it borrows for `'a`.
+        let buffer = unsafe { core::slice::from_raw_parts(view.as_ptr(), shape.0 * shape.1) };
+        Ok(Self {
+            buffer,
+            num_rows: shape.0,
+            num_cols: shape.1,
+        })
+    }
+}
+
+/// Views the elements of a mutable row-major `nalgebra` view in place.
+/// See `TwoDimensionalArrayRef`.
+///
+/// # Errors
+///
+/// Returns `ShapeError::NotContiguous` when there is a gap between rows.
+impl<'a, T: Scalar> TryFrom<DMatrixViewMut<'a, T, Dyn, U1>> for TwoDimensionalArray<'a, T> {
+    type Error = ShapeError;
+
+    fn try_from(mut view: DMatrixViewMut<'a, T, Dyn, U1>) -> Result<Self, ShapeError> {
+        let shape = view.shape();
+        if !is_row_major(shape, view.strides().0) {
+            return Err(ShapeError::NotContiguous);
+        }
+        // SAFETY: As for `TwoDimensionalArrayRef`, and the view is consumed
+        // so the buffer is borrowed exclusively for `'a`.
+        let buffer =
+            unsafe { core::slice::from_raw_parts_mut(view.as_mut_ptr(), shape.0 * shape.1) };
+        Ok(Self {
+            buffer,
+            num_rows: shape.0,
+            num_cols: shape.1,
+        })
+    }
+}
+
+/// Copies the array into a column-major `DMatrix`.
+impl<T: Scalar> From<&TwoDimensionalVec<T>> for DMatrix<T> {
+    fn from(array: &TwoDimensionalVec<T>) -> Self {
+        DMatrix::from_row_slice(array.num_rows, array.num_cols, &array.buffer)
+    }
+}
+
+/// Copies a column-major `DMatrix` into a row-major array.
+///
+/// # Example
+///
+/// ```
+/// use nalgebra::DMatrix;
+/// use two_dim_array::TwoDimensionalVec;
+///
+/// let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+/// assert_eq!(m.as_slice(), [1, 4, 2, 5, 3, 6]);
+///
+/// let x = TwoDimensionalVec::from(&m);
+/// assert_eq!(x.as_slice(), [1, 2, 3, 4, 5, 6]);
+/// assert_eq!(DMatrix::from(&x), m);
+/// ```
+impl<T: Scalar> From<&DMatrix<T>> for TwoDimensionalVec<T> {
+    fn from(matrix: &DMatrix<T>) -> Self {
+        let (num_rows, num_cols) = matrix.shape();
+        TwoDimensionalVec::from_fn(num_rows, num_cols, |row, col| matrix[(row, col)].clone())
+    }
+}
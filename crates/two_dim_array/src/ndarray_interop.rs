@@ -0,0 +1,120 @@
+use ndarray::{ArrayView2, ArrayViewMut2};
+
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+/// Zero-copy conversion to `ndarray` views, available with the `ndarray`
+/// feature.
+impl<'a, T> TwoDimensionalArrayRef<'a, T> {
+    /// Returns an `ndarray` view of the array sharing its buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// let x = TwoDimensionalArrayRef::new(&[1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+    /// let nd = x.as_ndarray();
+    ///
+    /// assert_eq!(nd.dim(), (2, 3));
+    /// assert_eq!(nd.column(1).sum(), 7);
+    /// ```
+    pub fn as_ndarray(&self) -> ArrayView2<'a, T> {
+        ArrayView2::from_shape(self.shape(), self.buffer).expect("shape matches the buffer")
+    }
+}
+
+/// Zero-copy conversion to `ndarray` views, available with the `ndarray`
+/// feature.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an `ndarray` view of the array sharing its buffer. See
+    /// `TwoDimensionalArrayRef::as_ndarray`.
+    pub fn as_ndarray(&self) -> ArrayView2<'_, T> {
+        self.as_view().as_ndarray()
+    }
+
+    /// Returns a mutable `ndarray` view of the array sharing its buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.as_ndarray_mut().column_mut(0).fill(0);
+    /// assert_eq!(a, [0, 2, 0, 4]);
+    /// ```
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        ArrayViewMut2::from_shape(self.shape(), &mut *self.buffer)
+            .expect("shape matches the buffer")
+    }
+}
+
+/// Zero-copy conversion to `ndarray` views, available with the `ndarray`
+/// feature.
+impl<T> TwoDimensionalVec<T> {
+    /// Returns an `ndarray` view of the array sharing its buffer. See
+    /// `TwoDimensionalArrayRef::as_ndarray`.
+    pub fn as_ndarray(&self) -> ArrayView2<'_, T> {
+        self.as_view().as_ndarray()
+    }
+
+    /// Returns a mutable `ndarray` view of the array sharing its buffer.
+    /// See `TwoDimensionalArray::as_ndarray_mut`.
+    pub fn as_ndarray_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        ArrayViewMut2::from_shape(self.shape(), &mut self.buffer).expect("shape matches the buffer")
+    }
+}
+
+/// Views the elements of an `ndarray` view in place.
+///
+/// # Errors
+///
+/// Returns `ShapeError::NotContiguous` unless the view is in standard
+/// (row-major, contiguous) layout, which a transposed view is not.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::array;
+/// use two_dim_array::TwoDimensionalArray;
+///
+/// let mut nd = array![[1, 2], [3, 4]];
+/// assert!(TwoDimensionalArray::try_from(nd.view_mut().reversed_axes()).is_err());
+///
+/// let mut x = TwoDimensionalArray::try_from(nd.view_mut()).unwrap();
+/// *x.get_mut_panic(1, 0) = 7;
+/// assert_eq!(nd, array![[1, 2], [7, 4]]);
+/// ```
+impl<'a, T> TryFrom<ArrayViewMut2<'a, T>> for TwoDimensionalArray<'a, T> {
+    type Error = ShapeError;
+
+    fn try_from(view: ArrayViewMut2<'a, T>) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = view.dim();
+        let buffer = view.into_slice().ok_or(ShapeError::NotContiguous)?;
+        Ok(Self {
+            buffer,
+            num_rows,
+            num_cols,
+        })
+    }
+}
+
+/// Views the elements of an `ndarray` view in place. See
+/// `TwoDimensionalArray`.
+///
+/// # Errors
+///
+/// Returns `ShapeError::NotContiguous` unless the view is in standard
+/// (row-major, contiguous) layout.
+impl<'a, T> TryFrom<ArrayView2<'a, T>> for TwoDimensionalArrayRef<'a, T> {
+    type Error = ShapeError;
+
+    fn try_from(view: ArrayView2<'a, T>) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = view.dim();
+        let buffer = view.to_slice().ok_or(ShapeError::NotContiguous)?;
+        Ok(Self {
+            buffer,
+            num_rows,
+            num_cols,
+        })
+    }
+}
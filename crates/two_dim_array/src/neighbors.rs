@@ -0,0 +1,131 @@
+use crate::TwoDimensionalArray;
+
+/// Offsets of the edge-adjacent positions, in row-major order.
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the edge- and corner-adjacent positions, in row-major order.
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Returns the positions `offsets` away from `(row, col)` which lie within
+/// `shape`.
+fn clipped<const N: usize>(
+    offsets: [(isize, isize); N],
+    (row, col): (usize, usize),
+    (num_rows, num_cols): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    offsets.into_iter().filter_map(move |(dr, dc)| {
+        let row = row.checked_add_signed(dr).filter(|&row| row < num_rows)?;
+        let col = col.checked_add_signed(dc).filter(|&col| col < num_cols)?;
+        Some((row, col))
+    })
+}
+
+/// Neighbourhoods of positions on the grid.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the in-bounds positions sharing an edge
+    /// with `(row, col)`, in row-major order. Positions past the edges of
+    /// the array are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 9];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    ///
+    /// assert_eq!(x.neighbors4(1, 1).count(), 4);
+    /// assert_eq!(x.neighbors4(0, 2).collect::<Vec<_>>(), [(0, 1), (1, 2)]);
+    /// ```
+    pub fn neighbors4(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        clipped(OFFSETS4, (row, col), self.shape())
+    }
+
+    /// Returns an iterator over the in-bounds positions sharing an edge or
+    /// corner with `(row, col)`, in row-major order. Positions past the
+    /// edges of the array are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 9];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    ///
+    /// assert_eq!(x.neighbors8(1, 1).count(), 8);
+    /// assert_eq!(x.neighbors8(2, 0).collect::<Vec<_>>(), [(1, 0), (1, 1), (2, 1)]);
+    /// ```
+    pub fn neighbors8(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        clipped(OFFSETS8, (row, col), self.shape())
+    }
+
+    /// Returns an iterator over the in-bounds edge-adjacent positions of
+    /// `(row, col)` along with references to their elements. See
+    /// `neighbors4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// let sum: i32 = x.neighbor_values4(0, 1).map(|(_, value)| value).sum();
+    /// assert_eq!(sum, 1 + 3 + 5);
+    /// ```
+    pub fn neighbor_values4(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors4(row, col)
+            .map(|(row, col)| ((row, col), &self.buffer[row * self.num_cols + col]))
+    }
+
+    /// Returns an iterator over the in-bounds edge- and corner-adjacent
+    /// positions of `(row, col)` along with references to their elements.
+    /// See `neighbors8`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 0, 1,
+    ///     0, 1, 1,
+    ///     0, 0, 0,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    ///
+    /// // Live neighbours in the Game of Life.
+    /// let live = x.neighbor_values8(1, 1).filter(|&(_, &cell)| cell == 1).count();
+    /// assert_eq!(live, 3);
+    /// ```
+    pub fn neighbor_values8(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors8(row, col)
+            .map(|(row, col)| ((row, col), &self.buffer[row * self.num_cols + col]))
+    }
+}
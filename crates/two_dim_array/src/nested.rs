@@ -0,0 +1,163 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::{ShapeError, TwoDimensionalVec};
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// Viewing nested fixed-size rows in place.
+impl<'a, T> TwoDimensionalArray<'a, T> {
+    /// Construct a `TwoDimensionalArray` viewing `rows`, with one row per
+    /// inner array, without copying.
+    ///
+    /// Nested arrays are laid out contiguously in row-major order, so the
+    /// rows are flattened into the buffer with `as_flattened_mut`. A
+    /// `&mut [[T; C]; R]` coerces to the slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut grid = [[1, 2, 3], [4, 5, 6]];
+    /// let mut x = TwoDimensionalArray::from_nested(&mut grid);
+    ///
+    /// assert_eq!(x.shape(), (2, 3));
+    /// *x.get_mut_panic(1, 0) = 9;
+    /// assert_eq!(grid, [[1, 2, 3], [9, 5, 6]]);
+    /// ```
+    pub const fn from_nested<const C: usize>(rows: &'a mut [[T; C]]) -> Self {
+        let num_rows = rows.len();
+        Self {
+            buffer: rows.as_flattened_mut(),
+            num_rows,
+            num_cols: C,
+        }
+    }
+}
+
+impl<'a, T> TwoDimensionalArrayRef<'a, T> {
+    /// Construct a `TwoDimensionalArrayRef` viewing `rows` without copying.
+    /// See `TwoDimensionalArray::from_nested`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// let x = TwoDimensionalArrayRef::from_nested(&[[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(x.shape(), (3, 2));
+    /// assert_eq!(x.get(2, 1), Some(&6));
+    /// ```
+    pub const fn from_nested<const C: usize>(rows: &'a [[T; C]]) -> Self {
+        Self {
+            buffer: rows.as_flattened(),
+            num_rows: rows.len(),
+            num_cols: C,
+        }
+    }
+}
+
+/// Views the nested rows in place. See `TwoDimensionalArray::from_nested`.
+impl<'a, T, const R: usize, const C: usize> From<&'a mut [[T; C]; R]>
+    for TwoDimensionalArray<'a, T>
+{
+    fn from(rows: &'a mut [[T; C]; R]) -> Self {
+        Self::from_nested(rows)
+    }
+}
+
+/// Views the nested rows in place. See `TwoDimensionalArrayRef::from_nested`.
+impl<'a, T, const R: usize, const C: usize> From<&'a [[T; C]; R]>
+    for TwoDimensionalArrayRef<'a, T>
+{
+    fn from(rows: &'a [[T; C]; R]) -> Self {
+        Self::from_nested(rows)
+    }
+}
+
+/// Copying out to nested `Vec`s of rows.
+impl<T: Clone> TwoDimensionalArrayRef<'_, T> {
+    /// Returns a copy of the array as a `Vec` of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// let x = TwoDimensionalArrayRef::new(&[1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+    /// assert_eq!(x.to_nested_vec(), [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        (0..self.num_rows)
+            .map(|row_idx| self.get_panic(row_idx, ..).to_vec())
+            .collect()
+    }
+}
+
+impl<T: Clone> TwoDimensionalArray<'_, T> {
+    /// Returns a copy of the array as a `Vec` of rows. See
+    /// `TwoDimensionalArrayRef::to_nested_vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        self.as_view().to_nested_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> TwoDimensionalVec<T> {
+    /// Returns a copy of the array as a `Vec` of rows. See
+    /// `TwoDimensionalArrayRef::to_nested_vec`.
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        self.as_view().to_nested_vec()
+    }
+}
+
+/// Moves the rows out into a `Vec` of rows.
+#[cfg(feature = "alloc")]
+impl<T> From<TwoDimensionalVec<T>> for Vec<Vec<T>> {
+    fn from(array: TwoDimensionalVec<T>) -> Self {
+        let num_cols = array.num_cols;
+        let mut elems = array.buffer.into_iter();
+        (0..array.num_rows)
+            .map(|_| elems.by_ref().take(num_cols).collect())
+            .collect()
+    }
+}
+
+/// Builds the array from a `Vec` of rows, moving the elements.
+///
+/// An empty `Vec` gives a `0 x 0` array.
+///
+/// # Errors
+///
+/// Returns `ShapeError::LengthMismatch` when a row does not have as many
+/// elements as the first.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalVec;
+/// let x = TwoDimensionalVec::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+/// assert_eq!(x.shape(), (2, 2));
+/// assert_eq!(Vec::from(x), [[1, 2], [3, 4]]);
+///
+/// assert!(TwoDimensionalVec::try_from(vec![vec![1, 2], vec![3]]).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<Vec<Vec<T>>> for TwoDimensionalVec<T> {
+    type Error = ShapeError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, ShapeError> {
+        let num_cols = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().find(|row| row.len() != num_cols) {
+            return Err(ShapeError::LengthMismatch {
+                expected: num_cols,
+                found: row.len(),
+            });
+        }
+        Ok(Self {
+            num_rows: rows.len(),
+            num_cols,
+            buffer: rows.into_iter().flatten().collect(),
+        })
+    }
+}
@@ -0,0 +1,256 @@
+//! Seeded gradient noise for filling float grids, such as terrain
+//! heightmaps.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalVec;
+//! use two_dim_array::noise::{Noise, NoiseKind};
+//!
+//! let mut heightmap = TwoDimensionalVec::from_vec(vec![0.0f32; 64 * 64], 64, 64).unwrap();
+//! let noise = Noise {
+//!     kind: NoiseKind::Simplex,
+//!     seed: 42,
+//!     octaves: 4,
+//!     ..Noise::default()
+//! };
+//! heightmap.as_view_mut().fill_noise(&noise);
+//!
+//! assert!(heightmap.as_slice().iter().all(|h| (-1.0..=1.0).contains(h)));
+//! ```
+
+use core::ops::Range;
+
+use crate::TwoDimensionalArray;
+use crate::num::Float;
+
+/// The gradient noise function evaluated for each octave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseKind {
+    /// Ken Perlin's improved gradient noise on a square lattice.
+    Perlin,
+    /// Simplex noise on a triangular lattice, with fewer directional
+    /// artifacts than Perlin noise.
+    Simplex,
+}
+
+/// Parameters of fractal Brownian motion (fBm) noise, a sum of `octaves`
+/// layers of gradient noise of increasing frequency and decreasing
+/// amplitude.
+///
+/// Grid cell `(row, col)` samples the noise at `x = col`, `y = row`
+/// scaled by `frequency`, so neighbouring regions of one grid line up.
+/// Values are normalised to lie in `[-1, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Noise {
+    pub kind: NoiseKind,
+    /// Seed for the lattice gradients. Equal seeds give equal noise.
+    pub seed: u64,
+    /// Frequency of the first octave in cycles per cell.
+    pub frequency: f64,
+    /// Number of octaves summed. Zero octaves gives a flat zero field.
+    pub octaves: u32,
+    /// Frequency multiplier between successive octaves.
+    pub lacunarity: f64,
+    /// Amplitude multiplier between successive octaves.
+    pub persistence: f64,
+}
+
+impl Default for Noise {
+    /// Single octave Perlin noise with seed `0` and a frequency of one
+    /// cycle per 16 cells.
+    fn default() -> Self {
+        Self {
+            kind: NoiseKind::Perlin,
+            seed: 0,
+            frequency: 1.0 / 16.0,
+            octaves: 1,
+            lacunarity: 2.0,
+            persistence: 0.5,
+        }
+    }
+}
+
+/// Noise parameters with the permutation table derived from the seed.
+struct Sampler {
+    noise: Noise,
+    perm: [u8; 512],
+    norm: f64,
+}
+
+impl Sampler {
+    fn new(noise: &Noise) -> Self {
+        let mut perm = [0u8; 512];
+        let mut table: [u8; 256] = core::array::from_fn(|idx| idx as u8);
+        let mut state = noise.seed;
+        for idx in (1..table.len()).rev() {
+            let swap_idx = (splitmix64(&mut state) % (idx as u64 + 1)) as usize;
+            table.swap(idx, swap_idx);
+        }
+        perm[..256].copy_from_slice(&table);
+        perm[256..].copy_from_slice(&table);
+        let norm = (0..noise.octaves)
+            .map(|octave| noise.persistence.powi(octave as i32))
+            .sum();
+        Self {
+            noise: *noise,
+            perm,
+            norm,
+        }
+    }
+
+    fn sample(&self, x: f64, y: f64) -> f64 {
+        if self.noise.octaves == 0 || self.norm == 0.0 {
+            return 0.0;
+        }
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = self.noise.frequency;
+        for _ in 0..self.noise.octaves {
+            let value = match self.noise.kind {
+                NoiseKind::Perlin => self.perlin(x * frequency, y * frequency),
+                NoiseKind::Simplex => self.simplex(x * frequency, y * frequency),
+            };
+            total += amplitude * value;
+            amplitude *= self.noise.persistence;
+            frequency *= self.noise.lacunarity;
+        }
+        (total / self.norm).clamp(-1.0, 1.0)
+    }
+
+    fn hash(&self, x: i64, y: i64) -> u8 {
+        let x = (x & 255) as usize;
+        let y = (y & 255) as usize;
+        self.perm[self.perm[x] as usize + y]
+    }
+
+    fn perlin(&self, x: f64, y: f64) -> f64 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (xf, yf) = (x - x0, y - y0);
+        let (xi, yi) = (x0 as i64, y0 as i64);
+        let (u, v) = (fade(xf), fade(yf));
+        let top = lerp(
+            u,
+            grad(self.hash(xi, yi), xf, yf),
+            grad(self.hash(xi + 1, yi), xf - 1.0, yf),
+        );
+        let bottom = lerp(
+            u,
+            grad(self.hash(xi, yi + 1), xf, yf - 1.0),
+            grad(self.hash(xi + 1, yi + 1), xf - 1.0, yf - 1.0),
+        );
+        lerp(v, top, bottom)
+    }
+
+    fn simplex(&self, x: f64, y: f64) -> f64 {
+        const F2: f64 = 0.366_025_403_784_438_6; // (sqrt(3) - 1) / 2
+        const G2: f64 = 0.211_324_865_405_187_1; // (3 - sqrt(3)) / 6
+
+        let skew = (x + y) * F2;
+        let (i, j) = ((x + skew).floor(), (y + skew).floor());
+        let unskew = (i + j) * G2;
+        let (x0, y0) = (x - (i - unskew), y - (j - unskew));
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let corners = [
+            (x0, y0, 0, 0),
+            (x0 - i1 as f64 + G2, y0 - j1 as f64 + G2, i1, j1),
+            (x0 - 1.0 + 2.0 * G2, y0 - 1.0 + 2.0 * G2, 1, 1),
+        ];
+        let (i, j) = (i as i64, j as i64);
+        let total: f64 = corners
+            .iter()
+            .map(|&(dx, dy, di, dj)| {
+                let falloff = 0.5 - dx * dx - dy * dy;
+                if falloff < 0.0 {
+                    0.0
+                } else {
+                    falloff.powi(4) * grad(self.hash(i + di, j + dj), dx, dy)
+                }
+            })
+            .sum();
+        70.0 * total
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Dot product of `(x, y)` with one of eight lattice gradients.
+fn grad(hash: u8, x: f64, y: f64) -> f64 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Overwrites every element of the array with `noise` sampled at its
+    /// position. See the `noise` module.
+    pub fn fill_noise(&mut self, noise: &Noise) {
+        let (num_rows, num_cols) = self.shape();
+        self.fill_noise_region(0..num_rows, 0..num_cols, noise);
+    }
+
+    /// Overwrites the elements in the region `rows` x `cols` of the array
+    /// with `noise` sampled at their positions in the whole array, so the
+    /// region matches what `fill_noise` would have written there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// use two_dim_array::noise::Noise;
+    ///
+    /// let noise = Noise { seed: 7, ..Noise::default() };
+    /// let mut a = [0.0; 64];
+    /// let mut b = [0.0; 64];
+    /// let mut full = TwoDimensionalArray::new(&mut a, 8, 8).unwrap();
+    /// let mut partial = TwoDimensionalArray::new(&mut b, 8, 8).unwrap();
+    /// full.fill_noise(&noise);
+    /// partial.fill_noise_region(2..5, 3..8, &noise);
+    ///
+    /// assert_eq!(full.get(3, 4), partial.get(3, 4));
+    /// assert_eq!(partial.get(0, 0), Some(&0.0));
+    /// ```
+    pub fn fill_noise_region(&mut self, rows: Range<usize>, cols: Range<usize>, noise: &Noise) {
+        let (row_start, col_start) = (rows.start, cols.start);
+        let (num_rows, num_cols) = self.shape();
+        let mut region = self
+            .sub_view_mut(rows.clone(), cols.clone())
+            .unwrap_or_else(|| {
+                panic!(
+                    "region {:?} x {:?} out of bounds for {}x{} array",
+                    rows, cols, num_rows, num_cols
+                )
+            });
+        let sampler = Sampler::new(noise);
+        let region_cols = region.num_cols();
+        for (idx, elem) in region.iter_mut().enumerate() {
+            let row = row_start + idx / region_cols;
+            let col = col_start + idx % region_cols;
+            *elem = T::from_f64(sampler.sample(col as f64, row as f64));
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Reading and writing arrays in NumPy's `.npy` format, for exchange with
+//! `numpy.save` and `numpy.load`.
+//!
+//! Arrays are written as format version 1.0 in C (row-major) order with
+//! little-endian elements. Versions 1.0 to 3.0 are read, and arrays saved
+//! in Fortran (column-major) order are transposed into row-major order.
+//! Only two-dimensional arrays of the element types implementing
+//! `NpyElement` are accepted.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalArray;
+//! use two_dim_array::TwoDimensionalVec;
+//!
+//! let mut a = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+//! let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+//!
+//! let mut encoded = Vec::new();
+//! x.write_npy(&mut encoded).unwrap();
+//! assert!(encoded.starts_with(b"\x93NUMPY\x01\x00"));
+//! assert_eq!(encoded.len(), 128 + 6 * 4);
+//!
+//! let decoded = TwoDimensionalVec::<f32>::read_npy(encoded.as_slice()).unwrap();
+//! assert_eq!(decoded, x);
+//! ```
+
+use std::io::{self, Read, Write};
+
+use crate::bytes::FixedWidth;
+use crate::error::checked_len;
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// The length the magic bytes, version and header are padded to.
+const ALIGN: usize = 64;
+
+/// Element types with a NumPy dtype.
+///
+/// Implemented for the primitive integer types up to 64 bits, `f32`, `f64`
+/// and `bool`.
+pub trait NpyElement: FixedWidth {
+    /// The little-endian dtype string, such as `<f8`.
+    const DESCR: &'static str;
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $descr:literal)*) => {
+        $(
+            impl NpyElement for $t {
+                const DESCR: &'static str = $descr;
+            }
+        )*
+    };
+}
+
+impl_npy_element! {
+    u8 => "|u1" u16 => "<u2" u32 => "<u4" u64 => "<u8"
+    i8 => "|i1" i16 => "<i2" i32 => "<i4" i64 => "<i8"
+    f32 => "<f4" f64 => "<f8" bool => "|b1"
+}
+
+impl<T: NpyElement> TwoDimensionalArrayRef<'_, T> {
+    /// Writes the array to `writer` in the `.npy` format. See the `npy`
+    /// module.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            T::DESCR,
+            self.num_rows,
+            self.num_cols
+        );
+        let unpadded_len = MAGIC.len() + 4 + header.len() + 1;
+        header.extend(std::iter::repeat_n(
+            ' ',
+            unpadded_len.next_multiple_of(ALIGN) - unpadded_len,
+        ));
+        header.push('\n');
+        let header_len = u16::try_from(header.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "header too long"))?;
+
+        let mut out =
+            Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.buffer.len() * T::WIDTH);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[1, 0]);
+        out.extend_from_slice(&header_len.to_le_bytes());
+        out.extend_from_slice(header.as_bytes());
+        let mut elem_buf = vec![0; T::WIDTH];
+        for elem in self.buffer {
+            elem.write_le(&mut elem_buf);
+            out.extend_from_slice(&elem_buf);
+        }
+        writer.write_all(&out)
+    }
+}
+
+impl<T: NpyElement> TwoDimensionalArray<'_, T> {
+    /// Writes the array to `writer` in the `.npy` format. See
+    /// `TwoDimensionalArrayRef::write_npy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.as_view().write_npy(writer)
+    }
+}
+
+impl<T: NpyElement> TwoDimensionalVec<T> {
+    /// Writes the array to `writer` in the `.npy` format. See
+    /// `TwoDimensionalArrayRef::write_npy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_npy<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.as_view().write_npy(writer)
+    }
+
+    /// Reads an array in the `.npy` format, such as one saved by
+    /// `numpy.save`. See the `npy` module.
+    ///
+    /// # Errors
+    ///
+    /// Returns `io::ErrorKind::InvalidData` for malformed input, including
+    /// a dtype other than `T::DESCR`, a shape which is not two-dimensional
+    /// and invalid `bool` elements, and any error produced by `reader`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    ///
+    /// // `numpy.save(f, numpy.array([[1, 2], [3, 4]], dtype='<i2', order='F'))`
+    /// let mut encoded = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+    /// let header = "{'descr': '<i2', 'fortran_order': True, 'shape': (2, 2), }";
+    /// encoded.extend(format!("{:<117}\n", header).bytes());
+    /// encoded.extend([1, 0, 3, 0, 2, 0, 4, 0]);
+    ///
+    /// let x = TwoDimensionalVec::<i16>::read_npy(encoded.as_slice()).unwrap();
+    /// assert_eq!(x, [[1, 2], [3, 4]]);
+    ///
+    /// assert!(TwoDimensionalVec::<f64>::read_npy(encoded.as_slice()).is_err());
+    /// ```
+    pub fn read_npy<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut preamble = [0; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != MAGIC {
+            return Err(invalid_data("missing npy magic bytes"));
+        }
+        let header_len = match preamble[6] {
+            1 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                usize::from(u16::from_le_bytes(len))
+            }
+            2 | 3 => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                usize::try_from(u32::from_le_bytes(len))
+                    .map_err(|_| invalid_data("header length overflows usize"))?
+            }
+            _ => return Err(invalid_data("unsupported npy version")),
+        };
+        let mut header = Vec::new();
+        reader
+            .by_ref()
+            .take(header_len as u64)
+            .read_to_end(&mut header)?;
+        if header.len() != header_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let header =
+            std::str::from_utf8(&header).map_err(|_| invalid_data("header is not UTF-8"))?;
+        let (descr, fortran_order, (num_rows, num_cols)) = parse_header(header)?;
+        if descr != T::DESCR {
+            return Err(invalid_data(format!(
+                "dtype {} does not match the element type's {}",
+                descr,
+                T::DESCR
+            )));
+        }
+
+        let len = checked_len(num_rows, num_cols).map_err(|err| invalid_data(err.to_string()))?;
+        let data_len = len
+            .checked_mul(T::WIDTH)
+            .ok_or_else(|| invalid_data("data length overflows usize"))?;
+        let mut data = Vec::new();
+        reader.take(data_len as u64).read_to_end(&mut data)?;
+        if data.len() != data_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let buffer = data
+            .chunks_exact(T::WIDTH)
+            .map(|bytes| T::read_le(bytes).ok_or_else(|| invalid_data("invalid element")))
+            .collect::<io::Result<_>>()?;
+        if fortran_order {
+            // The data holds the columns in turn, which are the rows of the
+            // transpose.
+            let mut array = Self::from_vec(buffer, num_cols, num_rows)
+                .map_err(|err| invalid_data(err.to_string()))?;
+            array.transpose_in_place();
+            Ok(array)
+        } else {
+            Self::from_vec(buffer, num_rows, num_cols).map_err(|err| invalid_data(err.to_string()))
+        }
+    }
+}
+
+/// Returns the dtype, whether the data is in Fortran order and the shape
+/// from a header dictionary, such as
+/// `{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }`.
+fn parse_header(header: &str) -> io::Result<(&str, bool, (usize, usize))> {
+    let descr = header_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|descr| descr.split_once('\''))
+        .or_else(|| descr.strip_prefix('"')?.split_once('"'))
+        .map(|(descr, _)| descr)
+        .ok_or_else(|| invalid_data("invalid dtype in npy header"))?;
+
+    let fortran_order = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return Err(invalid_data("invalid fortran_order in npy header"));
+    };
+
+    let shape = header_value(header, "shape")?;
+    let dims: Vec<usize> = shape
+        .strip_prefix('(')
+        .and_then(|shape| shape.split_once(')'))
+        .ok_or_else(|| invalid_data("invalid shape in npy header"))?
+        .0
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid_data("invalid shape in npy header"))?;
+    let &[num_rows, num_cols] = dims.as_slice() else {
+        return Err(invalid_data(format!(
+            "expected a two-dimensional array but found {} dimensions",
+            dims.len()
+        )));
+    };
+    Ok((descr, fortran_order, (num_rows, num_cols)))
+}
+
+/// Returns the text following `'key':` in a header dictionary, with
+/// leading whitespace removed.
+fn header_value<'h>(header: &'h str, key: &str) -> io::Result<&'h str> {
+    [format!("'{}':", key), format!("\"{}\":", key)]
+        .iter()
+        .find_map(|pattern| Some(&header[header.find(pattern.as_str())? + pattern.len()..]))
+        .map(str::trim_start)
+        .ok_or_else(|| invalid_data(format!("npy header is missing {}", key)))
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
@@ -0,0 +1,96 @@
+//! Minimal numeric traits used by the arithmetic operations of the crate.
+//!
+//! These are implemented for the primitive integer and floating point
+//! types. Implement them for your own types to use those operations with
+//! them.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Types with an additive identity.
+pub trait Zero {
+    /// Returns the additive identity, `0`.
+    fn zero() -> Self;
+}
+
+/// Types with a multiplicative identity.
+pub trait One {
+    /// Returns the multiplicative identity, `1`.
+    fn one() -> Self;
+}
+
+macro_rules! impl_identities {
+    ($zero:literal, $one:literal; $($t:ty)*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_identities!(0, 1; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_identities!(0.0, 1.0; f32 f64);
+
+/// Floating point types, as needed by operations such as
+/// `TwoDimensionalArray::cholesky`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Returns the square root of the number.
+    fn sqrt(self) -> Self;
+
+    /// Returns the four quadrant arctangent of `self` (`y`) and `x` in
+    /// radians.
+    fn atan2(self, x: Self) -> Self;
+
+    /// Converts from an `f64`, rounding if necessary.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts to an `f64`.
+    fn to_f64(self) -> f64;
+}
+
+// The float methods are inherent methods of `std`, not `core`.
+#[cfg(feature = "std")]
+macro_rules! impl_float {
+    ($($t:ty)*) => {
+        $(
+            impl Float for $t {
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn atan2(self, x: Self) -> Self {
+                    <$t>::atan2(self, x)
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float!(f32 f64);
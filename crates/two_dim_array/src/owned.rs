@@ -0,0 +1,1061 @@
+use alloc::vec;
+use alloc::vec::{Drain, Vec};
+use core::mem;
+use core::ops::{Bound, RangeBounds};
+use core::slice::SliceIndex;
+
+use crate::error::{checked_len, infer_shape};
+use crate::{IndexError, ShapeError, TwoDimensionalArray};
+
+/// An owned two-dimensional array backed by a `Vec`.
+///
+/// Uses the same row-major layout as `TwoDimensionalArray`, with the same
+/// accessors, and hands out views of itself through `as_view` and
+/// `as_view_mut` for everything else. Produced by operations which need to
+/// allocate, such as `tile`.
+///
+/// # Example
+/// ```
+/// use two_dim_array::TwoDimensionalVec;
+///
+/// let mut owned = TwoDimensionalVec::from_fn(2, 2, |row, col| 2 * row + col);
+/// *owned.get_mut_panic(0, 1) = 42;
+/// assert_eq!(owned.as_slice(), [0, 42, 2, 3]);
+///
+/// let mut view = owned.as_view_mut();
+/// view.as_mut_slice()[3] = 7;
+/// assert_eq!(owned.get(1, ..), Some(&[2, 7][..]));
+/// ```
+#[derive(Clone)]
+pub struct TwoDimensionalVec<T> {
+    pub(crate) buffer: Vec<T>,
+    pub(crate) num_rows: usize,
+    pub(crate) num_cols: usize,
+}
+
+impl<T> TwoDimensionalVec<T> {
+    /// Construct a `TwoDimensionalVec` taking ownership of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::InvalidShape` when the buffer cannot be arranged
+    /// with `num_rows * num_cols` and `ShapeError::Overflow` when that
+    /// product overflows.
+    pub fn from_vec(buffer: Vec<T>, num_rows: usize, num_cols: usize) -> Result<Self, ShapeError> {
+        if buffer.len() != checked_len(num_rows, num_cols)? {
+            Err(ShapeError::InvalidShape {
+                buffer_len: buffer.len(),
+                num_rows,
+                num_cols,
+            })
+        } else {
+            Ok(Self {
+                buffer,
+                num_rows,
+                num_cols,
+            })
+        }
+    }
+
+    /// Returns the shape of the array.
+    ///
+    /// Return order: `(num_rows, num_cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// The number of rows in the array.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the array.
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the total number of elements (`num_rows * num_cols`).
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns whether the array holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns a mutable `TwoDimensionalArray` view of the array.
+    pub fn as_view_mut(&mut self) -> TwoDimensionalArray<'_, T> {
+        TwoDimensionalArray {
+            buffer: &mut self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        }
+    }
+
+    /// Returns a reference to the underlying row-major buffer.
+    pub fn as_slice(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// Returns a mutable reference to the underlying row-major buffer.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.buffer
+    }
+
+    /// Consumes the array, returning the underlying row-major buffer.
+    pub fn into_vec(self) -> Vec<T> {
+        self.buffer
+    }
+}
+
+/// Constructors which fill the buffer, and reshaping.
+impl<T> TwoDimensionalVec<T> {
+    /// Construct a `num_rows` by `num_cols` array with every element set to
+    /// `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows * num_cols` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let x = TwoDimensionalVec::from_elem(0, 2, 3);
+    /// assert_eq!(x.shape(), (2, 3));
+    /// assert_eq!(x.as_slice(), [0; 6]);
+    /// ```
+    pub fn from_elem(value: T, num_rows: usize, num_cols: usize) -> Self
+    where
+        T: Clone,
+    {
+        let len = checked_len(num_rows, num_cols).expect("array length overflows usize");
+        Self {
+            buffer: vec![value; len],
+            num_rows,
+            num_cols,
+        }
+    }
+
+    /// Construct a `num_rows` by `num_cols` array, setting each element to
+    /// `f(row, col)` in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows * num_cols` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let x = TwoDimensionalVec::from_fn(2, 3, |row, col| 10 * row + col);
+    /// assert_eq!(x.as_slice(), [0, 1, 2, 10, 11, 12]);
+    /// ```
+    pub fn from_fn<F>(num_rows: usize, num_cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let len = checked_len(num_rows, num_cols).expect("array length overflows usize");
+        let mut buffer = Vec::with_capacity(len);
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                buffer.push(f(row, col));
+            }
+        }
+        Self {
+            buffer,
+            num_rows,
+            num_cols,
+        }
+    }
+
+    /// Construct a `TwoDimensionalVec` with `num_rows` rows, inferring the
+    /// number of columns from the length of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the buffer length is not a
+    /// multiple of `num_rows`. Zero rows are only accepted for an empty
+    /// buffer, giving zero columns.
+    pub fn from_vec_with_rows(buffer: Vec<T>, num_rows: usize) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = infer_shape(buffer.len(), num_rows, true)?;
+        Self::from_vec(buffer, num_rows, num_cols)
+    }
+
+    /// Construct a `TwoDimensionalVec` with `num_cols` columns, inferring
+    /// the number of rows from the length of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the buffer length is not a
+    /// multiple of `num_cols`. Zero columns are only accepted for an empty
+    /// buffer, giving zero rows.
+    pub fn from_vec_with_cols(buffer: Vec<T>, num_cols: usize) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = infer_shape(buffer.len(), num_cols, false)?;
+        Self::from_vec(buffer, num_rows, num_cols)
+    }
+
+    /// Update the shape of the array to have `num_rows`, `num_cols`,
+    /// keeping the buffer as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::InvalidShape` when the buffer cannot be reshaped
+    /// to the requested shape and `ShapeError::Overflow` when
+    /// `num_rows * num_cols` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+    /// x.reshape(3, 2).unwrap();
+    /// assert_eq!(x.get(2, ..), Some(&[5, 6][..]));
+    /// assert!(x.reshape(4, 2).is_err());
+    /// ```
+    pub fn reshape(&mut self, num_rows: usize, num_cols: usize) -> Result<(), ShapeError> {
+        let mut view = self.as_view_mut();
+        view.reshape(num_rows, num_cols)?;
+        (self.num_rows, self.num_cols) = (num_rows, num_cols);
+        Ok(())
+    }
+
+    /// Update the shape to have `num_rows` rows, inferring the number of
+    /// columns. See `TwoDimensionalArray::reshape_rows`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the length is not a multiple
+    /// of `num_rows`. The shape is unchanged on error.
+    pub fn reshape_rows(&mut self, num_rows: usize) -> Result<(), ShapeError> {
+        let (num_rows, num_cols) = infer_shape(self.buffer.len(), num_rows, true)?;
+        self.reshape(num_rows, num_cols)
+    }
+
+    /// Update the shape to have `num_cols` columns, inferring the number of
+    /// rows. See `TwoDimensionalArray::reshape_cols`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the length is not a multiple
+    /// of `num_cols`. The shape is unchanged on error.
+    pub fn reshape_cols(&mut self, num_cols: usize) -> Result<(), ShapeError> {
+        let (num_rows, num_cols) = infer_shape(self.buffer.len(), num_cols, false)?;
+        self.reshape(num_rows, num_cols)
+    }
+
+    /// Resizes the array to `num_rows` by `num_cols`, like `Vec::resize` in
+    /// two dimensions. Elements in the overlap of the old and new shapes
+    /// keep their positions, those outside the new shape are dropped and
+    /// new positions are set to `value`.
+    ///
+    /// Changing only the number of rows resizes the buffer at its end,
+    /// while changing the number of columns rebuilds it in a single pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows * num_cols` overflows. If cloning `value` panics,
+    /// the array is left empty, with no rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4], 2, 2).unwrap();
+    /// x.resize(3, 3, 0);
+    /// assert_eq!(x.as_slice(), [1, 2, 0, 3, 4, 0, 0, 0, 0]);
+    /// x.resize(1, 2, 0);
+    /// assert_eq!(x.as_slice(), [1, 2]);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Bomb;
+    /// impl Clone for Bomb {
+    ///     fn clone(&self) -> Self {
+    ///         panic!("clone failed")
+    ///     }
+    /// }
+    /// let mut y = TwoDimensionalVec::from_vec(vec![Bomb, Bomb, Bomb, Bomb], 2, 2).unwrap();
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     y.resize(2, 3, Bomb)
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(y.shape(), (0, 2));
+    /// assert!(y.as_slice().is_empty());
+    /// ```
+    pub fn resize(&mut self, num_rows: usize, num_cols: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = checked_len(num_rows, num_cols).expect("array length overflows usize");
+        let old_rows = self.num_rows;
+        let mut buffer = mem::take(&mut self.buffer);
+        // The array stays empty but consistent until the new buffer is in
+        // place, in case cloning `value` panics.
+        self.num_rows = 0;
+        if num_cols != self.num_cols {
+            let kept_cols = num_cols.min(self.num_cols);
+            let mut old = mem::replace(&mut buffer, Vec::with_capacity(len)).into_iter();
+            for _ in 0..num_rows.min(old_rows) {
+                buffer.extend(old.by_ref().take(kept_cols));
+                old.by_ref().take(self.num_cols - kept_cols).for_each(drop);
+                buffer.extend(core::iter::repeat_n(value.clone(), num_cols - kept_cols));
+            }
+        }
+        buffer.resize(len, value);
+        self.buffer = buffer;
+        (self.num_rows, self.num_cols) = (num_rows, num_cols);
+    }
+}
+
+/// Element and row access, mirroring `TwoDimensionalArray`.
+impl<T> TwoDimensionalVec<T> {
+    /// Returns a reference to an element or row subslice, without doing
+    /// bounds checking. See `TwoDimensionalArray::get_unchecked`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is
+    /// *[undefined behavior]* even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked<I>(&self, row_idx: usize, col_idx: I) -> &I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        unsafe {
+            self.buffer
+                .get_unchecked(row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols)
+                .get_unchecked(col_idx)
+        }
+    }
+
+    /// Returns a mutable reference to an element or row subslice, without
+    /// doing bounds checking. See `TwoDimensionalArray::get_unchecked_mut`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is
+    /// *[undefined behavior]* even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked_mut<I>(&mut self, row_idx: usize, col_idx: I) -> &mut I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        unsafe {
+            self.buffer
+                .get_unchecked_mut(row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols)
+                .get_unchecked_mut(col_idx)
+        }
+    }
+
+    /// Returns a reference to an element or row subslice depending on the
+    /// type of index, or `None` if it is out of bounds. See
+    /// `TwoDimensionalArray::get`.
+    pub fn get<I>(&self, row_idx: usize, col_idx: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.buffer
+            .get(row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols)?
+            .get(col_idx)
+    }
+
+    /// Returns a mutable reference to an element or row subslice depending
+    /// on the type of index, or `None` if it is out of bounds.
+    pub fn get_mut<I>(&mut self, row_idx: usize, col_idx: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.buffer
+            .get_mut(row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols)?
+            .get_mut(col_idx)
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)` or an
+    /// `IndexError` carrying the index and shape if it is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` when either index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4], 2, 2).unwrap();
+    /// assert_eq!(x.try_get(1, 0).ok(), Some(&3));
+    /// assert_eq!(x.try_get(0, 2).unwrap_err().shape, (2, 2));
+    /// ```
+    pub fn try_get(&self, row_idx: usize, col_idx: usize) -> Result<&T, IndexError> {
+        self.check_index(row_idx, col_idx)?;
+        Ok(&self.buffer[row_idx * self.num_cols + col_idx])
+    }
+
+    /// Returns a mutable reference to the element at `(row_idx, col_idx)` or
+    /// an `IndexError` if it is out of bounds. See `try_get`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` when either index is out of bounds.
+    pub fn try_get_mut(&mut self, row_idx: usize, col_idx: usize) -> Result<&mut T, IndexError> {
+        self.check_index(row_idx, col_idx)?;
+        Ok(&mut self.buffer[row_idx * self.num_cols + col_idx])
+    }
+
+    fn check_index(&self, row_idx: usize, col_idx: usize) -> Result<(), IndexError> {
+        if row_idx >= self.num_rows || col_idx >= self.num_cols {
+            return Err(IndexError {
+                row: row_idx,
+                col: col_idx,
+                shape: self.shape(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a bounds checked reference to an element or row subslice
+    /// depending on the type of col_idx. Panics on out of bounds access.
+    pub fn get_panic<I>(&self, row_idx: usize, col_idx: I) -> &I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        &self.buffer[row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols][col_idx]
+    }
+
+    /// Returns a bounds checked, mutable reference to an element or row
+    /// subslice depending on the type of col_idx. Panics on out of bounds
+    /// access.
+    pub fn get_mut_panic<I>(&mut self, row_idx: usize, col_idx: I) -> &mut I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        &mut self.buffer[row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols][col_idx]
+    }
+
+    /// Returns an iterator yielding the array slices of the contiguous
+    /// rows of the buffer.
+    ///
+    /// An array without columns yields one empty slice per row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4], 2, 2).unwrap();
+    /// assert!(x.rows().eq([[1, 2], [3, 4]]));
+    ///
+    /// let empty = TwoDimensionalVec::<i32>::with_num_cols(0);
+    /// assert_eq!(empty.rows().count(), 0);
+    /// let no_cols = TwoDimensionalVec::<i32>::from_vec(vec![], 2, 0).unwrap();
+    /// assert!(no_cols.rows().all(|row| row.is_empty()));
+    /// assert_eq!(no_cols.rows().count(), 2);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Indexing rather than `chunks`, which rejects a length of zero.
+        (0..self.num_rows)
+            .map(|row_idx| &self.buffer[row_idx * self.num_cols..(row_idx + 1) * self.num_cols])
+    }
+
+    /// Returns an iterator yielding mutable references to the array
+    /// slices of the contiguous rows of the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_elem(0, 2, 2);
+    /// for (idx, row) in x.rows_mut().enumerate() {
+    ///     row[0] = idx + 1;
+    /// }
+    /// assert_eq!(x.as_slice(), [1, 0, 2, 0]);
+    ///
+    /// let mut no_cols = TwoDimensionalVec::<i32>::from_vec(vec![], 3, 0).unwrap();
+    /// assert_eq!(no_cols.rows_mut().count(), 3);
+    /// ```
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let num_cols = self.num_cols;
+        let mut rest = self.buffer.as_mut_slice();
+        (0..self.num_rows).map(move |_| {
+            let (row, tail) = mem::take(&mut rest).split_at_mut(num_cols);
+            rest = tail;
+            row
+        })
+    }
+}
+
+/// Appending and removing rows at the bottom, so the array can be built up
+/// incrementally.
+impl<T> TwoDimensionalVec<T> {
+    /// Construct an empty `TwoDimensionalVec` with no rows and `num_cols`
+    /// columns, to be filled with `push_row` and friends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut table = TwoDimensionalVec::with_num_cols(2);
+    /// table.push_row(&[1, 2]).unwrap();
+    /// table.push_row_from_iter(3..5).unwrap();
+    ///
+    /// assert_eq!(table.shape(), (2, 2));
+    /// assert_eq!(table.as_slice(), [1, 2, 3, 4]);
+    /// assert!(table.push_row(&[5]).is_err());
+    /// ```
+    pub fn with_num_cols(num_cols: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            num_rows: 0,
+            num_cols,
+        }
+    }
+
+    /// Appends a copy of `row` to the bottom of the array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `row.len()` is not
+    /// `num_cols`, leaving the array unchanged.
+    pub fn push_row(&mut self, row: &[T]) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        if row.len() != self.num_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_cols,
+                found: row.len(),
+            });
+        }
+        self.buffer.extend_from_slice(row);
+        self.num_rows += 1;
+        Ok(())
+    }
+
+    /// Appends the elements yielded by `row` as a new row at the bottom of
+    /// the array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `row` does not yield exactly
+    /// `num_cols` elements, leaving the array unchanged.
+    pub fn push_row_from_iter<I>(&mut self, row: I) -> Result<(), ShapeError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let old_len = self.buffer.len();
+        self.buffer.extend(row);
+        let found = self.buffer.len() - old_len;
+        if found != self.num_cols {
+            self.buffer.truncate(old_len);
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_cols,
+                found,
+            });
+        }
+        self.num_rows += 1;
+        Ok(())
+    }
+
+    /// Appends copies of all rows of `other` to the bottom of the array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `other` does not have
+    /// `num_cols` columns, leaving the array unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{TwoDimensionalArray, TwoDimensionalVec};
+    /// let mut table = TwoDimensionalVec::from_vec(vec![1, 2], 1, 2).unwrap();
+    /// let mut a = [3, 4, 5, 6];
+    /// table
+    ///     .extend_from_rows(&TwoDimensionalArray::new(&mut a, 2, 2).unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(table.shape(), (3, 2));
+    /// assert_eq!(table.as_slice(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn extend_from_rows(&mut self, other: &TwoDimensionalArray<'_, T>) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        if other.num_cols != self.num_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_cols,
+                found: other.num_cols,
+            });
+        }
+        self.buffer.extend_from_slice(other.buffer);
+        self.num_rows += other.num_rows;
+        Ok(())
+    }
+
+    /// Removes the bottom row and returns it, or `None` if the array has no
+    /// rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4], 2, 2).unwrap();
+    /// assert_eq!(x.pop_row(), Some(vec![3, 4]));
+    /// assert_eq!(x.pop_row(), Some(vec![1, 2]));
+    /// assert_eq!(x.pop_row(), None);
+    /// assert_eq!(x.shape(), (0, 2));
+    /// ```
+    pub fn pop_row(&mut self) -> Option<Vec<T>> {
+        self.num_rows = self.num_rows.checked_sub(1)?;
+        Some(self.buffer.split_off(self.num_rows * self.num_cols))
+    }
+}
+
+/// Inserting and removing rows and columns at arbitrary positions, shifting
+/// those after them.
+impl<T> TwoDimensionalVec<T> {
+    /// Inserts a copy of `row` so it becomes row `row_idx`, shifting the
+    /// rows from `row_idx` onwards down by one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `row.len()` is not
+    /// `num_cols`, leaving the array unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_idx > num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 5, 6], 2, 2).unwrap();
+    /// x.insert_row(1, &[3, 4]).unwrap();
+    /// assert_eq!(x.shape(), (3, 2));
+    /// assert_eq!(x.as_slice(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn insert_row(&mut self, row_idx: usize, row: &[T]) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        assert!(
+            row_idx <= self.num_rows,
+            "row {} out of bounds for array with {} rows",
+            row_idx,
+            self.num_rows
+        );
+        if row.len() != self.num_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_cols,
+                found: row.len(),
+            });
+        }
+        let start = row_idx * self.num_cols;
+        self.buffer.splice(start..start, row.iter().cloned());
+        self.num_rows += 1;
+        Ok(())
+    }
+
+    /// Removes row `row_idx` and returns it, shifting the rows after it up
+    /// by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_idx` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+    /// assert_eq!(x.remove_row(0), [1, 2]);
+    /// assert_eq!(x.as_slice(), [3, 4, 5, 6]);
+    /// ```
+    pub fn remove_row(&mut self, row_idx: usize) -> Vec<T> {
+        assert!(
+            row_idx < self.num_rows,
+            "row {} out of bounds for array with {} rows",
+            row_idx,
+            self.num_rows
+        );
+        let start = row_idx * self.num_cols;
+        self.num_rows -= 1;
+        self.buffer.drain(start..start + self.num_cols).collect()
+    }
+
+    /// Inserts the elements of `col` so they become column `col_idx`,
+    /// shifting the columns from `col_idx` onwards right by one.
+    ///
+    /// The buffer is rebuilt in a single pass, moving each element once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::LengthMismatch` when `col.len()` is not
+    /// `num_rows`, leaving the array unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_idx > num_cols` or if the new length overflows
+    /// `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 3, 4, 6], 2, 2).unwrap();
+    /// x.insert_col(1, &[2, 5]).unwrap();
+    /// assert_eq!(x.shape(), (2, 3));
+    /// assert_eq!(x.as_slice(), [1, 2, 3, 4, 5, 6]);
+    ///
+    /// // A panicking `Clone` leaves the array unchanged.
+    /// #[derive(Debug, PartialEq)]
+    /// struct Bomb;
+    /// impl Clone for Bomb {
+    ///     fn clone(&self) -> Self {
+    ///         panic!("clone failed")
+    ///     }
+    /// }
+    /// let mut y = TwoDimensionalVec::from_vec(vec![Bomb, Bomb], 2, 1).unwrap();
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     y.insert_col(0, &[Bomb, Bomb])
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(y.shape(), (2, 1));
+    /// assert_eq!(y.as_slice().len(), 2);
+    /// ```
+    pub fn insert_col(&mut self, col_idx: usize, col: &[T]) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        assert!(
+            col_idx <= self.num_cols,
+            "column {} out of bounds for array with {} columns",
+            col_idx,
+            self.num_cols
+        );
+        if col.len() != self.num_rows {
+            return Err(ShapeError::LengthMismatch {
+                expected: self.num_rows,
+                found: col.len(),
+            });
+        }
+        let num_cols = self
+            .num_cols
+            .checked_add(1)
+            .expect("array length overflows usize");
+        let len = checked_len(self.num_rows, num_cols).expect("array length overflows usize");
+        // Cloned up front, so that a panicking `Clone` leaves the array
+        // unchanged; the rebuild below only moves elements.
+        let col = col.to_vec();
+        let mut buffer = Vec::with_capacity(len);
+        let mut old = mem::take(&mut self.buffer).into_iter();
+        for value in col {
+            buffer.extend(old.by_ref().take(col_idx));
+            buffer.push(value);
+            buffer.extend(old.by_ref().take(self.num_cols - col_idx));
+        }
+        self.buffer = buffer;
+        self.num_cols = num_cols;
+        Ok(())
+    }
+
+    /// Removes column `col_idx` and returns it, shifting the columns after
+    /// it left by one.
+    ///
+    /// The remaining elements are compacted in place in a single pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_idx` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).unwrap();
+    /// assert_eq!(x.remove_col(0), [1, 4]);
+    /// assert_eq!(x.shape(), (2, 2));
+    /// assert_eq!(x.as_slice(), [2, 3, 5, 6]);
+    /// ```
+    pub fn remove_col(&mut self, col_idx: usize) -> Vec<T> {
+        assert!(
+            col_idx < self.num_cols,
+            "column {} out of bounds for array with {} columns",
+            col_idx,
+            self.num_cols
+        );
+        let num_cols = self.num_cols;
+        let mut idx = 0;
+        let removed = self
+            .buffer
+            .extract_if(.., |_| {
+                let in_col = idx % num_cols == col_idx;
+                idx += 1;
+                in_col
+            })
+            .collect();
+        self.num_cols -= 1;
+        removed
+    }
+}
+
+/// Row filtering, which changes the number of rows in the array.
+impl<T> TwoDimensionalVec<T> {
+    /// Retains only the rows for which `predicate` returns `true`,
+    /// compacting the buffer in place and preserving the order of the
+    /// retained rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+    /// x.retain_rows(|row| row[0] != 3);
+    /// assert_eq!(x.shape(), (2, 2));
+    /// assert_eq!(x.as_slice(), [1, 2, 5, 6]);
+    /// ```
+    pub fn retain_rows<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        let num_cols = self.num_cols;
+        let mut num_kept = 0;
+        for row_idx in 0..self.num_rows {
+            let start = row_idx * num_cols;
+            if !predicate(&self.buffer[start..start + num_cols]) {
+                continue;
+            }
+            if num_kept != row_idx {
+                // Rows before `row_idx` are either kept or already moved
+                // out of the way, so the kept prefix and this row are
+                // disjoint.
+                let (kept, rest) = self.buffer.split_at_mut(start);
+                kept[num_kept * num_cols..(num_kept + 1) * num_cols]
+                    .swap_with_slice(&mut rest[..num_cols]);
+            }
+            num_kept += 1;
+        }
+        self.buffer.truncate(num_kept * num_cols);
+        self.num_rows = num_kept;
+    }
+
+    /// Consumes the array, splitting it into the rows for which `predicate`
+    /// returns `true` and those for which it returns `false`, each in their
+    /// original order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+    /// let (small, large) = x.partition_rows(|row| row[0] < 3);
+    /// assert_eq!(small.as_slice(), [1, 2]);
+    /// assert_eq!(large.as_slice(), [3, 4, 5, 6]);
+    /// ```
+    pub fn partition_rows<F>(self, mut predicate: F) -> (Self, Self)
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        let num_cols = self.num_cols;
+        let keep: Vec<bool> = (0..self.num_rows)
+            .map(|row_idx| predicate(&self.buffer[row_idx * num_cols..(row_idx + 1) * num_cols]))
+            .collect();
+        let num_true = keep.iter().filter(|&&k| k).count();
+        let mut matching = Vec::with_capacity(num_true * num_cols);
+        let mut rest = Vec::with_capacity((self.num_rows - num_true) * num_cols);
+        for (idx, elem) in self.buffer.into_iter().enumerate() {
+            if keep[idx / num_cols] {
+                matching.push(elem);
+            } else {
+                rest.push(elem);
+            }
+        }
+        (
+            Self {
+                buffer: matching,
+                num_rows: num_true,
+                num_cols,
+            },
+            Self {
+                buffer: rest,
+                num_rows: self.num_rows - num_true,
+                num_cols,
+            },
+        )
+    }
+}
+
+/// A draining iterator over rows of a `TwoDimensionalVec`, yielding each
+/// row as a `Vec`.
+///
+/// Created by `TwoDimensionalVec::drain_rows`. As with `Vec::drain`, the
+/// whole range of rows is removed when the iterator is dropped, even if it
+/// was not fully consumed.
+pub struct DrainRows<'a, T> {
+    drain: Drain<'a, T>,
+    num_cols: usize,
+    remaining: usize,
+    /// The row count of the array, which holds only the rows before the
+    /// range until the iterator is dropped, so that leaking the iterator
+    /// leaves the shape consistent with the buffer.
+    num_rows: &'a mut usize,
+    /// The number of rows kept after the range.
+    tail_rows: usize,
+}
+
+impl<T> Drop for DrainRows<'_, T> {
+    fn drop(&mut self) {
+        // Runs before `drain` is dropped and moves the tail back.
+        *self.num_rows += self.tail_rows;
+    }
+}
+
+impl<T> Iterator for DrainRows<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.drain.by_ref().take(self.num_cols).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for DrainRows<'_, T> {
+    fn next_back(&mut self) -> Option<Vec<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let mut row: Vec<T> = self.drain.by_ref().rev().take(self.num_cols).collect();
+        row.reverse();
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for DrainRows<'_, T> {}
+
+impl<T> TwoDimensionalVec<T> {
+    /// Removes the rows in `range` from the array, returning an iterator
+    /// which yields the removed rows as `Vec`s, mirroring `Vec::drain`.
+    ///
+    /// The rows are removed when the iterator is dropped, whether or not it
+    /// was consumed. If the iterator is leaked, as with `mem::forget`, the
+    /// rows from the start of the range onwards are lost.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end or if the
+    /// end is greater than `num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8], 4, 2).unwrap();
+    /// let drained: Vec<Vec<i32>> = x.drain_rows(1..3).collect();
+    /// assert_eq!(drained, [[3, 4], [5, 6]]);
+    /// assert_eq!(x.shape(), (2, 2));
+    /// assert_eq!(x.as_slice(), [1, 2, 7, 8]);
+    ///
+    /// // Leaking the iterator keeps the shape in step with the buffer.
+    /// std::mem::forget(x.drain_rows(1..2));
+    /// assert_eq!(x.shape(), (1, 2));
+    /// assert_eq!(x.as_slice(), [1, 2]);
+    /// ```
+    pub fn drain_rows<R>(&mut self, range: R) -> DrainRows<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflows usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflows usize"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.num_rows,
+        };
+        assert!(
+            start <= end && end <= self.num_rows,
+            "row range {}..{} out of bounds for array with {} rows",
+            start,
+            end,
+            self.num_rows
+        );
+        let tail_rows = self.num_rows - end;
+        self.num_rows = start;
+        DrainRows {
+            drain: self
+                .buffer
+                .drain(start * self.num_cols..end * self.num_cols),
+            num_cols: self.num_cols,
+            remaining: end - start,
+            num_rows: &mut self.num_rows,
+            tail_rows,
+        }
+    }
+}
+
+impl<T: Clone> TwoDimensionalArray<'_, T> {
+    /// Returns a new owned array consisting of the array repeated
+    /// `reps_rows` times vertically and `reps_cols` times horizontally,
+    /// like `numpy.tile`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tiled shape or its number of elements overflows
+    /// `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let tiled = x.tile(2, 3);
+    ///
+    /// assert_eq!(tiled.shape(), (4, 6));
+    /// assert_eq!(
+    ///     tiled.as_slice(),
+    ///     [
+    ///         1, 2, 1, 2, 1, 2,
+    ///         3, 4, 3, 4, 3, 4,
+    ///         1, 2, 1, 2, 1, 2,
+    ///         3, 4, 3, 4, 3, 4,
+    ///     ]
+    /// );
+    /// ```
+    pub fn tile(&self, reps_rows: usize, reps_cols: usize) -> TwoDimensionalVec<T> {
+        let num_rows = self
+            .num_rows
+            .checked_mul(reps_rows)
+            .expect("array length overflows usize");
+        let num_cols = self
+            .num_cols
+            .checked_mul(reps_cols)
+            .expect("array length overflows usize");
+        let len = checked_len(num_rows, num_cols).expect("array length overflows usize");
+        let mut buffer = Vec::with_capacity(len);
+        for _ in 0..reps_rows {
+            for row_idx in 0..self.num_rows {
+                let row = &self.buffer[row_idx * self.num_cols..(row_idx + 1) * self.num_cols];
+                for _ in 0..reps_cols {
+                    buffer.extend_from_slice(row);
+                }
+            }
+        }
+        TwoDimensionalVec {
+            buffer,
+            num_rows,
+            num_cols,
+        }
+    }
+}
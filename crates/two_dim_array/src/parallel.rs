@@ -0,0 +1,160 @@
+use rayon::iter::{
+    Either, IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelIterator,
+};
+use rayon::slice::{Iter, IterMut, ParallelSliceMut};
+
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+/// Returns a parallel iterator over the rows of `buffer`.
+fn par_rows<T: Sync>(
+    buffer: &[T],
+    num_rows: usize,
+    num_cols: usize,
+) -> impl IndexedParallelIterator<Item = &[T]> {
+    (0..num_rows)
+        .into_par_iter()
+        .map(move |row_idx| &buffer[row_idx * num_cols..(row_idx + 1) * num_cols])
+}
+
+/// Returns a parallel iterator over the mutable rows of `buffer`.
+fn par_rows_mut<T: Send>(
+    buffer: &mut [T],
+    num_rows: usize,
+    num_cols: usize,
+) -> impl IndexedParallelIterator<Item = &mut [T]> {
+    // `par_chunks_exact_mut` cannot split into empty rows, but rows without
+    // columns borrow nothing.
+    if num_cols == 0 {
+        Either::Right((0..num_rows).into_par_iter().map(|_| <&mut [T]>::default()))
+    } else {
+        Either::Left(buffer.par_chunks_exact_mut(num_cols))
+    }
+}
+
+/// Parallel iteration with `rayon`, available with the `rayon` feature.
+impl<'a, T: Sync> TwoDimensionalArrayRef<'a, T> {
+    /// Returns a parallel iterator over the rows of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    ///
+    /// let x = TwoDimensionalArrayRef::new(&[1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+    /// let sums: Vec<i32> = x.par_rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 7, 11]);
+    /// ```
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &'a [T]> + use<'a, T> {
+        par_rows(self.buffer, self.num_rows, self.num_cols)
+    }
+
+    /// Returns a parallel iterator over the elements of the array in
+    /// row-major order.
+    pub fn par_iter(&self) -> Iter<'a, T> {
+        self.buffer.par_iter()
+    }
+}
+
+/// Parallel iteration with `rayon`, available with the `rayon` feature.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns a parallel iterator over the rows of the array. See
+    /// `TwoDimensionalArrayRef::par_rows`.
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]>
+    where
+        T: Sync,
+    {
+        par_rows(self.buffer, self.num_rows, self.num_cols)
+    }
+
+    /// Returns a parallel iterator over the mutable rows of the array.
+    ///
+    /// The rows are disjoint, so each can be processed on a separate thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// let mut a = [3, 1, 2, 6, 5, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.par_rows_mut().for_each(|row| row.sort());
+    /// assert_eq!(a, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]>
+    where
+        T: Send,
+    {
+        par_rows_mut(self.buffer, self.num_rows, self.num_cols)
+    }
+
+    /// Returns a parallel iterator over the elements of the array in
+    /// row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// let mut a = [1, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// assert_eq!(x.par_iter().max(), Some(&4));
+    /// ```
+    pub fn par_iter(&self) -> Iter<'_, T>
+    where
+        T: Sync,
+    {
+        self.buffer.par_iter()
+    }
+
+    /// Returns a parallel iterator over mutable references to the elements
+    /// of the array in row-major order.
+    pub fn par_iter_mut(&mut self) -> IterMut<'_, T>
+    where
+        T: Send,
+    {
+        self.buffer.par_iter_mut()
+    }
+}
+
+/// Parallel iteration with `rayon`, available with the `rayon` feature.
+impl<T> TwoDimensionalVec<T> {
+    /// Returns a parallel iterator over the rows of the array. See
+    /// `TwoDimensionalArrayRef::par_rows`.
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]>
+    where
+        T: Sync,
+    {
+        par_rows(&self.buffer, self.num_rows, self.num_cols)
+    }
+
+    /// Returns a parallel iterator over the mutable rows of the array. See
+    /// `TwoDimensionalArray::par_rows_mut`.
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]>
+    where
+        T: Send,
+    {
+        par_rows_mut(&mut self.buffer, self.num_rows, self.num_cols)
+    }
+
+    /// Returns a parallel iterator over the elements of the array in
+    /// row-major order.
+    pub fn par_iter(&self) -> Iter<'_, T>
+    where
+        T: Sync,
+    {
+        self.buffer.par_iter()
+    }
+
+    /// Returns a parallel iterator over mutable references to the elements
+    /// of the array in row-major order.
+    pub fn par_iter_mut(&mut self) -> IterMut<'_, T>
+    where
+        T: Send,
+    {
+        self.buffer.par_iter_mut()
+    }
+}
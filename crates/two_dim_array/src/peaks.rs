@@ -0,0 +1,103 @@
+use crate::TwoDimensionalArray;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl<T: PartialOrd> TwoDimensionalArray<'_, T> {
+    /// Returns the positions of the strict local maxima of the array which
+    /// are at least `threshold`, sorted from the largest value down.
+    ///
+    /// An element is a local maximum when it is greater than every other
+    /// element within `min_distance` rows and columns of it, so any two
+    /// peaks are more than `min_distance` apart along some axis. Plateaus
+    /// therefore have no peak. Peaks of equal value keep row-major order,
+    /// and elements which are incomparable, like `NaN`, are never peaks.
+    /// Peaks whose values are incomparable with each other, which is only
+    /// possible when `T` is not totally ordered, come in an unspecified
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     0.0, 0.0, 0.0, 0.0, 0.0,
+    ///     0.0, 3.0, 0.0, 0.0, 0.0,
+    ///     0.0, 0.0, 0.0, 2.0, 0.0,
+    ///     0.0, 0.0, 0.0, 0.0, 0.5,
+    /// ];
+    /// let response = TwoDimensionalArray::new(&mut a, 4, 5).unwrap();
+    ///
+    /// // The 0.5 is next to the 2.0, so it is not a peak.
+    /// assert_eq!(response.find_peaks(1, 0.0), [(1, 1), (2, 3)]);
+    /// assert_eq!(response.find_peaks(1, 2.5), [(1, 1)]);
+    /// // The 2.0 is two columns from the 3.0.
+    /// assert_eq!(response.find_peaks(2, 0.0), [(1, 1)]);
+    /// // A distance covering the whole array keeps only the maximum.
+    /// assert_eq!(response.find_peaks(usize::MAX, 0.0), [(1, 1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn find_peaks(&self, min_distance: usize, threshold: T) -> Vec<(usize, usize)> {
+        let (num_rows, num_cols) = self.shape();
+        let at = |row: usize, col: usize| &self.buffer[row * num_cols + col];
+        let mut peaks = Vec::new();
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                let value = at(row, col);
+                let rows = row.saturating_sub(min_distance)
+                    ..row
+                        .saturating_add(min_distance)
+                        .saturating_add(1)
+                        .min(num_rows);
+                let cols = col.saturating_sub(min_distance)
+                    ..col
+                        .saturating_add(min_distance)
+                        .saturating_add(1)
+                        .min(num_cols);
+                let is_peak = *value >= threshold
+                    && rows.clone().all(|other_row| {
+                        cols.clone().all(|other_col| {
+                            (other_row, other_col) == (row, col) || value > at(other_row, other_col)
+                        })
+                    });
+                if is_peak {
+                    peaks.push((row, col));
+                }
+            }
+        }
+        sort_descending(&mut peaks, |&(r0, c0), &(r1, c1)| at(r0, c0) > at(r1, c1));
+        peaks
+    }
+}
+
+/// Stably sorts `items` so that greater items come first, where `greater`
+/// need only be a partial order.
+///
+/// `slice::sort_by` requires a total order and may panic without one, so
+/// this is a bottom-up merge sort which only moves an item ahead of
+/// another when it is strictly greater. Equal items keep their order.
+#[cfg(feature = "alloc")]
+fn sort_descending<P: Copy>(items: &mut Vec<P>, greater: impl Fn(&P, &P) -> bool) {
+    let len = items.len();
+    let mut merged = items.clone();
+    let mut width = 1;
+    while width < len {
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut left, mut right) = (start, mid);
+            for slot in &mut merged[start..end] {
+                let take_right =
+                    right < end && (left == mid || greater(&items[right], &items[left]));
+                if take_right {
+                    *slot = items[right];
+                    right += 1;
+                } else {
+                    *slot = items[left];
+                    left += 1;
+                }
+            }
+        }
+        core::mem::swap(items, &mut merged);
+        width *= 2;
+    }
+}
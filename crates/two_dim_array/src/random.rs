@@ -0,0 +1,192 @@
+use core::ops::Range;
+
+use rand::Rng;
+use rand::distr::uniform::{SampleUniform, Uniform};
+use rand::distr::{Distribution, StandardUniform};
+use rand_distr::num_traits::Float;
+use rand_distr::{Normal, NormalError, StandardNormal};
+
+use crate::error::checked_len;
+use crate::{TwoDimensionalArray, TwoDimensionalVec};
+
+/// Random constructors, available with the `rand` feature.
+impl<T> TwoDimensionalVec<T> {
+    /// Construct a `TwoDimensionalVec` with every element drawn uniformly
+    /// from `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or if `num_rows * num_cols` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use two_dim_array::TwoDimensionalVec;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let x = TwoDimensionalVec::random_uniform(3, 4, 0..10, &mut rng);
+    /// assert_eq!(x.shape(), (3, 4));
+    /// assert!(x.as_slice().iter().all(|elem| (0..10).contains(elem)));
+    /// ```
+    pub fn random_uniform<R>(num_rows: usize, num_cols: usize, range: Range<T>, rng: &mut R) -> Self
+    where
+        T: SampleUniform,
+        R: Rng + ?Sized,
+    {
+        let dist = Uniform::new(range.start, range.end).expect("range must not be empty");
+        Self::from_rng(num_rows, num_cols, rng, dist)
+    }
+
+    /// Construct a `TwoDimensionalVec` with every element drawn from a
+    /// normal distribution with the given mean and standard deviation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NormalError` when `std_dev` is not finite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows * num_cols` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use two_dim_array::TwoDimensionalVec;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let x = TwoDimensionalVec::random_normal(100, 100, 5.0, 0.1, &mut rng).unwrap();
+    /// let mean = x.as_slice().iter().sum::<f64>() / x.len() as f64;
+    /// assert!((mean - 5.0).abs() < 0.01);
+    /// ```
+    pub fn random_normal<R>(
+        num_rows: usize,
+        num_cols: usize,
+        mean: T,
+        std_dev: T,
+        rng: &mut R,
+    ) -> Result<Self, NormalError>
+    where
+        T: Float,
+        StandardNormal: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        let dist = Normal::new(mean, std_dev)?;
+        Ok(Self::from_rng(num_rows, num_cols, rng, dist))
+    }
+
+    /// Overwrites every element of the array with a sample from `dist`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::distr::Bernoulli;
+    /// use rand::rngs::SmallRng;
+    /// use two_dim_array::TwoDimensionalVec;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let mut x = TwoDimensionalVec::from_vec(vec![true; 6], 2, 3).unwrap();
+    /// x.random_fill_with(&mut rng, Bernoulli::new(0.0).unwrap());
+    /// assert_eq!(x.as_slice(), [false; 6]);
+    /// ```
+    pub fn random_fill_with<R, D>(&mut self, rng: &mut R, dist: D)
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        self.as_view_mut().random_fill_with(rng, dist);
+    }
+
+    /// Overwrites every element of the array with a sample from the
+    /// `StandardUniform` distribution. See
+    /// `TwoDimensionalArray::fill_random`.
+    pub fn fill_random<R>(&mut self, rng: &mut R)
+    where
+        StandardUniform: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        self.as_view_mut().fill_random(rng);
+    }
+
+    /// Construct a `TwoDimensionalVec` with every element drawn from
+    /// `dist`, in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows * num_cols` overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::distr::Uniform;
+    /// use rand::rngs::SmallRng;
+    /// use two_dim_array::TwoDimensionalVec;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let dist = Uniform::new_inclusive(-1.0, 1.0).unwrap();
+    /// let x = TwoDimensionalVec::from_rng(4, 5, &mut rng, dist);
+    /// assert_eq!(x.shape(), (4, 5));
+    /// assert!(x.as_slice().iter().all(|elem: &f64| elem.abs() <= 1.0));
+    ///
+    /// // The same seed gives the same array.
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// assert_eq!(TwoDimensionalVec::from_rng(4, 5, &mut rng, dist), x);
+    /// ```
+    pub fn from_rng<R, D>(num_rows: usize, num_cols: usize, rng: &mut R, dist: D) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        let len = checked_len(num_rows, num_cols).expect("array length overflows usize");
+        Self {
+            buffer: dist.sample_iter(rng).take(len).collect(),
+            num_rows,
+            num_cols,
+        }
+    }
+}
+
+/// Random filling, available with the `rand` feature.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Overwrites every element of the array with a sample from `dist`, in
+    /// row-major order.
+    pub fn random_fill_with<R, D>(&mut self, rng: &mut R, dist: D)
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        for (elem, sample) in self.buffer.iter_mut().zip(dist.sample_iter(rng)) {
+            *elem = sample;
+        }
+    }
+
+    /// Overwrites every element of the array with a sample from the
+    /// `StandardUniform` distribution, which is uniform over all values of
+    /// the integer types and over `[0, 1)` for the floating point types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use two_dim_array::TwoDimensionalArray;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let mut a = [2.0f32; 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.fill_random(&mut rng);
+    /// assert!(a.iter().all(|elem| (0.0..1.0).contains(elem)));
+    /// ```
+    pub fn fill_random<R>(&mut self, rng: &mut R)
+    where
+        StandardUniform: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        self.random_fill_with(rng, StandardUniform);
+    }
+}
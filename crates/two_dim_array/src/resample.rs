@@ -0,0 +1,140 @@
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+use crate::TwoDimensionalArray;
+#[cfg(feature = "std")]
+use crate::TwoDimensionalVec;
+use crate::num::Float;
+
+/// How values are read at positions between elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Take the value of the nearest element.
+    Nearest,
+    /// Interpolate linearly between the four surrounding elements.
+    Bilinear,
+}
+
+impl<T: Float> TwoDimensionalArray<'_, T> {
+    /// Returns the value at the fractional position `(row, col)`, where
+    /// element `(r, c)` sits at `(r, c)`, or `None` if the position is
+    /// outside the array.
+    ///
+    /// Every element covers the unit square centred on it, so positions up
+    /// to half a cell past the outer elements are inside. There `Bilinear`
+    /// reads the edge element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Filter, TwoDimensionalArray};
+    /// let mut a = [0.0, 1.0, 2.0, 3.0];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    ///
+    /// assert_eq!(x.sample(0.5, 0.5, Filter::Bilinear), Some(1.5));
+    /// assert_eq!(x.sample(0.4, 0.6, Filter::Nearest), Some(1.0));
+    /// assert_eq!(x.sample(-0.25, 0.0, Filter::Bilinear), Some(0.0));
+    /// assert_eq!(x.sample(2.0, 0.0, Filter::Nearest), None);
+    /// ```
+    pub fn sample(&self, row: T, col: T, filter: Filter) -> Option<T> {
+        let (row, col) = (row.to_f64(), col.to_f64());
+        let inside = |pos: f64, len: usize| -0.5 <= pos && pos < len as f64 - 0.5;
+        if !inside(row, self.num_rows) || !inside(col, self.num_cols) {
+            return None;
+        }
+        let at = |row: usize, col: usize| self.buffer[row * self.num_cols + col];
+        match filter {
+            // The positions are non-negative, so truncating with `as` rounds
+            // down like `floor`, which needs `std`.
+            Filter::Nearest => Some(at((row + 0.5) as usize, (col + 0.5) as usize)),
+            Filter::Bilinear => {
+                // The lower neighbour and the weight of the upper one.
+                let split = |pos: f64, len: usize| {
+                    let pos = pos.clamp(0.0, (len - 1) as f64);
+                    let low = (pos as usize).min(len.saturating_sub(2));
+                    (low, pos - low as f64)
+                };
+                let (r0, dr) = split(row, self.num_rows);
+                let (c0, dc) = split(col, self.num_cols);
+                let (r1, c1) = (
+                    (r0 + 1).min(self.num_rows - 1),
+                    (c0 + 1).min(self.num_cols - 1),
+                );
+                let (dr, dc) = (T::from_f64(dr), T::from_f64(dc));
+                let lerp = |a: T, b: T, t: T| a + (b - a) * t;
+                let top = lerp(at(r0, c0), at(r0, c1), dc);
+                let bottom = lerp(at(r1, c0), at(r1, c1), dc);
+                Some(lerp(top, bottom, dr))
+            }
+        }
+    }
+
+    /// Returns the array rotated counter-clockwise by `radians` about its
+    /// centre, as displayed with row 0 at the top.
+    ///
+    /// The result is just large enough to hold the rotated array, so its
+    /// shape swaps for quarter turns. Each of its elements is read from
+    /// the array with `sample` and `filter`, and those falling outside the
+    /// array are set to `fill`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{Filter, TwoDimensionalArray};
+    /// let mut a = [
+    ///     1.0, 2.0, 3.0,
+    ///     4.0, 5.0, 6.0,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// let quarter = x.rotated_by(std::f64::consts::FRAC_PI_2, Filter::Nearest, 0.0);
+    /// assert_eq!(quarter.shape(), (3, 2));
+    /// assert_eq!(quarter.as_slice(), [3.0, 6.0, 2.0, 5.0, 1.0, 4.0]);
+    ///
+    /// // The corners of a square turned by 45 degrees are filled.
+    /// let mut b = [1.0; 16];
+    /// let square = TwoDimensionalArray::new(&mut b, 4, 4).unwrap();
+    /// let diamond = square.rotated_by(std::f64::consts::FRAC_PI_4, Filter::Bilinear, 0.0);
+    /// assert_eq!(diamond.shape(), (6, 6));
+    /// assert_eq!(diamond.as_slice()[0], 0.0);
+    /// assert_eq!(diamond.as_slice()[2 * 6 + 2], 1.0);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rotated_by(&self, radians: T, filter: Filter, fill: T) -> TwoDimensionalVec<T> {
+        let (sin, cos) = radians.to_f64().sin_cos();
+        // Shave off rounding error so exact quarter turns keep their size.
+        let extent = |along: usize, across: usize| {
+            (along as f64 * cos.abs() + across as f64 * sin.abs() - 1e-9).ceil() as usize
+        };
+        let (num_rows, num_cols) = if self.is_empty() {
+            (0, 0)
+        } else {
+            (
+                extent(self.num_rows, self.num_cols),
+                extent(self.num_cols, self.num_rows),
+            )
+        };
+        let centre = |len: usize| (len as f64 - 1.0) / 2.0;
+        let (src_row, src_col) = (centre(self.num_rows), centre(self.num_cols));
+        let (dst_row, dst_col) = (centre(num_rows), centre(num_cols));
+
+        let mut buffer = Vec::with_capacity(num_rows * num_cols);
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                let (y, x) = (row as f64 - dst_row, col as f64 - dst_col);
+                // Undo the rotation to find where the element came from.
+                let source_row = src_row + x * sin + y * cos;
+                let source_col = src_col + x * cos - y * sin;
+                buffer.push(
+                    self.sample(T::from_f64(source_row), T::from_f64(source_col), filter)
+                        .unwrap_or(fill),
+                );
+            }
+        }
+        TwoDimensionalVec {
+            buffer,
+            num_rows,
+            num_cols,
+        }
+    }
+}
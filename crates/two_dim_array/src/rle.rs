@@ -0,0 +1,236 @@
+//! Run-length encoding of arrays, as an iterator of runs and as a compact
+//! binary format.
+//!
+//! Runs are taken over the elements in row-major order, so a run may
+//! continue from the end of one row onto the start of the next.
+//!
+//! # Binary format
+//!
+//! All integers are little-endian.
+//!
+//! - The magic bytes `TDRL` and a format version byte, currently `1`.
+//! - The element width in bytes as a `u8`.
+//! - `num_rows`, `num_cols` and the number of runs, each as a `u64`.
+//! - For each run, the element encoded with `FixedWidth` followed by the
+//!   run length as an unsigned LEB128 varint.
+//!
+//! # Example
+//! ```
+//! use two_dim_array::TwoDimensionalArray;
+//! use two_dim_array::TwoDimensionalVec;
+//!
+//! let mut a = [0u8; 1000];
+//! a[500] = 7;
+//! let x = TwoDimensionalArray::new(&mut a, 25, 40).unwrap();
+//!
+//! let mut encoded = Vec::new();
+//! x.write_rle(&mut encoded).unwrap();
+//! assert!(encoded.len() < 50);
+//!
+//! let decoded = TwoDimensionalVec::<u8>::read_rle(&mut encoded.as_slice()).unwrap();
+//! assert_eq!(decoded.shape(), (25, 40));
+//! assert_eq!(decoded.as_slice(), x.as_slice());
+//! ```
+
+use std::io::{self, Read, Write};
+
+use crate::bytes::FixedWidth;
+use crate::error::checked_len;
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalVec};
+
+const MAGIC: &[u8; 4] = b"TDRL";
+const VERSION: u8 = 1;
+
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the runs of equal elements of the array in
+    /// row-major order, as `(value, run_length)` pairs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 1, 1, 2, 2, 1];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// let runs: Vec<_> = x.runs().collect();
+    /// assert_eq!(runs, [(&1, 3), (&2, 2), (&1, 1)]);
+    /// ```
+    pub fn runs(&self) -> impl Iterator<Item = (&T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut rest = &*self.buffer;
+        std::iter::from_fn(move || {
+            let first = rest.first()?;
+            let len = rest.iter().take_while(|&elem| elem == first).count();
+            rest = &rest[len..];
+            Some((first, len))
+        })
+    }
+
+    /// Writes the array to `writer` in the run-length encoded binary format
+    /// described in the `rle` module.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_rle<W: Write>(&self, mut writer: W) -> io::Result<()>
+    where
+        T: PartialEq + FixedWidth,
+    {
+        let width = u8::try_from(T::WIDTH)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "element too wide"))?;
+        let num_runs = self.runs().count();
+        let mut out = Vec::with_capacity(29 + num_runs * (T::WIDTH + 2));
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.push(width);
+        for value in [self.num_rows, self.num_cols, num_runs] {
+            out.extend_from_slice(&(value as u64).to_le_bytes());
+        }
+        let mut elem_buf = vec![0; T::WIDTH];
+        for (value, len) in self.runs() {
+            value.write_le(&mut elem_buf);
+            out.extend_from_slice(&elem_buf);
+            write_varint(&mut out, len as u64);
+        }
+        writer.write_all(&out)
+    }
+}
+
+impl<T: Clone> TwoDimensionalVec<T> {
+    /// Construct a `TwoDimensionalVec` by expanding `(value, run_length)`
+    /// runs in row-major order, the inverse of `runs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::InvalidShape` when the runs do not add up to
+    /// `num_rows * num_cols` elements and `ShapeError::Overflow` when that
+    /// product overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let x = TwoDimensionalVec::from_runs(2, 3, [(1, 3), (2, 2), (1, 1)]).unwrap();
+    /// assert_eq!(x.as_slice(), [1, 1, 1, 2, 2, 1]);
+    /// assert!(TwoDimensionalVec::from_runs(2, 3, [(1, 5)]).is_err());
+    /// ```
+    pub fn from_runs<I>(num_rows: usize, num_cols: usize, runs: I) -> Result<Self, ShapeError>
+    where
+        I: IntoIterator<Item = (T, usize)>,
+    {
+        let len = checked_len(num_rows, num_cols)?;
+        // Grown run by run rather than reserved up front, so that a shape
+        // the runs do not fill cannot allocate a huge buffer.
+        let mut buffer = Vec::new();
+        for (value, run_len) in runs {
+            if run_len > len - buffer.len() {
+                return Err(ShapeError::InvalidShape {
+                    buffer_len: buffer.len().saturating_add(run_len),
+                    num_rows,
+                    num_cols,
+                });
+            }
+            buffer.resize(buffer.len() + run_len, value);
+        }
+        Self::from_vec(buffer, num_rows, num_cols)
+    }
+
+    /// Reads an array written by `TwoDimensionalArray::write_rle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `io::ErrorKind::InvalidData` for malformed input, including
+    /// runs which do not add up to the stored shape, and any error produced
+    /// by `reader`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    /// use two_dim_array::TwoDimensionalVec;
+    ///
+    /// // A header claiming a 2^28 x 2^28 array of bytes but holding no runs.
+    /// let mut input = b"TDRL\x01\x01".to_vec();
+    /// for value in [1u64 << 28, 1 << 28, 0] {
+    ///     input.extend_from_slice(&value.to_le_bytes());
+    /// }
+    /// let err = TwoDimensionalVec::<u8>::read_rle(input.as_slice()).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidData);
+    /// ```
+    pub fn read_rle<R: Read>(mut reader: R) -> io::Result<Self>
+    where
+        T: FixedWidth,
+    {
+        let mut header = [0; 6];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(invalid_data("missing run-length encoding magic bytes"));
+        }
+        if header[4] != VERSION {
+            return Err(invalid_data("unsupported run-length encoding version"));
+        }
+        if usize::from(header[5]) != T::WIDTH {
+            return Err(invalid_data(
+                "element width does not match the element type",
+            ));
+        }
+        let num_rows = read_u64_as_usize(&mut reader)?;
+        let num_cols = read_u64_as_usize(&mut reader)?;
+        let num_runs = read_u64_as_usize(&mut reader)?;
+        let len = checked_len(num_rows, num_cols).map_err(|err| invalid_data(err.to_string()))?;
+
+        let mut runs = Vec::new();
+        let mut total = 0usize;
+        let mut elem_buf = vec![0; T::WIDTH];
+        for _ in 0..num_runs {
+            reader.read_exact(&mut elem_buf)?;
+            let value = T::read_le(&elem_buf).ok_or_else(|| invalid_data("invalid element"))?;
+            let run_len = usize::try_from(read_varint(&mut reader)?)
+                .map_err(|_| invalid_data("run length overflows usize"))?;
+            total = total
+                .checked_add(run_len)
+                .filter(|&total| total <= len)
+                .ok_or_else(|| invalid_data("runs exceed the stored shape"))?;
+            runs.push((value, run_len));
+        }
+        if total != len {
+            return Err(invalid_data("runs do not add up to the stored shape"));
+        }
+        Self::from_runs(num_rows, num_cols, runs).map_err(|err| invalid_data(err.to_string()))
+    }
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+fn read_u64_as_usize<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+    usize::try_from(u64::from_le_bytes(buf)).map_err(|_| invalid_data("value overflows usize"))
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let mut byte = [0];
+        reader.read_exact(&mut byte)?;
+        value |= u64::from(byte[0] & 0x7f)
+            .checked_shl(shift)
+            .filter(|shifted| shifted >> shift == u64::from(byte[0] & 0x7f))
+            .ok_or_else(|| invalid_data("varint overflows u64"))?;
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(invalid_data("varint overflows u64"))
+}
@@ -0,0 +1,341 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "alloc")]
+use crate::ScatterError;
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::error::check_shape;
+#[cfg(feature = "alloc")]
+use crate::error::checked_len;
+use crate::{ShapeError, TwoDimensionalArray};
+
+/// How `scatter_rows` treats a row index which appears more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Reject the scatter with `ScatterError::DuplicateIndex`, leaving the
+    /// destination unmodified.
+    Error,
+    /// Write every source row in order, so the last row given for an
+    /// index wins.
+    LastWins,
+}
+
+/// Operations treating each row of the array as a record.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns the indices of the distinct rows of the array, in order of
+    /// first occurrence.
+    ///
+    /// See `unique_rows` to copy the distinct rows into a new array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 1, 2, 5, 6, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 5, 2).unwrap();
+    /// assert_eq!(x.unique_row_indices(), [0, 1, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_row_indices(&self) -> Vec<usize>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.num_rows);
+        (0..self.num_rows)
+            .filter(|&row_idx| seen.insert(self.get_panic(row_idx, ..)))
+            .collect()
+    }
+
+    /// Returns a new owned array holding the distinct rows of the array, in
+    /// order of first occurrence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the new array overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 1, 2, 5, 6, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 5, 2).unwrap();
+    /// let unique = x.unique_rows();
+    /// assert_eq!(unique.shape(), (3, 2));
+    /// assert_eq!(unique.as_slice(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn unique_rows(&self) -> TwoDimensionalVec<T>
+    where
+        T: Hash + Eq + Clone,
+    {
+        let indices = self.unique_row_indices();
+        let len = checked_len(indices.len(), self.num_cols).expect("array length overflows usize");
+        let mut buffer = Vec::with_capacity(len);
+        for &row_idx in &indices {
+            buffer.extend_from_slice(self.get_panic(row_idx, ..));
+        }
+        TwoDimensionalVec {
+            buffer,
+            num_rows: indices.len(),
+            num_cols: self.num_cols,
+        }
+    }
+
+    /// Groups the rows of the array by the key `key_fn` computes for each
+    /// row, returning the indices of the rows in each group in ascending
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// // Rows of (category, value).
+    /// let mut a = [0, 10, 1, 20, 0, 30, 2, 40, 1, 50];
+    /// let x = TwoDimensionalArray::new(&mut a, 5, 2).unwrap();
+    ///
+    /// let groups = x.group_rows_by(|row| row[0]);
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups[&0], [0, 2]);
+    /// assert_eq!(groups[&1], [1, 4]);
+    /// assert_eq!(groups[&2], [3]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn group_rows_by<K, F>(&self, mut key_fn: F) -> HashMap<K, Vec<usize>>
+    where
+        K: Hash + Eq,
+        F: FnMut(&[T]) -> K,
+    {
+        let mut groups: HashMap<K, Vec<usize>> = HashMap::new();
+        for row_idx in 0..self.num_rows {
+            groups
+                .entry(key_fn(self.get_panic(row_idx, ..)))
+                .or_default()
+                .push(row_idx);
+        }
+        groups
+    }
+
+    /// Returns a new owned array holding the rows named by `indices`, in
+    /// order. Indices may repeat.
+    ///
+    /// See `gather_rows_into` to write into an existing array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds or if the length of the new
+    /// array overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// let batch = x.gather_rows(&[2, 0, 2]);
+    /// assert_eq!(batch.shape(), (3, 2));
+    /// assert_eq!(batch.as_slice(), [5, 6, 1, 2, 5, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn gather_rows(&self, indices: &[usize]) -> TwoDimensionalVec<T>
+    where
+        T: Clone,
+    {
+        let len = checked_len(indices.len(), self.num_cols).expect("array length overflows usize");
+        let mut buffer = Vec::with_capacity(len);
+        for &row_idx in indices {
+            buffer.extend_from_slice(self.row_or_panic(row_idx));
+        }
+        TwoDimensionalVec {
+            buffer,
+            num_rows: indices.len(),
+            num_cols: self.num_cols,
+        }
+    }
+
+    /// Copies the rows named by `indices` into consecutive rows of `out`, so
+    /// row `i` of `out` becomes row `indices[i]` of the array. Indices may
+    /// repeat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::ShapeMismatch` unless `out` has shape
+    /// `(indices.len(), num_cols)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut b = [0; 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// let mut out = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    /// x.gather_rows_into(&[1, 1], &mut out).unwrap();
+    /// assert_eq!(b, [3, 4, 3, 4]);
+    /// ```
+    pub fn gather_rows_into(
+        &self,
+        indices: &[usize],
+        out: &mut TwoDimensionalArray<'_, T>,
+    ) -> Result<(), ShapeError>
+    where
+        T: Clone,
+    {
+        check_shape((indices.len(), self.num_cols), out.shape())?;
+        for (dst_idx, &row_idx) in indices.iter().enumerate() {
+            out.get_mut_panic(dst_idx, ..)
+                .clone_from_slice(self.row_or_panic(row_idx));
+        }
+        Ok(())
+    }
+
+    /// Copies row `i` of `src` into row `indices[i]` of the array, the
+    /// inverse of `gather_rows`.
+    ///
+    /// `policy` decides what happens when an index appears more than once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScatterError::Shape` unless `src` has shape
+    /// `(indices.len(), num_cols)` and `ScatterError::DuplicateIndex` for a
+    /// repeated index under `DuplicatePolicy::Error`. The array is left
+    /// unmodified on error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{DuplicatePolicy, ScatterError, TwoDimensionalArray};
+    /// let mut a = [0; 6];
+    /// let mut b = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// let src = TwoDimensionalArray::new(&mut b, 2, 2).unwrap();
+    ///
+    /// assert!(matches!(
+    ///     x.scatter_rows(&[2, 2], &src, DuplicatePolicy::Error),
+    ///     Err(ScatterError::DuplicateIndex(2))
+    /// ));
+    /// x.scatter_rows(&[2, 0], &src, DuplicatePolicy::Error).unwrap();
+    /// assert_eq!(x.as_slice(), [3, 4, 0, 0, 1, 2]);
+    ///
+    /// x.scatter_rows(&[1, 1], &src, DuplicatePolicy::LastWins).unwrap();
+    /// assert_eq!(x.as_slice(), [3, 4, 3, 4, 1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn scatter_rows(
+        &mut self,
+        indices: &[usize],
+        src: &TwoDimensionalArray<'_, T>,
+        policy: DuplicatePolicy,
+    ) -> Result<(), ScatterError>
+    where
+        T: Clone,
+    {
+        check_shape((indices.len(), self.num_cols), src.shape())?;
+        let mut seen = vec![false; self.num_rows];
+        for &row_idx in indices {
+            self.assert_row_in_bounds(row_idx);
+            if core::mem::replace(&mut seen[row_idx], true) && policy == DuplicatePolicy::Error {
+                return Err(ScatterError::DuplicateIndex(row_idx));
+            }
+        }
+        for (src_idx, &row_idx) in indices.iter().enumerate() {
+            self.get_mut_panic(row_idx, ..)
+                .clone_from_slice(src.get_panic(src_idx, ..));
+        }
+        Ok(())
+    }
+
+    /// Returns the first row at which `value` could be inserted into column
+    /// `col_idx` keeping it sorted, for arrays whose rows are sorted by that
+    /// column. Like `numpy.searchsorted` with `side="left"`.
+    ///
+    /// If the column is not sorted the result is unspecified but in bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_idx` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// // Rows of (key, value), sorted by key.
+    /// let mut a = [1, 10, 3, 30, 3, 31, 7, 70];
+    /// let x = TwoDimensionalArray::new(&mut a, 4, 2).unwrap();
+    /// assert_eq!(x.searchsorted(0, &3), 1);
+    /// assert_eq!(x.searchsorted(0, &4), 3);
+    /// assert_eq!(x.searchsorted(0, &9), 4);
+    /// ```
+    pub fn searchsorted(&self, col_idx: usize, value: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.searchsorted_by(col_idx, |elem| elem.cmp(value))
+    }
+
+    /// Binary searches column `col_idx` with a comparator, returning the
+    /// first row for which `compare` does not return `Ordering::Less`. See
+    /// `searchsorted`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_idx` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0.5f64, 1.0, 1.5, 2.0, 2.5, 3.0];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// let row = x.searchsorted_by(1, |elem| elem.total_cmp(&2.5));
+    /// assert_eq!(row, 2);
+    /// ```
+    pub fn searchsorted_by<F>(&self, col_idx: usize, mut compare: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        assert!(
+            col_idx < self.num_cols,
+            "column index {} out of bounds for array with {} columns",
+            col_idx,
+            self.num_cols
+        );
+        let (mut low, mut high) = (0, self.num_rows);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if compare(&self.buffer[mid * self.num_cols + col_idx]) == Ordering::Less {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    fn row_or_panic(&self, row_idx: usize) -> &[T] {
+        self.assert_row_in_bounds(row_idx);
+        self.get_panic(row_idx, ..)
+    }
+
+    fn assert_row_in_bounds(&self, row_idx: usize) {
+        assert!(
+            row_idx < self.num_rows,
+            "row index {} out of bounds for array with {} rows",
+            row_idx,
+            self.num_rows
+        );
+    }
+}
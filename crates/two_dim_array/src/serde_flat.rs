@@ -0,0 +1,85 @@
+//! A flat serde representation of `TwoDimensionalVec`, for use with
+//! `#[serde(with = "two_dim_array::serde_flat")]`. Enabled by the `serde`
+//! feature.
+//!
+//! The array is written as a struct of its shape and row-major buffer,
+//! `{"rows": 2, "cols": 3, "data": [...]}`, rather than the nested rows of
+//! the default representation. Binary formats such as bincode or CBOR then
+//! store the buffer as one sequence with no per-row framing, and arrays
+//! without rows keep their number of columns.
+//!
+//! # Example
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use two_dim_array::TwoDimensionalVec;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Grid {
+//!     #[serde(with = "two_dim_array::serde_flat")]
+//!     cells: TwoDimensionalVec<u8>,
+//! }
+//!
+//! let grid = Grid {
+//!     cells: TwoDimensionalVec::from_fn(2, 3, |row, col| (3 * row + col) as u8),
+//! };
+//! let json = serde_json::to_string(&grid).unwrap();
+//! assert_eq!(json, r#"{"cells":{"rows":2,"cols":3,"data":[0,1,2,3,4,5]}}"#);
+//!
+//! let back: Grid = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.cells.as_slice(), grid.cells.as_slice());
+//!
+//! let bad = r#"{"cells":{"rows":2,"cols":2,"data":[0,1,2]}}"#;
+//! assert!(serde_json::from_str::<Grid>(bad).is_err());
+//! ```
+
+use alloc::vec::Vec;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::TwoDimensionalVec;
+
+#[derive(serde::Serialize)]
+#[serde(rename = "TwoDimensionalVec")]
+struct FlatRef<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T],
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename = "TwoDimensionalVec")]
+struct Flat<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+/// Serialises `array` as its shape and flat buffer.
+pub fn serialize<T, S>(array: &TwoDimensionalVec<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    FlatRef {
+        rows: array.num_rows,
+        cols: array.num_cols,
+        data: &array.buffer,
+    }
+    .serialize(serializer)
+}
+
+/// Deserialises an array from its shape and flat buffer.
+///
+/// # Errors
+///
+/// Fails with the message of the `ShapeError` from
+/// `TwoDimensionalVec::from_vec` when the buffer length does not match the
+/// shape.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TwoDimensionalVec<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let Flat { rows, cols, data } = Flat::deserialize(deserializer)?;
+    TwoDimensionalVec::from_vec(data, rows, cols).map_err(de::Error::custom)
+}
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::Deserialize;
+use serde::de::{self, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{ShapeError, TwoDimensionalArray, TwoDimensionalArrayRef, TwoDimensionalVec};
+
+/// Serialises as a sequence of rows, each a sequence of elements, such as
+/// `[[1, 2], [3, 4]]` in JSON. Available with the `serde` feature.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+/// let mut a = [1, 2, 3, 4, 5, 6];
+/// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+/// assert_eq!(serde_json::to_string(&x).unwrap(), "[[1,2,3],[4,5,6]]");
+/// ```
+impl<T: Serialize> Serialize for TwoDimensionalArray<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_view().serialize(serializer)
+    }
+}
+
+/// Serialises as a sequence of rows. See `TwoDimensionalArray`.
+impl<T: Serialize> Serialize for TwoDimensionalArrayRef<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Indexing rather than `rows` keeps the empty rows of an array
+        // without columns.
+        serializer.collect_seq((0..self.num_rows).map(|row_idx| self.get_panic(row_idx, ..)))
+    }
+}
+
+/// Serialises as a sequence of rows. See `TwoDimensionalArray`.
+impl<T: Serialize> Serialize for TwoDimensionalVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_view().serialize(serializer)
+    }
+}
+
+/// Deserialises from a sequence of rows, as written by `Serialize`.
+/// Available with the `serde` feature.
+///
+/// Rows are read straight into the buffer. A row with a different number
+/// of elements to the first fails with a `ShapeError::LengthMismatch`
+/// message. An empty sequence gives a `0 x 0` array, so the number of
+/// columns of an array without rows does not round-trip; `serde_flat`
+/// preserves it.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::TwoDimensionalVec;
+/// let x: TwoDimensionalVec<i32> = serde_json::from_str("[[1, 2], [3, 4]]").unwrap();
+/// assert_eq!(x.shape(), (2, 2));
+/// assert_eq!(x.as_slice(), [1, 2, 3, 4]);
+///
+/// let no_cols = TwoDimensionalVec::<i32>::from_elem(0, 2, 0);
+/// let json = serde_json::to_string(&no_cols).unwrap();
+/// assert_eq!(json, "[[],[]]");
+/// let back: TwoDimensionalVec<i32> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back.shape(), (2, 0));
+///
+/// let ragged = serde_json::from_str::<TwoDimensionalVec<i32>>("[[1, 2], [3]]");
+/// assert!(ragged.is_err());
+/// ```
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for TwoDimensionalVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(RowsVisitor(PhantomData))
+    }
+}
+
+struct RowsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RowsVisitor<T> {
+    type Value = TwoDimensionalVec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of rows of equal length")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut array = TwoDimensionalVec::with_num_cols(0);
+        while let Some(found) = seq.next_element_seed(RowSeed(&mut array.buffer))? {
+            if array.num_rows == 0 {
+                array.num_cols = found;
+            } else if found != array.num_cols {
+                return Err(de::Error::custom(ShapeError::LengthMismatch {
+                    expected: array.num_cols,
+                    found,
+                }));
+            }
+            array.num_rows += 1;
+        }
+        Ok(array)
+    }
+}
+
+/// Appends the elements of one row to the buffer, producing their count.
+struct RowSeed<'a, T>(&'a mut Vec<T>);
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for RowSeed<'_, T> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RowSeed<'_, T> {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a row of elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<usize, A::Error> {
+        let old_len = self.0.len();
+        while let Some(elem) = seq.next_element()? {
+            self.0.push(elem);
+        }
+        Ok(self.0.len() - old_len)
+    }
+}
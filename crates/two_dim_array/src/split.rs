@@ -0,0 +1,264 @@
+use core::iter::FusedIterator;
+use core::mem;
+
+use crate::{TwoDimensionalArray, TwoDimensionalArrayRef};
+
+/// An iterator over consecutive bands of rows of an array as read-only
+/// views, created by `TwoDimensionalArray::row_chunks`.
+#[derive(Debug)]
+pub struct RowChunks<'a, T> {
+    rest: &'a [T],
+    num_rows: usize,
+    num_cols: usize,
+    chunk_rows: usize,
+}
+
+/// An iterator over consecutive bands of rows of an array as mutable
+/// views, created by `TwoDimensionalArray::row_chunks_mut`.
+#[derive(Debug)]
+pub struct RowChunksMut<'a, T> {
+    rest: &'a mut [T],
+    num_rows: usize,
+    num_cols: usize,
+    chunk_rows: usize,
+}
+
+impl<T> RowChunks<'_, T> {
+    /// The number of rows in the last band, which may be short.
+    fn last_rows(&self) -> usize {
+        match self.num_rows % self.chunk_rows {
+            0 => self.chunk_rows,
+            rem => rem,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RowChunks<'a, T> {
+    type Item = TwoDimensionalArrayRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_rows == 0 {
+            return None;
+        }
+        let num_rows = self.chunk_rows.min(self.num_rows);
+        let (chunk, rest) = self.rest.split_at(num_rows * self.num_cols);
+        self.rest = rest;
+        self.num_rows -= num_rows;
+        Some(TwoDimensionalArrayRef {
+            buffer: chunk,
+            num_rows,
+            num_cols: self.num_cols,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.num_rows.div_ceil(self.chunk_rows);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for RowChunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.num_rows == 0 {
+            return None;
+        }
+        let num_rows = self.last_rows();
+        self.num_rows -= num_rows;
+        let (rest, chunk) = self.rest.split_at(self.num_rows * self.num_cols);
+        self.rest = rest;
+        Some(TwoDimensionalArrayRef {
+            buffer: chunk,
+            num_rows,
+            num_cols: self.num_cols,
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for RowChunks<'_, T> {}
+
+impl<T> FusedIterator for RowChunks<'_, T> {}
+
+impl<T> RowChunksMut<'_, T> {
+    /// The number of rows in the last band, which may be short.
+    fn last_rows(&self) -> usize {
+        match self.num_rows % self.chunk_rows {
+            0 => self.chunk_rows,
+            rem => rem,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RowChunksMut<'a, T> {
+    type Item = TwoDimensionalArray<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.num_rows == 0 {
+            return None;
+        }
+        let num_rows = self.chunk_rows.min(self.num_rows);
+        let (chunk, rest) = mem::take(&mut self.rest).split_at_mut(num_rows * self.num_cols);
+        self.rest = rest;
+        self.num_rows -= num_rows;
+        Some(TwoDimensionalArray {
+            buffer: chunk,
+            num_rows,
+            num_cols: self.num_cols,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.num_rows.div_ceil(self.chunk_rows);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for RowChunksMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.num_rows == 0 {
+            return None;
+        }
+        let num_rows = self.last_rows();
+        self.num_rows -= num_rows;
+        let (rest, chunk) = mem::take(&mut self.rest).split_at_mut(self.num_rows * self.num_cols);
+        self.rest = rest;
+        Some(TwoDimensionalArray {
+            buffer: chunk,
+            num_rows,
+            num_cols: self.num_cols,
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for RowChunksMut<'_, T> {}
+
+impl<T> FusedIterator for RowChunksMut<'_, T> {}
+
+/// Splitting the array into views of contiguous bands of rows.
+impl<'a, T> TwoDimensionalArray<'a, T> {
+    /// Returns an iterator over consecutive bands of `chunk_rows` rows,
+    /// each as a read-only view, like `slice::chunks`. The last band has
+    /// fewer rows if `chunk_rows` does not divide `num_rows`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_rows` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let x = TwoDimensionalArray::new(&mut a, 5, 2).unwrap();
+    ///
+    /// let shapes: Vec<_> = x.row_chunks(2).map(|band| band.shape()).collect();
+    /// assert_eq!(shapes, [(2, 2), (2, 2), (1, 2)]);
+    /// assert_eq!(x.row_chunks(2).nth(1).unwrap().get(1, 0), Some(&7));
+    /// ```
+    pub fn row_chunks(&self, chunk_rows: usize) -> RowChunks<'_, T> {
+        assert!(chunk_rows != 0, "chunk_rows must be non-zero");
+        RowChunks {
+            rest: self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+            chunk_rows,
+        }
+    }
+
+    /// Returns an iterator over consecutive bands of `chunk_rows` rows,
+    /// each as a mutable view, like `slice::chunks_mut`. See `row_chunks`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_rows` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 8];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 4, 2).unwrap();
+    ///
+    /// for (idx, mut band) in x.row_chunks_mut(3).enumerate() {
+    ///     *band.get_mut_panic(band.num_rows() - 1, 1) = idx + 1;
+    /// }
+    /// assert_eq!(a, [0, 0, 0, 0, 0, 1, 0, 2]);
+    /// ```
+    pub fn row_chunks_mut(&mut self, chunk_rows: usize) -> RowChunksMut<'_, T> {
+        assert!(chunk_rows != 0, "chunk_rows must be non-zero");
+        RowChunksMut {
+            rest: self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+            chunk_rows,
+        }
+    }
+
+    /// Divides the array into two mutable views, of the rows before
+    /// `row_idx` and of those from it onwards, mirroring
+    /// `slice::split_at_mut`.
+    ///
+    /// The views borrow disjoint parts of the buffer, so they can be
+    /// handed to separate routines or threads together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_idx > num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    ///
+    /// let (mut top, mut bottom) = x.split_at_row_mut(1);
+    /// assert_eq!((top.shape(), bottom.shape()), ((1, 2), (2, 2)));
+    /// std::thread::scope(|scope| {
+    ///     scope.spawn(|| top.as_mut_slice().fill(0));
+    ///     scope.spawn(|| *bottom.get_mut_panic(1, 1) = 9);
+    /// });
+    /// assert_eq!(a, [0, 0, 3, 4, 5, 9]);
+    /// ```
+    pub fn split_at_row_mut(
+        &mut self,
+        row_idx: usize,
+    ) -> (TwoDimensionalArray<'_, T>, TwoDimensionalArray<'_, T>) {
+        let view = TwoDimensionalArray {
+            buffer: &mut *self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        };
+        view.into_split_at_row(row_idx)
+    }
+
+    /// Consumes the array, dividing it into two mutable views for the full
+    /// lifetime of the buffer. See `split_at_row_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_idx > num_rows`.
+    pub fn into_split_at_row(
+        self,
+        row_idx: usize,
+    ) -> (TwoDimensionalArray<'a, T>, TwoDimensionalArray<'a, T>) {
+        assert!(
+            row_idx <= self.num_rows,
+            "row {} out of bounds for array with {} rows",
+            row_idx,
+            self.num_rows
+        );
+        let (top, bottom) = self.buffer.split_at_mut(row_idx * self.num_cols);
+        (
+            TwoDimensionalArray {
+                buffer: top,
+                num_rows: row_idx,
+                num_cols: self.num_cols,
+            },
+            TwoDimensionalArray {
+                buffer: bottom,
+                num_rows: self.num_rows - row_idx,
+                num_cols: self.num_cols,
+            },
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::checked_len;
+use crate::{ShapeError, TwoDimensionalArrayRef, TwoDimensionalVec};
+use alloc::vec::Vec;
+
+/// Concatenates `arrays` vertically into a new owned array, stacking their
+/// rows from top to bottom.
+///
+/// An empty iterator gives a `0 x 0` array.
+///
+/// # Errors
+///
+/// Returns `ShapeError::LengthMismatch` when an array does not have as many
+/// columns as the first, and `ShapeError::Overflow` when the total number
+/// of rows overflows.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::{ShapeError, TwoDimensionalArrayRef, vstack};
+/// let top = TwoDimensionalArrayRef::new(&[1, 2], 1, 2).unwrap();
+/// let bottom = TwoDimensionalArrayRef::new(&[3, 4, 5, 6], 2, 2).unwrap();
+///
+/// let stacked = vstack([top, bottom]).unwrap();
+/// assert_eq!(stacked.shape(), (3, 2));
+/// assert_eq!(stacked.as_slice(), [1, 2, 3, 4, 5, 6]);
+///
+/// let narrow = TwoDimensionalArrayRef::new(&[7], 1, 1).unwrap();
+/// assert!(vstack([top, narrow]).is_err());
+///
+/// // Arrays without columns can have any number of rows.
+/// let tall = TwoDimensionalArrayRef::<i32>::new(&[], usize::MAX, 0).unwrap();
+/// assert!(matches!(vstack([tall, tall]), Err(ShapeError::Overflow { .. })));
+/// ```
+pub fn vstack<'a, T, I>(arrays: I) -> Result<TwoDimensionalVec<T>, ShapeError>
+where
+    T: Clone + 'a,
+    I: IntoIterator<Item = TwoDimensionalArrayRef<'a, T>>,
+{
+    let mut arrays = arrays.into_iter();
+    let Some(first) = arrays.next() else {
+        return Ok(TwoDimensionalVec::with_num_cols(0));
+    };
+    let mut stacked = TwoDimensionalVec::with_num_cols(first.num_cols);
+    for array in core::iter::once(first).chain(arrays) {
+        if array.num_cols != stacked.num_cols {
+            return Err(ShapeError::LengthMismatch {
+                expected: stacked.num_cols,
+                found: array.num_cols,
+            });
+        }
+        stacked.num_rows =
+            stacked
+                .num_rows
+                .checked_add(array.num_rows)
+                .ok_or(ShapeError::Overflow {
+                    num_rows: usize::MAX,
+                    num_cols: stacked.num_cols,
+                })?;
+        stacked.buffer.extend_from_slice(array.buffer);
+    }
+    Ok(stacked)
+}
+
+/// Concatenates `arrays` horizontally into a new owned array, placing them
+/// side by side from left to right.
+///
+/// An empty iterator gives a `0 x 0` array.
+///
+/// # Errors
+///
+/// Returns `ShapeError::LengthMismatch` when an array does not have as many
+/// rows as the first, and `ShapeError::Overflow` when the total number of
+/// columns or the total length overflows.
+///
+/// # Example
+///
+/// ```
+/// use two_dim_array::{ShapeError, TwoDimensionalArrayRef, hstack};
+/// let left = TwoDimensionalArrayRef::new(&[1, 4], 2, 1).unwrap();
+/// let right = TwoDimensionalArrayRef::new(&[2, 3, 5, 6], 2, 2).unwrap();
+///
+/// let stacked = hstack([left, right]).unwrap();
+/// assert_eq!(stacked.shape(), (2, 3));
+/// assert_eq!(stacked.as_slice(), [1, 2, 3, 4, 5, 6]);
+///
+/// // Arrays without rows can have any number of columns.
+/// let wide = TwoDimensionalArrayRef::<i32>::new(&[], 0, usize::MAX).unwrap();
+/// assert!(matches!(hstack([wide, wide]), Err(ShapeError::Overflow { .. })));
+/// ```
+pub fn hstack<'a, T, I>(arrays: I) -> Result<TwoDimensionalVec<T>, ShapeError>
+where
+    T: Clone + 'a,
+    I: IntoIterator<Item = TwoDimensionalArrayRef<'a, T>>,
+{
+    let arrays: Vec<_> = arrays.into_iter().collect();
+    let Some(first) = arrays.first() else {
+        return Ok(TwoDimensionalVec::with_num_cols(0));
+    };
+    let num_rows = first.num_rows;
+    if let Some(array) = arrays.iter().find(|array| array.num_rows != num_rows) {
+        return Err(ShapeError::LengthMismatch {
+            expected: num_rows,
+            found: array.num_rows,
+        });
+    }
+    let num_cols = arrays
+        .iter()
+        .try_fold(0usize, |total, array| total.checked_add(array.num_cols))
+        .ok_or(ShapeError::Overflow {
+            num_rows,
+            num_cols: usize::MAX,
+        })?;
+    let mut buffer = Vec::with_capacity(checked_len(num_rows, num_cols)?);
+    for row in 0..num_rows {
+        for array in &arrays {
+            buffer.extend_from_slice(array.get_panic(row, ..));
+        }
+    }
+    Ok(TwoDimensionalVec {
+        buffer,
+        num_rows,
+        num_cols,
+    })
+}
@@ -0,0 +1,299 @@
+//! `proptest` strategies generating owned arrays, enabled by the
+//! `proptest` feature.
+//!
+//! Generated arrays shrink toward smaller shapes as well as toward simpler
+//! elements.
+//!
+//! # Example
+//! ```
+//! use proptest::prelude::*;
+//! use two_dim_array::strategy::arrays;
+//!
+//! proptest!(|(x in arrays(1..4, 0..5, -10i32..10))| {
+//!     prop_assert!((1..4).contains(&x.num_rows()));
+//!     prop_assert!(x.num_cols() < 5);
+//!     prop_assert!(x.as_slice().iter().all(|elem| (-10..10).contains(elem)));
+//! });
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+use core::ops::Range;
+
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+use crate::TwoDimensionalVec;
+
+/// Returns a strategy generating arrays with a number of rows drawn from
+/// `rows`, a number of columns drawn from `cols` and elements drawn from
+/// `element`.
+///
+/// Failing arrays are shrunk by simplifying each element in turn, and then
+/// by trying to remove each row and then each column, keeping those the
+/// test needs to fail. The elements which remain keep their simplified
+/// values.
+///
+/// # Panics
+///
+/// Panics if either range is empty.
+///
+/// # Example
+///
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+/// use two_dim_array::strategy::arrays;
+///
+/// // Shrink an array with an element of at least 10 down to the smallest.
+/// let mut runner = TestRunner::deterministic();
+/// let mut tree = loop {
+///     let tree = arrays(1..10, 1..10, 0..100u32).new_tree(&mut runner).unwrap();
+///     if tree.current().as_slice().iter().any(|&elem| elem >= 10) {
+///         break tree;
+///     }
+/// };
+/// while tree.simplify() {
+///     while !tree.current().as_slice().iter().any(|&elem| elem >= 10) && tree.complicate() {}
+/// }
+/// assert_eq!(tree.current(), [[10]]);
+/// ```
+pub fn arrays<S>(
+    rows: Range<usize>,
+    cols: Range<usize>,
+    element: S,
+) -> impl Strategy<Value = TwoDimensionalVec<S::Value>>
+where
+    S: Strategy,
+{
+    assert!(!rows.is_empty(), "empty row range {:?}", rows);
+    assert!(!cols.is_empty(), "empty column range {:?}", cols);
+    ArrayStrategy {
+        rows,
+        cols,
+        square: false,
+        element,
+    }
+}
+
+/// Returns a strategy generating square arrays with a side length drawn
+/// from `size`, for operations such as `cholesky` and `pow`.
+///
+/// Arrays shrink as for `arrays`, removing rows together with the columns
+/// of the same index so they stay square.
+///
+/// # Panics
+///
+/// Panics if `size` is empty.
+pub fn square_arrays<S>(
+    size: Range<usize>,
+    element: S,
+) -> impl Strategy<Value = TwoDimensionalVec<S::Value>>
+where
+    S: Strategy,
+{
+    assert!(!size.is_empty(), "empty size range {:?}", size);
+    ArrayStrategy {
+        rows: size.clone(),
+        cols: size,
+        square: true,
+        element,
+    }
+}
+
+/// The strategy returned by `arrays` and `square_arrays`.
+#[derive(Debug)]
+struct ArrayStrategy<S> {
+    rows: Range<usize>,
+    cols: Range<usize>,
+    /// Whether the number of columns is always the number of rows.
+    square: bool,
+    element: S,
+}
+
+impl<S: Strategy> Strategy for ArrayStrategy<S> {
+    type Tree = ArrayValueTree<S::Tree>;
+    type Value = TwoDimensionalVec<S::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let num_rows = self.rows.new_tree(runner)?.current();
+        let num_cols = if self.square {
+            num_rows
+        } else {
+            self.cols.new_tree(runner)?.current()
+        };
+        let elements = (0..num_rows * num_cols)
+            .map(|_| self.element.new_tree(runner))
+            .collect::<Result<_, _>>()?;
+        Ok(ArrayValueTree {
+            elements,
+            rows: vec![true; num_rows],
+            cols: vec![true; num_cols],
+            min_rows: self.rows.start,
+            min_cols: self.cols.start,
+            square: self.square,
+            shrink: Shrink::Element(0),
+            prev_shrink: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Shrink {
+    /// Simplify the element at this index of the generated elements.
+    Element(usize),
+    /// Remove this row, and the column with the same index for square
+    /// arrays.
+    Row(usize),
+    /// Remove this column.
+    Col(usize),
+}
+
+/// The value tree of `ArrayStrategy`.
+///
+/// The elements are kept in the generated shape, and the current array is
+/// made of those in the rows and columns still included.
+#[derive(Debug)]
+struct ArrayValueTree<E> {
+    elements: Vec<E>,
+    rows: Vec<bool>,
+    cols: Vec<bool>,
+    min_rows: usize,
+    min_cols: usize,
+    square: bool,
+    shrink: Shrink,
+    prev_shrink: Option<Shrink>,
+}
+
+impl<E: ValueTree> ValueTree for ArrayValueTree<E> {
+    type Value = TwoDimensionalVec<E::Value>;
+
+    fn current(&self) -> Self::Value {
+        let included = |flags: &[bool]| {
+            let indices = flags.iter().enumerate().filter(|&(_, &flag)| flag);
+            indices.map(|(idx, _)| idx).collect::<Vec<_>>()
+        };
+        let (rows, cols) = (included(&self.rows), included(&self.cols));
+        TwoDimensionalVec::from_fn(rows.len(), cols.len(), |row, col| {
+            self.elements[rows[row] * self.cols.len() + cols[col]].current()
+        })
+    }
+
+    fn simplify(&mut self) -> bool {
+        // Simplify each element in turn, then try removing each row and then
+        // each column. `complicate` undoes the last step.
+        while let Shrink::Element(idx) = self.shrink {
+            if idx >= self.elements.len() {
+                self.shrink = Shrink::Row(0);
+            } else if self.elements[idx].simplify() {
+                self.prev_shrink = Some(self.shrink);
+                return true;
+            } else {
+                self.shrink = Shrink::Element(idx + 1);
+            }
+        }
+        if let Shrink::Row(idx) = self.shrink {
+            let num_rows = self.rows.iter().filter(|&&flag| flag).count();
+            if idx < self.rows.len() && num_rows > self.min_rows {
+                self.set_row(idx, false);
+                self.prev_shrink = Some(self.shrink);
+                self.shrink = Shrink::Row(idx + 1);
+                return true;
+            }
+            self.shrink = Shrink::Col(0);
+        }
+        if let Shrink::Col(idx) = self.shrink {
+            let num_cols = self.cols.iter().filter(|&&flag| flag).count();
+            if !self.square && idx < self.cols.len() && num_cols > self.min_cols {
+                self.cols[idx] = false;
+                self.prev_shrink = Some(self.shrink);
+                self.shrink = Shrink::Col(idx + 1);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.prev_shrink {
+            None => false,
+            Some(Shrink::Element(idx)) => {
+                // The element may be able to complicate again.
+                if !self.elements[idx].complicate() {
+                    self.prev_shrink = None;
+                    return false;
+                }
+                true
+            }
+            Some(Shrink::Row(idx)) => {
+                self.set_row(idx, true);
+                self.prev_shrink = None;
+                true
+            }
+            Some(Shrink::Col(idx)) => {
+                self.cols[idx] = true;
+                self.prev_shrink = None;
+                true
+            }
+        }
+    }
+}
+
+impl<E> ArrayValueTree<E> {
+    /// Includes or removes a row, along with the matching column of a
+    /// square array.
+    fn set_row(&mut self, idx: usize, included: bool) {
+        self.rows[idx] = included;
+        if self.square {
+            self.cols[idx] = included;
+        }
+    }
+}
+
+/// Parameters of the `Arbitrary` implementation of `TwoDimensionalVec`.
+#[derive(Debug, Clone)]
+pub struct ArrayParams<P> {
+    /// The range the number of rows is drawn from.
+    pub rows: Range<usize>,
+    /// The range the number of columns is drawn from.
+    pub cols: Range<usize>,
+    /// The parameters passed to the element strategy.
+    pub element: P,
+}
+
+/// Defaults to up to 8 rows and columns.
+impl<P: Default> Default for ArrayParams<P> {
+    fn default() -> Self {
+        Self {
+            rows: 0..9,
+            cols: 0..9,
+            element: P::default(),
+        }
+    }
+}
+
+/// Generates arrays using `arrays` with the element type's own strategy.
+///
+/// # Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use two_dim_array::TwoDimensionalVec;
+///
+/// proptest!(|(x: TwoDimensionalVec<u8>)| {
+///     prop_assert_eq!(x.len(), x.num_rows() * x.num_cols());
+/// });
+/// ```
+impl<T> Arbitrary for TwoDimensionalVec<T>
+where
+    T: Arbitrary + Debug + 'static,
+    T::Strategy: 'static,
+{
+    type Parameters = ArrayParams<T::Parameters>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        arrays(params.rows, params.cols, T::arbitrary_with(params.element)).boxed()
+    }
+}
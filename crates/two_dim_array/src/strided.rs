@@ -0,0 +1,886 @@
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr::NonNull;
+
+use crate::TwoDimensionalArray;
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+
+/// A read-only two-dimensional view whose rows and columns are each
+/// separated by a fixed stride in the underlying buffer.
+///
+/// Unlike `TwoDimensionalArray`, rows need not be contiguous, which
+/// allows zero-copy views such as transposes (see
+/// `TwoDimensionalArray::t`). Element `(row, col)` lives at offset
+/// `row * row_stride + col * col_stride` from the start of the view.
+///
+/// `StridedView` is `Copy`, so it can be passed around freely while
+/// the underlying buffer is borrowed.
+///
+/// # Example
+/// ```
+/// use two_dim_array::TwoDimensionalArray;
+///
+/// let mut buffer = [1, 2, 3, 4, 5, 6];
+/// let view = TwoDimensionalArray::new(&mut buffer, 2, 3).unwrap();
+/// let transposed = view.t();
+///
+/// assert_eq!(transposed.shape(), (3, 2));
+/// assert_eq!(transposed.get(2, 1), Some(&6));
+/// ```
+pub struct StridedView<'a, T> {
+    ptr: NonNull<T>,
+    num_rows: usize,
+    num_cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// A mutable two-dimensional view whose rows and columns are each
+/// separated by a fixed stride in the underlying buffer.
+///
+/// The mutable counterpart of `StridedView`. The strides of a
+/// `StridedViewMut` never map two positions onto the same element,
+/// so disjoint views of one buffer may be held simultaneously.
+pub struct StridedViewMut<'a, T> {
+    ptr: NonNull<T>,
+    num_rows: usize,
+    num_cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: The views behave like `&'a T` and `&'a mut T` respectively.
+unsafe impl<T: Sync> Send for StridedView<'_, T> {}
+unsafe impl<T: Sync> Sync for StridedView<'_, T> {}
+unsafe impl<T: Send> Send for StridedViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for StridedViewMut<'_, T> {}
+
+impl<T> Clone for StridedView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StridedView<'_, T> {}
+
+impl<'a, T> StridedView<'a, T> {
+    /// Construct a `StridedView` from a pointer to its first element.
+    ///
+    /// # Safety
+    ///
+    /// For every `row < num_rows` and `col < num_cols` the offset
+    /// `row * row_stride + col * col_stride` from `ptr` must be a valid,
+    /// initialised `T` which is not mutated for the lifetime `'a`.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: NonNull<T>,
+        num_rows: usize,
+        num_cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        Self {
+            ptr,
+            num_rows,
+            num_cols,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Construct a `StridedView` with contiguous rows over `buffer`.
+    ///
+    /// Panics unless `buffer.len() == num_rows * num_cols`.
+    pub(crate) fn from_slice(buffer: &'a [T], num_rows: usize, num_cols: usize) -> Self {
+        assert_eq!(buffer.len(), num_rows * num_cols);
+        // SAFETY: Rows are contiguous and `num_cols` apart, covering exactly
+        // the borrowed buffer.
+        unsafe {
+            Self::from_raw_parts(
+                NonNull::from(buffer).cast(),
+                num_rows,
+                num_cols,
+                num_cols,
+                1,
+            )
+        }
+    }
+
+    /// Returns the shape of the view.
+    ///
+    /// Return order: `(num_rows, num_cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// The number of rows in the view.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the view.
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the total number of elements in the view
+    /// (`num_rows * num_cols`).
+    pub fn len(&self) -> usize {
+        self.num_rows * self.num_cols
+    }
+
+    /// Returns whether the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)`, without
+    /// doing bounds checking.
+    ///
+    /// For a safe alternative see `get`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked(&self, row_idx: usize, col_idx: usize) -> &'a T {
+        unsafe {
+            self.ptr
+                .add(row_idx * self.row_stride + col_idx * self.col_stride)
+                .as_ref()
+        }
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)` or `None`
+    /// if out of bounds.
+    ///
+    /// See `get_panic` for a version which panics on out of bounds access.
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Option<&'a T> {
+        if row_idx < self.num_rows && col_idx < self.num_cols {
+            // SAFETY: Bounds checked above.
+            Some(unsafe { self.get_unchecked(row_idx, col_idx) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)`. Panics on
+    /// out of bounds access.
+    pub fn get_panic(&self, row_idx: usize, col_idx: usize) -> &'a T {
+        match self.get(row_idx, col_idx) {
+            Some(elem) => elem,
+            None => out_of_bounds(row_idx, col_idx, self.shape()),
+        }
+    }
+
+    /// Returns an iterator over the elements of the view in row-major
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let elems: Vec<_> = x.t().iter().copied().collect();
+    /// assert_eq!(elems, [1, 3, 2, 4]);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + use<'a, T> {
+        let view = *self;
+        (0..self.len()).map(move |idx| {
+            // SAFETY: `idx < len` so the position is in bounds. This branch
+            // is never reached when `num_cols == 0` as then `len == 0`.
+            unsafe { view.get_unchecked(idx / view.num_cols, idx % view.num_cols) }
+        })
+    }
+
+    /// Returns an iterator yielding each row of the view as a `1 x n`
+    /// strided view, from the top.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// let col_sums: Vec<i32> = x.transposed().rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(col_sums, [5, 7, 9]);
+    /// ```
+    pub fn rows(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = StridedView<'a, T>> + ExactSizeIterator + use<'a, T> {
+        let view = *self;
+        (0..self.num_rows).map(move |row| {
+            view.sub_view(row..row + 1, 0..view.num_cols)
+                .expect("row is in bounds")
+        })
+    }
+
+    /// Returns the transpose of the view, without copying.
+    pub fn t(&self) -> StridedView<'a, T> {
+        Self {
+            num_rows: self.num_cols,
+            num_cols: self.num_rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..*self
+        }
+    }
+
+    /// Returns the sub-view covering `rows` x `cols` of the view, or `None`
+    /// if either range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let sub = x.t().sub_view(1..3, 0..2).unwrap();
+    /// assert_eq!(sub.iter().copied().collect::<Vec<_>>(), [2, 5, 3, 6]);
+    /// ```
+    pub fn sub_view(&self, rows: Range<usize>, cols: Range<usize>) -> Option<StridedView<'a, T>> {
+        let (ptr, num_rows, num_cols) =
+            sub_region(self.ptr, self.shape(), self.strides(), rows, cols)?;
+        // SAFETY: `sub_region` only yields positions within this view.
+        Some(unsafe {
+            Self::from_raw_parts(ptr, num_rows, num_cols, self.row_stride, self.col_stride)
+        })
+    }
+
+    fn strides(&self) -> (usize, usize) {
+        (self.row_stride, self.col_stride)
+    }
+
+    /// Copies the elements of the view into a new owned array with
+    /// contiguous rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// let transposed = x.t().to_owned_array();
+    /// assert_eq!(transposed.shape(), (3, 2));
+    /// assert_eq!(transposed.as_slice(), [1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_array(&self) -> TwoDimensionalVec<T>
+    where
+        T: Clone,
+    {
+        TwoDimensionalVec {
+            buffer: self.iter().cloned().collect(),
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        }
+    }
+}
+
+impl<'a, T> StridedViewMut<'a, T> {
+    /// Construct a `StridedViewMut` from a pointer to its first element.
+    ///
+    /// # Safety
+    ///
+    /// For every `row < num_rows` and `col < num_cols` the offset
+    /// `row * row_stride + col * col_stride` from `ptr` must be a valid,
+    /// initialised `T` which is not accessed through any other path for
+    /// the lifetime `'a`, and no two positions may map to the same offset.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: NonNull<T>,
+        num_rows: usize,
+        num_cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        Self {
+            ptr,
+            num_rows,
+            num_cols,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a read-only view of the same elements.
+    pub fn as_view(&self) -> StridedView<'_, T> {
+        // SAFETY: The shared borrow of `self` prevents mutation for the
+        // lifetime of the returned view.
+        unsafe {
+            StridedView::from_raw_parts(
+                self.ptr,
+                self.num_rows,
+                self.num_cols,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Reborrows the view, allowing it to be passed to functions taking
+    /// a `StridedViewMut` by value without giving it up.
+    pub fn reborrow(&mut self) -> StridedViewMut<'_, T> {
+        // SAFETY: The mutable borrow of `self` makes the returned view the
+        // only path to the elements for its lifetime.
+        unsafe {
+            StridedViewMut::from_raw_parts(
+                self.ptr,
+                self.num_rows,
+                self.num_cols,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Converts the view into a read-only view for the full lifetime `'a`.
+    pub fn into_view(self) -> StridedView<'a, T> {
+        // SAFETY: `self` is consumed so no mutable path remains.
+        unsafe {
+            StridedView::from_raw_parts(
+                self.ptr,
+                self.num_rows,
+                self.num_cols,
+                self.row_stride,
+                self.col_stride,
+            )
+        }
+    }
+
+    /// Returns the shape of the view.
+    ///
+    /// Return order: `(num_rows, num_cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// The number of rows in the view.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the view.
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the total number of elements in the view
+    /// (`num_rows * num_cols`).
+    pub fn len(&self) -> usize {
+        self.num_rows * self.num_cols
+    }
+
+    /// Returns whether the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)` or `None`
+    /// if out of bounds.
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> Option<&T> {
+        self.as_view().get(row_idx, col_idx)
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)`. Panics on
+    /// out of bounds access.
+    pub fn get_panic(&self, row_idx: usize, col_idx: usize) -> &T {
+        self.as_view().get_panic(row_idx, col_idx)
+    }
+
+    /// Returns a mutable reference to the element at `(row_idx, col_idx)`,
+    /// without doing bounds checking.
+    ///
+    /// For a safe alternative see `get_mut`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    /// even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked_mut(&mut self, row_idx: usize, col_idx: usize) -> &mut T {
+        unsafe {
+            self.ptr
+                .add(row_idx * self.row_stride + col_idx * self.col_stride)
+                .as_mut()
+        }
+    }
+
+    /// Returns a mutable reference to the element at `(row_idx, col_idx)` or
+    /// `None` if out of bounds.
+    pub fn get_mut(&mut self, row_idx: usize, col_idx: usize) -> Option<&mut T> {
+        if row_idx < self.num_rows && col_idx < self.num_cols {
+            // SAFETY: Bounds checked above.
+            Some(unsafe { self.get_unchecked_mut(row_idx, col_idx) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `(row_idx, col_idx)`.
+    /// Panics on out of bounds access.
+    pub fn get_mut_panic(&mut self, row_idx: usize, col_idx: usize) -> &mut T {
+        let shape = self.shape();
+        match self.get_mut(row_idx, col_idx) {
+            Some(elem) => elem,
+            None => out_of_bounds(row_idx, col_idx, shape),
+        }
+    }
+
+    /// Returns an iterator over the elements of the view in row-major
+    /// order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.as_view().iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the
+    /// view in row-major order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        let view = self.reborrow();
+        (0..view.len()).map(move |idx| {
+            // SAFETY: `idx < len` so the position is in bounds, and each
+            // position is yielded at most once, with distinct positions
+            // never aliasing.
+            unsafe {
+                view.ptr
+                    .add(
+                        (idx / view.num_cols) * view.row_stride
+                            + (idx % view.num_cols) * view.col_stride,
+                    )
+                    .as_mut()
+            }
+        })
+    }
+
+    /// Returns the transpose of the view, without copying.
+    pub fn t(self) -> StridedViewMut<'a, T> {
+        Self {
+            num_rows: self.num_cols,
+            num_cols: self.num_rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    /// Returns a read-only sub-view covering `rows` x `cols` of the view,
+    /// or `None` if either range is out of bounds.
+    pub fn sub_view(&self, rows: Range<usize>, cols: Range<usize>) -> Option<StridedView<'_, T>> {
+        self.as_view().sub_view(rows, cols)
+    }
+
+    /// Returns a mutable sub-view covering `rows` x `cols` of the view, or
+    /// `None` if either range is out of bounds.
+    pub fn sub_view_mut(
+        &mut self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Option<StridedViewMut<'_, T>> {
+        self.reborrow().into_sub_view(rows, cols)
+    }
+
+    /// Converts the view into the mutable sub-view covering `rows` x `cols`,
+    /// or `None` if either range is out of bounds.
+    pub fn into_sub_view(
+        self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Option<StridedViewMut<'a, T>> {
+        let (ptr, num_rows, num_cols) = sub_region(
+            self.ptr,
+            self.shape(),
+            (self.row_stride, self.col_stride),
+            rows,
+            cols,
+        )?;
+        // SAFETY: `sub_region` only yields positions within this view, which
+        // is consumed.
+        Some(unsafe {
+            Self::from_raw_parts(ptr, num_rows, num_cols, self.row_stride, self.col_stride)
+        })
+    }
+
+    /// Returns a mutable sub-view covering `rows` x `cols` for the full
+    /// lifetime `'a` without consuming the view, or `None` if either range
+    /// is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// The region must not be accessed through `self`, or through any other
+    /// view obtained this way, while the returned view is alive.
+    pub(crate) unsafe fn sub_view_unchecked_mut(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Option<StridedViewMut<'a, T>> {
+        let (ptr, num_rows, num_cols) = sub_region(
+            self.ptr,
+            self.shape(),
+            (self.row_stride, self.col_stride),
+            rows,
+            cols,
+        )?;
+        // SAFETY: `sub_region` only yields positions within this view, and
+        // the caller guarantees the region is not otherwise accessed.
+        Some(unsafe {
+            Self::from_raw_parts(ptr, num_rows, num_cols, self.row_stride, self.col_stride)
+        })
+    }
+
+    /// Splits the view into four disjoint mutable quadrants about
+    /// `(row_mid, col_mid)`, returned in the order top-left, top-right,
+    /// bottom-left, bottom-right.
+    ///
+    /// The top-left quadrant covers rows `0..row_mid` and columns
+    /// `0..col_mid`. Quadrants may be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_mid > num_rows` or `col_mid > num_cols`.
+    pub fn split_quadrants(self, row_mid: usize, col_mid: usize) -> [StridedViewMut<'a, T>; 4] {
+        assert!(
+            row_mid <= self.num_rows && col_mid <= self.num_cols,
+            "split point ({}, {}) out of bounds for {}x{} view",
+            row_mid,
+            col_mid,
+            self.num_rows,
+            self.num_cols
+        );
+        let (num_rows, num_cols) = self.shape();
+        let quadrant = |rows: Range<usize>, cols: Range<usize>| {
+            let (ptr, num_rows, num_cols) = sub_region(
+                self.ptr,
+                self.shape(),
+                (self.row_stride, self.col_stride),
+                rows,
+                cols,
+            )
+            .expect("split point is in bounds");
+            // SAFETY: The four quadrants cover disjoint positions of this
+            // view, which is consumed.
+            unsafe {
+                Self::from_raw_parts(ptr, num_rows, num_cols, self.row_stride, self.col_stride)
+            }
+        };
+        [
+            quadrant(0..row_mid, 0..col_mid),
+            quadrant(0..row_mid, col_mid..num_cols),
+            quadrant(row_mid..num_rows, 0..col_mid),
+            quadrant(row_mid..num_rows, col_mid..num_cols),
+        ]
+    }
+
+    /// Copies the elements of the view into a new owned array with
+    /// contiguous rows.
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_array(&self) -> TwoDimensionalVec<T>
+    where
+        T: Clone,
+    {
+        self.as_view().to_owned_array()
+    }
+}
+
+/// Validates `rows` x `cols` against `shape`, returning the pointer to the
+/// first element of the region along with its shape.
+///
+/// Empty regions keep `ptr` unchanged as positions past the end of a
+/// strided view need not lie within its allocation.
+fn sub_region<T>(
+    ptr: NonNull<T>,
+    shape: (usize, usize),
+    strides: (usize, usize),
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> Option<(NonNull<T>, usize, usize)> {
+    if rows.start > rows.end || rows.end > shape.0 || cols.start > cols.end || cols.end > shape.1 {
+        return None;
+    }
+    let (num_rows, num_cols) = (rows.len(), cols.len());
+    if num_rows == 0 || num_cols == 0 {
+        return Some((ptr, num_rows, num_cols));
+    }
+    // SAFETY: `(rows.start, cols.start)` is a position within the view.
+    let ptr = unsafe { ptr.add(rows.start * strides.0 + cols.start * strides.1) };
+    Some((ptr, num_rows, num_cols))
+}
+
+fn out_of_bounds(row_idx: usize, col_idx: usize, shape: (usize, usize)) -> ! {
+    panic!(
+        "index ({}, {}) out of bounds for {}x{} view",
+        row_idx, col_idx, shape.0, shape.1
+    )
+}
+
+impl<'a, T> TwoDimensionalArray<'a, T> {
+    /// Returns the whole array as a `StridedView`.
+    pub(crate) fn as_strided(&self) -> StridedView<'_, T> {
+        StridedView::from_slice(self.buffer, self.num_rows, self.num_cols)
+    }
+
+    /// Returns the whole array as a `StridedViewMut`.
+    pub(crate) fn as_strided_mut(&mut self) -> StridedViewMut<'_, T> {
+        // SAFETY: Rows are contiguous and `num_cols` apart, covering exactly
+        // the mutably borrowed buffer without overlap.
+        unsafe {
+            StridedViewMut::from_raw_parts(
+                NonNull::from(&mut *self.buffer).cast(),
+                self.num_rows,
+                self.num_cols,
+                self.num_cols,
+                1,
+            )
+        }
+    }
+
+    /// Returns a read-only view of the region `rows` x `cols` of the array,
+    /// or `None` if either range is out of bounds.
+    ///
+    /// The region's rows are generally not contiguous, hence it is a
+    /// `StridedView`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let sub = x.sub_view(1..3, 1..3).unwrap();
+    /// assert_eq!(sub.iter().copied().collect::<Vec<_>>(), [5, 6, 8, 9]);
+    /// assert!(x.sub_view(0..4, 0..1).is_none());
+    /// ```
+    pub fn sub_view(&self, rows: Range<usize>, cols: Range<usize>) -> Option<StridedView<'_, T>> {
+        self.as_strided().sub_view(rows, cols)
+    }
+
+    /// Returns a mutable view of the region `rows` x `cols` of the array,
+    /// or `None` if either range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// for elem in x.sub_view_mut(0..2, 1..2).unwrap().iter_mut() {
+    ///     *elem = 0;
+    /// }
+    /// assert_eq!(a, [1, 0, 3, 4, 0, 6, 7, 8, 9]);
+    /// ```
+    pub fn sub_view_mut(
+        &mut self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Option<StridedViewMut<'_, T>> {
+        self.as_strided_mut().into_sub_view(rows, cols)
+    }
+
+    /// Calls `f` with a mutable view of the region `rows` x `cols` and
+    /// returns its result, or returns `None` without calling `f` if either
+    /// range is out of bounds.
+    ///
+    /// The bounds are checked once up front, so `f` can index the region
+    /// relative to its top-left corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 16];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 4, 4).unwrap();
+    ///
+    /// // Paint a 2x2 brush stroke and count the cells it covered.
+    /// let painted = x.apply_region(1..3, 2..4, |mut region| {
+    ///     region.iter_mut().for_each(|elem| *elem = 1);
+    ///     region.len()
+    /// });
+    /// assert_eq!(painted, Some(4));
+    /// assert_eq!(x.get_panic(1, ..), [0, 0, 1, 1]);
+    /// assert_eq!(x.apply_region(3..5, 0..1, |_| ()), None);
+    /// ```
+    pub fn apply_region<F, R>(&mut self, rows: Range<usize>, cols: Range<usize>, f: F) -> Option<R>
+    where
+        F: FnOnce(StridedViewMut<'_, T>) -> R,
+    {
+        self.sub_view_mut(rows, cols).map(f)
+    }
+
+    /// Splits the array into four disjoint mutable quadrants about
+    /// `(row_mid, col_mid)`, returned in the order top-left, top-right,
+    /// bottom-left, bottom-right.
+    ///
+    /// Each quadrant can itself be split with
+    /// `StridedViewMut::split_quadrants`, which suits recursive block
+    /// algorithms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_mid > num_rows` or `col_mid > num_cols`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 4, 4).unwrap();
+    /// let [top_left, top_right, bottom_left, mut bottom_right] = x.split_quadrants_mut(2, 2);
+    ///
+    /// assert_eq!(top_left.iter().copied().collect::<Vec<_>>(), [1, 2, 5, 6]);
+    /// assert_eq!(top_right.iter().copied().collect::<Vec<_>>(), [3, 4, 7, 8]);
+    /// assert_eq!(bottom_left.iter().copied().collect::<Vec<_>>(), [9, 10, 13, 14]);
+    /// *bottom_right.get_mut_panic(1, 1) = 0;
+    /// assert_eq!(a[15], 0);
+    /// ```
+    pub fn split_quadrants_mut(
+        &mut self,
+        row_mid: usize,
+        col_mid: usize,
+    ) -> [StridedViewMut<'_, T>; 4] {
+        self.as_strided_mut().split_quadrants(row_mid, col_mid)
+    }
+
+    /// Returns a zero-copy transposed view of the array, where
+    /// `get(r, c)` on the view reads `(c, r)` of the array.
+    ///
+    /// Rows of the transposed view are not contiguous, hence it is a
+    /// `StridedView` rather than a `TwoDimensionalArray`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// let xt = x.t();
+    ///
+    /// assert_eq!(xt.shape(), (3, 2));
+    /// for r in 0..2 {
+    ///     for c in 0..3 {
+    ///         assert_eq!(x.get(r, c), xt.get(c, r));
+    ///     }
+    /// }
+    /// ```
+    pub fn t(&self) -> StridedView<'_, T> {
+        self.as_strided().t()
+    }
+
+    /// Returns a view of the main diagonal as an `n x 1` column, where `n`
+    /// is the smaller dimension of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// assert_eq!(x.diag().shape(), (2, 1));
+    /// assert_eq!(x.diag().iter().sum::<i32>(), 6);
+    /// ```
+    pub fn diag(&self) -> StridedView<'_, T> {
+        let len = self.num_rows.min(self.num_cols);
+        // SAFETY: Element `(i, i)` of the array is at offset
+        // `i * (num_cols + 1)` for `i < len`.
+        unsafe {
+            StridedView::from_raw_parts(
+                NonNull::from(&*self.buffer).cast(),
+                len,
+                1,
+                self.num_cols + 1,
+                0,
+            )
+        }
+    }
+
+    /// Returns a mutable view of the main diagonal as an `n x 1` column.
+    /// See `diag`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 9];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    ///
+    /// x.diag_mut().iter_mut().for_each(|elem| *elem = 1);
+    /// assert_eq!(a, [1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    /// ```
+    pub fn diag_mut(&mut self) -> StridedViewMut<'_, T> {
+        let len = self.num_rows.min(self.num_cols);
+        // SAFETY: Element `(i, i)` of the array is at offset
+        // `i * (num_cols + 1)` for `i < len`, which are distinct. There is
+        // only one column, so the zero column stride never aliases.
+        unsafe {
+            StridedViewMut::from_raw_parts(
+                NonNull::from(&mut *self.buffer).cast(),
+                len,
+                1,
+                self.num_cols + 1,
+                0,
+            )
+        }
+    }
+
+    /// Returns a zero-copy transposed view of the array. The same as `t`.
+    pub fn transposed(&self) -> StridedView<'_, T> {
+        self.t()
+    }
+
+    /// Returns a zero-copy mutable transposed view of the array, where
+    /// `get_mut(r, c)` on the view writes `(c, r)` of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    ///
+    /// let mut xt = x.transposed_mut();
+    /// assert_eq!(xt.shape(), (3, 2));
+    /// *xt.get_mut_panic(2, 0) = 0;
+    /// assert_eq!(a, [1, 2, 0, 4, 5, 6]);
+    /// ```
+    pub fn transposed_mut(&mut self) -> StridedViewMut<'_, T> {
+        self.as_strided_mut().t()
+    }
+
+    /// Converts the array into a shared view over `Cell`s, in the manner of
+    /// `Cell::as_slice_of_cells`.
+    ///
+    /// The returned view is `Copy`, so several aliases of the same buffer
+    /// can read and write elements at once from single-threaded code,
+    /// without `unsafe`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    ///
+    /// let cells = x.as_cell_view();
+    /// let alias = cells;
+    /// for (r, c) in [(0, 0), (0, 1)] {
+    ///     // Write the lower row into the upper row through one alias
+    ///     // while reading through the other.
+    ///     alias.get_panic(r, c).set(cells.get_panic(r + 1, c).get() * 10);
+    /// }
+    /// assert_eq!(a, [30, 40, 3, 4]);
+    /// ```
+    pub fn as_cell_view(&mut self) -> StridedView<'_, Cell<T>> {
+        let cells = Cell::from_mut(&mut *self.buffer).as_slice_of_cells();
+        StridedView::from_slice(cells, self.num_rows, self.num_cols)
+    }
+}
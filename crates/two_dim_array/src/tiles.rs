@@ -0,0 +1,284 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{StridedView, StridedViewMut, TwoDimensionalArray};
+
+/// The layout of the tiles of a `num_rows` by `num_cols` array, numbered
+/// in row-major order.
+#[derive(Debug, Clone, Copy)]
+struct Tiling {
+    num_rows: usize,
+    num_cols: usize,
+    block_rows: usize,
+    block_cols: usize,
+}
+
+impl Tiling {
+    fn new(shape: (usize, usize), block_rows: usize, block_cols: usize) -> Self {
+        assert!(
+            block_rows != 0 && block_cols != 0,
+            "block dimensions must be non-zero"
+        );
+        Self {
+            num_rows: shape.0,
+            num_cols: shape.1,
+            block_rows,
+            block_cols,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.num_rows.div_ceil(self.block_rows) * self.num_cols.div_ceil(self.block_cols)
+    }
+
+    /// Returns the rows and columns covered by tile `idx`, clipped to the
+    /// array.
+    fn region(&self, idx: usize) -> (Range<usize>, Range<usize>) {
+        let per_row = self.num_cols.div_ceil(self.block_cols);
+        let (row, col) = (
+            idx / per_row * self.block_rows,
+            idx % per_row * self.block_cols,
+        );
+        (
+            row..(row + self.block_rows).min(self.num_rows),
+            col..(col + self.block_cols).min(self.num_cols),
+        )
+    }
+}
+
+/// An iterator over non-overlapping rectangular tiles of an array as
+/// read-only views, created by `TwoDimensionalArray::blocks`.
+pub struct Blocks<'a, T> {
+    view: StridedView<'a, T>,
+    tiling: Tiling,
+    remaining: Range<usize>,
+}
+
+/// An iterator over non-overlapping rectangular tiles of an array as
+/// mutable views, created by `TwoDimensionalArray::blocks_mut`.
+///
+/// The tiles are disjoint, so all the yielded views may be held at once.
+pub struct BlocksMut<'a, T> {
+    view: StridedViewMut<'a, T>,
+    tiling: Tiling,
+    remaining: Range<usize>,
+}
+
+impl<'a, T> Blocks<'a, T> {
+    fn block(&self, idx: usize) -> StridedView<'a, T> {
+        let (rows, cols) = self.tiling.region(idx);
+        self.view
+            .sub_view(rows, cols)
+            .expect("tiles are within the array")
+    }
+}
+
+impl<'a, T> Iterator for Blocks<'a, T> {
+    type Item = StridedView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.remaining.next()?;
+        Some(self.block(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Blocks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.remaining.next_back()?;
+        Some(self.block(idx))
+    }
+}
+
+impl<T> ExactSizeIterator for Blocks<'_, T> {}
+
+impl<T> FusedIterator for Blocks<'_, T> {}
+
+impl<'a, T> BlocksMut<'a, T> {
+    fn block(&self, idx: usize) -> StridedViewMut<'a, T> {
+        let (rows, cols) = self.tiling.region(idx);
+        // SAFETY: Tiles do not overlap and each index is yielded once, while
+        // `view` itself is never used to access elements.
+        unsafe { self.view.sub_view_unchecked_mut(rows, cols) }.expect("tiles are within the array")
+    }
+}
+
+impl<'a, T> Iterator for BlocksMut<'a, T> {
+    type Item = StridedViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.remaining.next()?;
+        Some(self.block(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for BlocksMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.remaining.next_back()?;
+        Some(self.block(idx))
+    }
+}
+
+impl<T> ExactSizeIterator for BlocksMut<'_, T> {}
+
+impl<T> FusedIterator for BlocksMut<'_, T> {}
+
+/// An iterator over every overlapping window of an array as read-only
+/// views, created by `TwoDimensionalArray::windows`.
+pub struct Windows<'a, T> {
+    view: StridedView<'a, T>,
+    window: (usize, usize),
+    /// The number of window positions along each row.
+    per_row: usize,
+    remaining: Range<usize>,
+}
+
+impl<'a, T> Windows<'a, T> {
+    fn window(&self, idx: usize) -> StridedView<'a, T> {
+        let (row, col) = (idx / self.per_row, idx % self.per_row);
+        self.view
+            .sub_view(row..row + self.window.0, col..col + self.window.1)
+            .expect("windows are within the array")
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = StridedView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.remaining.next()?;
+        Some(self.window(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.remaining.next_back()?;
+        Some(self.window(idx))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+/// Iteration over rectangular sub-views of the array.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns an iterator over the array in non-overlapping tiles of
+    /// `block_rows` by `block_cols`, in row-major order of the tiles.
+    ///
+    /// Tiles along the bottom and right edges are smaller when the block
+    /// dimensions do not divide the shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_rows` or `block_cols` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    ///     7, 8, 9,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    ///
+    /// let sums: Vec<i32> = x.blocks(2, 2).map(|tile| tile.iter().sum()).collect();
+    /// assert_eq!(sums, [12, 9, 15, 9]);
+    /// let shapes: Vec<_> = x.blocks(2, 2).map(|tile| tile.shape()).collect();
+    /// assert_eq!(shapes, [(2, 2), (2, 1), (1, 2), (1, 1)]);
+    /// ```
+    pub fn blocks(&self, block_rows: usize, block_cols: usize) -> Blocks<'_, T> {
+        let tiling = Tiling::new(self.shape(), block_rows, block_cols);
+        Blocks {
+            view: self.as_strided(),
+            tiling,
+            remaining: 0..tiling.len(),
+        }
+    }
+
+    /// Returns an iterator over the array in non-overlapping mutable tiles
+    /// of `block_rows` by `block_cols`. See `blocks`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_rows` or `block_cols` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [0; 12];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 4).unwrap();
+    ///
+    /// for (idx, mut tile) in x.blocks_mut(2, 2).enumerate() {
+    ///     tile.iter_mut().for_each(|elem| *elem = idx);
+    /// }
+    /// assert_eq!(a, [0, 0, 1, 1, 0, 0, 1, 1, 2, 2, 3, 3]);
+    /// ```
+    pub fn blocks_mut(&mut self, block_rows: usize, block_cols: usize) -> BlocksMut<'_, T> {
+        let tiling = Tiling::new(self.shape(), block_rows, block_cols);
+        BlocksMut {
+            view: self.as_strided_mut(),
+            tiling,
+            remaining: 0..tiling.len(),
+        }
+    }
+
+    /// Returns an iterator over every `height` by `width` window of the
+    /// array, like `slice::windows` in two dimensions. Windows overlap and
+    /// are yielded in row-major order of their top-left corners.
+    ///
+    /// Yields nothing if the window is larger than the array in either
+    /// dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `height` or `width` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    ///     7, 8, 9,
+    /// ];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    ///
+    /// let maxima: Vec<i32> = x
+    ///     .windows((2, 2))
+    ///     .map(|window| *window.iter().max().unwrap())
+    ///     .collect();
+    /// assert_eq!(maxima, [5, 6, 8, 9]);
+    /// assert_eq!(x.windows((4, 1)).count(), 0);
+    /// ```
+    pub fn windows(&self, (height, width): (usize, usize)) -> Windows<'_, T> {
+        assert!(
+            height != 0 && width != 0,
+            "window dimensions must be non-zero"
+        );
+        let positions = |len: usize, size: usize| (len + 1).saturating_sub(size);
+        let per_row = positions(self.num_cols, width);
+        Windows {
+            view: self.as_strided(),
+            window: (height, width),
+            per_row,
+            remaining: 0..positions(self.num_rows, height) * per_row,
+        }
+    }
+}
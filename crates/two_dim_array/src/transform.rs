@@ -0,0 +1,464 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+
+use crate::TwoDimensionalArray;
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+
+/// In-place rearrangements of the elements.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Transposes the array in place, so element `(r, c)` moves to
+    /// `(c, r)` and the shape becomes `(num_cols, num_rows)`.
+    ///
+    /// Square arrays swap elements across the diagonal. Rectangular arrays
+    /// follow the cycles of the permutation, tracking visited elements in a
+    /// bitset of one bit per element rather than a second buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.transpose_in_place();
+    ///
+    /// assert_eq!(x.shape(), (3, 2));
+    /// assert_eq!(x.get_panic(2, ..), [3, 6]);
+    /// assert_eq!(a, [1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn transpose_in_place(&mut self) {
+        let (num_rows, num_cols) = self.shape();
+        if num_rows == num_cols {
+            for row in 0..num_rows {
+                for col in row + 1..num_cols {
+                    self.buffer.swap(row * num_cols + col, col * num_cols + row);
+                }
+            }
+        } else if self.len() > 1 {
+            // Element `idx = r * num_cols + c` moves to `c * num_rows + r`,
+            // which is `idx * num_rows` modulo `len - 1`. The first and last
+            // elements stay put.
+            let modulus = self.len() as u128 - 1;
+            let dest = |idx: usize| (idx as u128 * num_rows as u128 % modulus) as usize;
+            let mut visited = vec![0u64; self.len().div_ceil(64)];
+            for start in 1..self.len() - 1 {
+                if visited[start / 64] & (1 << (start % 64)) != 0 {
+                    continue;
+                }
+                // `start` holds the element due at the current position,
+                // so swapping along the cycle carries each into place.
+                let mut idx = dest(start);
+                while idx != start {
+                    self.buffer.swap(start, idx);
+                    visited[idx / 64] |= 1 << (idx % 64);
+                    idx = dest(idx);
+                }
+            }
+        }
+        (self.num_rows, self.num_cols) = (num_cols, num_rows);
+    }
+
+    /// Rotates the array a quarter turn clockwise in place, so the first
+    /// column becomes the first row reversed.
+    ///
+    /// Square arrays keep their shape. Rectangular arrays become
+    /// `(num_cols, num_rows)` as with `transpose_in_place`, which the
+    /// rotation is built on, and the buffer is reinterpreted in that shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.rotate90();
+    ///
+    /// assert_eq!(x.shape(), (3, 2));
+    /// assert_eq!(a, [4, 1, 5, 2, 6, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn rotate90(&mut self) {
+        self.transpose_in_place();
+        self.reverse_each_row();
+    }
+
+    /// Rotates the array a half turn in place. The shape is unchanged, as
+    /// this is `reverse`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.rotate180();
+    /// assert_eq!(a, [6, 5, 4, 3, 2, 1]);
+    /// ```
+    pub fn rotate180(&mut self) {
+        self.reverse();
+    }
+
+    /// Rotates the array a quarter turn anticlockwise in place, so the
+    /// first row becomes the first column reversed. See `rotate90` for how
+    /// rectangular arrays are handled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.rotate270();
+    ///
+    /// assert_eq!(x.shape(), (3, 2));
+    /// assert_eq!(a, [3, 6, 2, 5, 1, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn rotate270(&mut self) {
+        self.reverse_each_row();
+        self.transpose_in_place();
+    }
+
+    #[cfg(feature = "alloc")]
+    fn reverse_each_row(&mut self) {
+        if self.num_cols > 0 {
+            for row in self.buffer.chunks_exact_mut(self.num_cols) {
+                row.reverse();
+            }
+        }
+    }
+}
+
+/// Swapping elements, and whole rows and columns such as for pivoting.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Swaps the elements at positions `a` and `b`, which may lie in the
+    /// same row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either position is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// x.swap((0, 0), (0, 1));
+    /// x.swap((0, 0), (1, 1));
+    /// assert_eq!(a, [4, 1, 3, 2]);
+    /// ```
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let offset = |(row, col): (usize, usize)| match self.try_get(row, col) {
+            Ok(_) => row * self.num_cols + col,
+            Err(err) => panic!("{}", err),
+        };
+        let (a, b) = (offset(a), offset(b));
+        self.buffer.swap(a, b);
+    }
+
+    /// Swaps rows `a` and `b`, exchanging their contiguous slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// x.swap_rows(0, 2);
+    /// assert_eq!(a, [5, 6, 3, 4, 1, 2]);
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for row in [a, b] {
+            assert!(
+                row < self.num_rows,
+                "row {} out of bounds for array with {} rows",
+                row,
+                self.num_rows
+            );
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        if low == high {
+            return;
+        }
+        let num_cols = self.num_cols;
+        let (head, tail) = self.buffer.split_at_mut(high * num_cols);
+        head[low * num_cols..(low + 1) * num_cols].swap_with_slice(&mut tail[..num_cols]);
+    }
+
+    /// Swaps columns `a` and `b`, one element per row as columns are not
+    /// contiguous.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 2, 3).unwrap();
+    /// x.swap_cols(0, 2);
+    /// assert_eq!(a, [3, 2, 1, 6, 5, 4]);
+    /// ```
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        for col in [a, b] {
+            assert!(
+                col < self.num_cols,
+                "column {} out of bounds for array with {} columns",
+                col,
+                self.num_cols
+            );
+        }
+        for row in self.buffer.chunks_exact_mut(self.num_cols) {
+            row.swap(a, b);
+        }
+    }
+}
+
+/// Reordering whole rows, such as when each row is a record.
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Sorts the rows lexicographically in place, as `slice::sort` sorts
+    /// slices. See `sort_rows_by`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     2, 1,
+    ///     1, 9,
+    ///     1, 3,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// x.sort_rows();
+    /// assert_eq!(a, [1, 3, 1, 9, 2, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_rows(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_rows_by(|a, b| a.cmp(b));
+    }
+
+    /// Sorts the rows in place with a comparator on whole rows.
+    ///
+    /// The sort is stable. Only a permutation of row indices is allocated
+    /// and sorted, which is then applied by swapping rows along its cycles,
+    /// so each row moves at most once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     1, 5,
+    ///     2, 4,
+    ///     3, 5,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// // Descending by the second field, keeping ties in order.
+    /// x.sort_rows_by(|a, b| b[1].cmp(&a[1]));
+    /// assert_eq!(a, [1, 5, 3, 5, 2, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_rows_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let num_cols = self.num_cols;
+        let row = |idx: usize| &self.buffer[idx * num_cols..(idx + 1) * num_cols];
+        let mut order: Vec<usize> = (0..self.num_rows).collect();
+        order.sort_by(|&a, &b| compare(row(a), row(b)));
+        self.permute_rows(order);
+    }
+
+    /// Sorts the rows in place by a key extracted from each row. See
+    /// `sort_rows_by`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [
+    ///     3, 4,
+    ///     1, 1,
+    ///     2, 0,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// x.sort_rows_by_key(|row| row.iter().sum::<i32>());
+    /// assert_eq!(a, [1, 1, 2, 0, 3, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_rows_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&[T]) -> K,
+        K: Ord,
+    {
+        self.sort_rows_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Rotates the rows in place so row `k` becomes the first, moving the
+    /// first `k` rows to the end, like `slice::rotate_left` on whole rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > num_rows`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// // A ring buffer of readings, oldest row first.
+    /// let mut a = [
+    ///     1, 10,
+    ///     2, 20,
+    ///     3, 30,
+    /// ];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// x.rotate_rows(1);
+    /// x.as_mut_slice()[4..].copy_from_slice(&[4, 40]);
+    /// assert_eq!(a, [2, 20, 3, 30, 4, 40]);
+    /// ```
+    pub fn rotate_rows(&mut self, k: usize) {
+        assert!(
+            k <= self.num_rows,
+            "cannot rotate by {} rows in array with {} rows",
+            k,
+            self.num_rows
+        );
+        self.buffer.rotate_left(k * self.num_cols);
+    }
+
+    /// Reverses the order of the rows in place, leaving each row's
+    /// contents as they are. The middle row of an odd number of rows stays
+    /// put.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// x.reverse_rows();
+    /// assert_eq!(a, [5, 6, 3, 4, 1, 2]);
+    /// ```
+    pub fn reverse_rows(&mut self) {
+        let num_rows = self.num_rows;
+        for row in 0..num_rows / 2 {
+            self.swap_rows(row, num_rows - 1 - row);
+        }
+    }
+
+    /// Reverses every element in place, reversing both the order of the
+    /// rows and each row. This is the same as `rotate180`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4, 5, 6];
+    /// let mut x = TwoDimensionalArray::new(&mut a, 3, 2).unwrap();
+    /// x.reverse();
+    /// assert_eq!(a, [6, 5, 4, 3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.buffer.reverse();
+    }
+
+    /// Rearranges the rows so row `idx` becomes the one previously at
+    /// `order[idx]`. `order` must be a permutation of the row indices.
+    #[cfg(feature = "alloc")]
+    fn permute_rows(&mut self, mut order: Vec<usize>) {
+        for start in 0..order.len() {
+            let mut idx = start;
+            // Pull each row due at `idx` into place, marking positions done
+            // by making them fixed points.
+            while order[idx] != start {
+                let source = order[idx];
+                self.swap_rows(idx, source);
+                order[idx] = idx;
+                idx = source;
+            }
+            order[idx] = idx;
+        }
+    }
+}
+
+/// In-place rearrangements which change the shape.
+#[cfg(feature = "alloc")]
+impl<T> TwoDimensionalVec<T> {
+    /// Transposes the array in place. See
+    /// `TwoDimensionalArray::transpose_in_place`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_fn(2, 4, |row, col| 10 * row + col);
+    /// x.transpose_in_place();
+    /// assert_eq!(x.shape(), (4, 2));
+    /// assert_eq!(x.as_slice(), [0, 10, 1, 11, 2, 12, 3, 13]);
+    /// ```
+    pub fn transpose_in_place(&mut self) {
+        self.as_view_mut().transpose_in_place();
+        (self.num_rows, self.num_cols) = (self.num_cols, self.num_rows);
+    }
+
+    /// Rotates the array a quarter turn clockwise in place. See
+    /// `TwoDimensionalArray::rotate90`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_fn(2, 2, |row, col| 2 * row + col);
+    /// x.rotate90();
+    /// assert_eq!(x.as_slice(), [2, 0, 3, 1]);
+    /// ```
+    pub fn rotate90(&mut self) {
+        self.as_view_mut().rotate90();
+        (self.num_rows, self.num_cols) = (self.num_cols, self.num_rows);
+    }
+
+    /// Rotates the array a half turn in place. See
+    /// `TwoDimensionalArray::rotate180`.
+    pub fn rotate180(&mut self) {
+        self.as_view_mut().rotate180();
+    }
+
+    /// Rotates the array a quarter turn anticlockwise in place. See
+    /// `TwoDimensionalArray::rotate270`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalVec;
+    /// let mut x = TwoDimensionalVec::from_fn(1, 3, |_, col| col);
+    /// x.rotate270();
+    /// assert_eq!(x.shape(), (3, 1));
+    /// assert_eq!(x.as_slice(), [2, 1, 0]);
+    /// ```
+    pub fn rotate270(&mut self) {
+        self.as_view_mut().rotate270();
+        (self.num_rows, self.num_cols) = (self.num_cols, self.num_rows);
+    }
+}
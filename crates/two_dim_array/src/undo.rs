@@ -0,0 +1,203 @@
+use alloc::collections::VecDeque;
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+use crate::{Change, StridedViewMut, TwoDimensionalArray};
+
+/// A mutable view which journals its mutations so they can be undone and
+/// redone, for editor-style applications.
+///
+/// Mutations made through `set` and `modify_region` accumulate into a
+/// pending step, which `snapshot` closes off as a single undo step. At most
+/// `max_history` steps are kept; the oldest are forgotten first. Making a
+/// new mutation after an `undo` discards the steps which could have been
+/// redone.
+///
+/// # Example
+/// ```
+/// use two_dim_array::{TwoDimensionalArray, UndoableArray};
+///
+/// let mut a = [0; 4];
+/// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+/// let mut editor = UndoableArray::new(x, 16);
+///
+/// editor.set(0, 0, 1);
+/// editor.set(0, 1, 2);
+/// editor.snapshot();
+/// editor.set(1, 1, 3);
+/// assert_eq!(editor.array().as_slice(), [1, 2, 0, 3]);
+///
+/// assert!(editor.undo());
+/// assert_eq!(editor.array().as_slice(), [1, 2, 0, 0]);
+/// assert!(editor.undo());
+/// assert_eq!(editor.array().as_slice(), [0, 0, 0, 0]);
+/// assert!(!editor.undo());
+///
+/// assert!(editor.redo());
+/// assert_eq!(editor.array().as_slice(), [1, 2, 0, 0]);
+/// ```
+pub struct UndoableArray<'a, T> {
+    array: TwoDimensionalArray<'a, T>,
+    undo_stack: VecDeque<Vec<Change<T>>>,
+    redo_stack: Vec<Vec<Change<T>>>,
+    pending: Vec<Change<T>>,
+    max_history: usize,
+}
+
+impl<'a, T: Clone + PartialEq> UndoableArray<'a, T> {
+    /// Wraps `array`, keeping at most `max_history` undo steps.
+    pub fn new(array: TwoDimensionalArray<'a, T>, max_history: usize) -> Self {
+        Self {
+            array,
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            pending: Vec::new(),
+            max_history,
+        }
+    }
+
+    /// Returns the wrapped array for reading.
+    pub fn array(&self) -> &TwoDimensionalArray<'a, T> {
+        &self.array
+    }
+
+    /// Unwraps the array, discarding the history.
+    pub fn into_inner(self) -> TwoDimensionalArray<'a, T> {
+        self.array
+    }
+
+    /// Sets the element at `(row_idx, col_idx)` to `value`, recording the
+    /// change in the pending step. Writing an equal value records nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn set(&mut self, row_idx: usize, col_idx: usize, value: T) {
+        let elem = self.array.get_mut_panic(row_idx, col_idx);
+        if *elem == value {
+            return;
+        }
+        let old = core::mem::replace(elem, value.clone());
+        self.record(vec![((row_idx, col_idx), old, value)]);
+    }
+
+    /// Runs `f` on a mutable view of the region `rows` x `cols`, recording
+    /// every element it changes in the pending step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::{TwoDimensionalArray, UndoableArray};
+    /// let mut a = [0; 9];
+    /// let x = TwoDimensionalArray::new(&mut a, 3, 3).unwrap();
+    /// let mut editor = UndoableArray::new(x, 16);
+    ///
+    /// editor.modify_region(1..3, 1..3, |mut region| {
+    ///     region.iter_mut().for_each(|elem| *elem = 7);
+    /// });
+    /// assert_eq!(editor.array().as_slice(), [0, 0, 0, 0, 7, 7, 0, 7, 7]);
+    ///
+    /// editor.undo();
+    /// assert_eq!(editor.array().as_slice(), [0; 9]);
+    /// ```
+    pub fn modify_region<F>(&mut self, rows: Range<usize>, cols: Range<usize>, f: F)
+    where
+        F: FnOnce(StridedViewMut<'_, T>),
+    {
+        let (num_rows, num_cols) = self.array.shape();
+        let mut region = self
+            .array
+            .sub_view_mut(rows.clone(), cols.clone())
+            .unwrap_or_else(|| {
+                panic!(
+                    "region {:?} x {:?} out of bounds for {}x{} array",
+                    rows, cols, num_rows, num_cols
+                )
+            });
+        let before: Vec<T> = region.iter().cloned().collect();
+        f(region.reborrow());
+
+        let width = cols.len();
+        let changes = before
+            .into_iter()
+            .zip(region.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| old != *new)
+            .map(|(idx, (old, new))| {
+                let index = (rows.start + idx / width, cols.start + idx % width);
+                (index, old, new.clone())
+            })
+            .collect();
+        self.record(changes);
+    }
+
+    /// Closes the pending mutations off as a single undo step. Does nothing
+    /// if there are none.
+    pub fn snapshot(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let step = core::mem::take(&mut self.pending);
+        self.undo_stack.push_back(step);
+        while self.undo_stack.len() > self.max_history {
+            self.undo_stack.pop_front();
+        }
+    }
+
+    /// Reverts the most recent undo step, first closing off any pending
+    /// mutations with `snapshot`. Returns `false` if there was nothing to
+    /// undo.
+    pub fn undo(&mut self) -> bool {
+        self.snapshot();
+        let Some(step) = self.undo_stack.pop_back() else {
+            return false;
+        };
+        for ((row_idx, col_idx), old, _) in step.iter().rev() {
+            self.array.get_mut_panic(*row_idx, *col_idx).clone_from(old);
+        }
+        self.redo_stack.push(step);
+        true
+    }
+
+    /// Reapplies the most recently undone step. Returns `false` if there
+    /// was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(step) = self.redo_stack.pop() else {
+            return false;
+        };
+        for ((row_idx, col_idx), _, new) in &step {
+            self.array.get_mut_panic(*row_idx, *col_idx).clone_from(new);
+        }
+        self.undo_stack.push_back(step);
+        true
+    }
+
+    /// Returns whether `undo` would change the array.
+    pub fn can_undo(&self) -> bool {
+        !self.pending.is_empty() || !self.undo_stack.is_empty()
+    }
+
+    /// Returns whether `redo` would change the array.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Forgets all undo and redo steps, keeping the array as it is.
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.pending.clear();
+    }
+
+    fn record(&mut self, changes: Vec<Change<T>>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.redo_stack.clear();
+        self.pending.extend(changes);
+    }
+}
@@ -0,0 +1,259 @@
+use core::slice::SliceIndex;
+
+#[cfg(feature = "alloc")]
+use crate::TwoDimensionalVec;
+use crate::error::{checked_len, infer_shape};
+use crate::{IndexError, ShapeError, TwoDimensionalArray};
+
+/// A read-only two-dimensional view of an underlying one-dimensional
+/// buffer, for data held only by shared reference such as a slice inside
+/// an `Arc`.
+///
+/// Has the same row-major layout and the non-`_mut` accessors of
+/// `TwoDimensionalArray`. As the view only borrows the buffer shared, it
+/// is `Copy` and the references it returns live as long as the buffer.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use two_dim_array::TwoDimensionalArrayRef;
+///
+/// let shared: Arc<[i32]> = Arc::from([1, 2, 3, 4, 5, 6]);
+/// let view = TwoDimensionalArrayRef::new(&shared, 2, 3).unwrap();
+///
+/// assert_eq!(view.shape(), (2, 3));
+/// assert_eq!(view.get(1, 0), Some(&4));
+/// assert_eq!(view.rows().last(), Some(&[4, 5, 6][..]));
+/// ```
+pub struct TwoDimensionalArrayRef<'a, T> {
+    pub(crate) buffer: &'a [T],
+    pub(crate) num_rows: usize,
+    pub(crate) num_cols: usize,
+}
+
+impl<T> Clone for TwoDimensionalArrayRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TwoDimensionalArrayRef<'_, T> {}
+
+impl<'a, T> TwoDimensionalArrayRef<'a, T> {
+    /// Construct a `TwoDimensionalArrayRef` from the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::InvalidShape` when the buffer cannot be arranged
+    /// with `num_rows * num_cols` and `ShapeError::Overflow` when that
+    /// product overflows.
+    pub fn new(buffer: &'a [T], num_rows: usize, num_cols: usize) -> Result<Self, ShapeError> {
+        if buffer.len() != checked_len(num_rows, num_cols)? {
+            Err(ShapeError::InvalidShape {
+                buffer_len: buffer.len(),
+                num_rows,
+                num_cols,
+            })
+        } else {
+            Ok(Self {
+                buffer,
+                num_rows,
+                num_cols,
+            })
+        }
+    }
+
+    /// Construct a `TwoDimensionalArrayRef` with `num_rows` rows, inferring
+    /// the number of columns from the length of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the buffer length is not a
+    /// multiple of `num_rows`. Zero rows are only accepted for an empty
+    /// buffer, giving zero columns.
+    pub fn new_with_rows(buffer: &'a [T], num_rows: usize) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = infer_shape(buffer.len(), num_rows, true)?;
+        Self::new(buffer, num_rows, num_cols)
+    }
+
+    /// Construct a `TwoDimensionalArrayRef` with `num_cols` columns,
+    /// inferring the number of rows from the length of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::NotDivisible` when the buffer length is not a
+    /// multiple of `num_cols`. Zero columns are only accepted for an empty
+    /// buffer, giving zero rows.
+    pub fn new_with_cols(buffer: &'a [T], num_cols: usize) -> Result<Self, ShapeError> {
+        let (num_rows, num_cols) = infer_shape(buffer.len(), num_cols, false)?;
+        Self::new(buffer, num_rows, num_cols)
+    }
+
+    /// Update the shape of the view to have `num_rows`, `num_cols`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ShapeError::InvalidShape` when the buffer cannot be reshaped
+    /// to the requested shape and `ShapeError::Overflow` when
+    /// `num_rows * num_cols` overflows.
+    pub fn reshape(&mut self, num_rows: usize, num_cols: usize) -> Result<(), ShapeError> {
+        *self = Self::new(self.buffer, num_rows, num_cols)?;
+        Ok(())
+    }
+
+    /// Returns the current shape that the buffer is being viewed as.
+    ///
+    /// Return order: `(num_rows, num_cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_cols)
+    }
+
+    /// The number of rows in the current view of the buffer.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the current view of the buffer.
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the total number of elements in the underlying
+    /// slice (`num_rows * num_cols`).
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns whether the underlying slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns a reference to an element or row subslice, without doing
+    /// bounds checking. See `TwoDimensionalArray::get_unchecked`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is
+    /// *[undefined behavior]* even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked<I>(&self, row_idx: usize, col_idx: I) -> &'a I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        unsafe {
+            self.buffer
+                .get_unchecked(row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols)
+                .get_unchecked(col_idx)
+        }
+    }
+
+    /// Returns a reference to an element or row subslice depending on the
+    /// type of index, or `None` if it is out of bounds. See
+    /// `TwoDimensionalArray::get`.
+    pub fn get<I>(&self, row_idx: usize, col_idx: I) -> Option<&'a I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.buffer
+            .get(row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols)?
+            .get(col_idx)
+    }
+
+    /// Returns a reference to the element at `(row_idx, col_idx)` or an
+    /// `IndexError` carrying the index and shape if it is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` when either index is out of bounds.
+    pub fn try_get(&self, row_idx: usize, col_idx: usize) -> Result<&'a T, IndexError> {
+        if row_idx >= self.num_rows || col_idx >= self.num_cols {
+            return Err(IndexError {
+                row: row_idx,
+                col: col_idx,
+                shape: self.shape(),
+            });
+        }
+        Ok(&self.buffer[row_idx * self.num_cols + col_idx])
+    }
+
+    /// Returns a bounds checked reference to an element or row subslice
+    /// depending on the type of col_idx. Panics on out of bounds access.
+    pub fn get_panic<I>(&self, row_idx: usize, col_idx: I) -> &'a I::Output
+    where
+        I: SliceIndex<[T]>,
+    {
+        &self.buffer[row_idx * self.num_cols..row_idx * self.num_cols + self.num_cols][col_idx]
+    }
+
+    /// Returns an iterator yielding the array slices of the contiguous
+    /// rows of the buffer.
+    ///
+    /// A view without columns yields one empty slice per row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArrayRef;
+    /// let x = TwoDimensionalArrayRef::new(&[1, 2, 3, 4], 2, 2).unwrap();
+    /// assert!(x.rows().eq([[1, 2], [3, 4]]));
+    ///
+    /// let no_cols = TwoDimensionalArrayRef::<i32>::new(&[], 3, 0).unwrap();
+    /// assert_eq!(no_cols.rows().count(), 3);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let (buffer, num_cols) = (self.buffer, self.num_cols);
+        // Indexing rather than `chunks`, which rejects a length of zero.
+        (0..self.num_rows).map(move |row_idx| &buffer[row_idx * num_cols..(row_idx + 1) * num_cols])
+    }
+
+    /// Returns a reference to the entire underlying one-dimensional
+    /// buffer.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.buffer
+    }
+}
+
+impl<T> TwoDimensionalArray<'_, T> {
+    /// Returns a read-only view of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use two_dim_array::TwoDimensionalArray;
+    /// let mut a = [1, 2, 3, 4];
+    /// let x = TwoDimensionalArray::new(&mut a, 2, 2).unwrap();
+    /// let (first, second) = (x.as_view(), x.as_view());
+    /// assert_eq!(first.get(0, 1), second.get(0, 1));
+    /// ```
+    pub fn as_view(&self) -> TwoDimensionalArrayRef<'_, T> {
+        TwoDimensionalArrayRef {
+            buffer: self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TwoDimensionalVec<T> {
+    /// Returns a read-only `TwoDimensionalArrayRef` view of the array.
+    pub fn as_view(&self) -> TwoDimensionalArrayRef<'_, T> {
+        TwoDimensionalArrayRef {
+            buffer: &self.buffer,
+            num_rows: self.num_rows,
+            num_cols: self.num_cols,
+        }
+    }
+}
+
+impl<'a, T> From<TwoDimensionalArray<'a, T>> for TwoDimensionalArrayRef<'a, T> {
+    fn from(array: TwoDimensionalArray<'a, T>) -> Self {
+        Self {
+            buffer: array.buffer,
+            num_rows: array.num_rows,
+            num_cols: array.num_cols,
+        }
+    }
+}